@@ -0,0 +1,84 @@
+//! Lists the instructions, accounts and events of the squads-mpl program from its sources, so
+//! that the SDK fails to compile when one of them has no wrapper, re-export or decoder.
+//!
+//! The lists are only generated when the program sources are next to the SDK (i.e. when
+//! building from the repository), the checks are skipped for the published crate.
+
+use std::{env, fs, path::Path};
+
+const PROGRAM_SRC: &str = "../programs/squads-mpl/src";
+
+fn main() {
+    println!("cargo:rustc-check-cfg=cfg(program_source)");
+    println!("cargo:rerun-if-changed={}", PROGRAM_SRC);
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("program.rs");
+    let src = Path::new(PROGRAM_SRC);
+    if !src.exists() {
+        fs::write(out, "").unwrap();
+        return;
+    }
+    println!("cargo:rustc-cfg=program_source");
+
+    let lib = parse(&src.join("lib.rs"));
+    let instructions = lib
+        .items
+        .iter()
+        .find_map(|item| match item {
+            syn::Item::Mod(module) if has_attribute(&module.attrs, "program") => module.content.as_ref(),
+            _ => None,
+        })
+        .expect("no #[program] module")
+        .1
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Fn(function) if matches!(function.vis, syn::Visibility::Public(_)) => {
+                Some(camel_case(&function.sig.ident.to_string()))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let accounts = structs_with_attribute(&parse(&src.join("state.rs")), "account");
+    let events = structs_with_attribute(&parse(&src.join("events.rs")), "event");
+
+    fs::write(
+        out,
+        format!(
+            "macro_rules! program_instructions {{ ($m:ident) => {{ $m!({}); }}; }}\n\
+             macro_rules! program_accounts {{ ($m:ident) => {{ $m!({}); }}; }}\n\
+             macro_rules! program_events {{ ($m:ident) => {{ $m!({}); }}; }}\n",
+            instructions.join(", "),
+            accounts.join(", "),
+            events.join(", "),
+        ),
+    )
+    .unwrap();
+}
+
+fn parse(path: &Path) -> syn::File {
+    syn::parse_file(&fs::read_to_string(path).unwrap()).unwrap()
+}
+
+fn has_attribute(attrs: &[syn::Attribute], name: &str) -> bool {
+    attrs.iter().any(|attr| attr.path.is_ident(name))
+}
+
+fn structs_with_attribute(file: &syn::File, attribute: &str) -> Vec<String> {
+    file.items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Struct(item) if has_attribute(&item.attrs, attribute) => Some(item.ident.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Name of the `squads_mpl::instruction` struct generated by anchor for an instruction.
+fn camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map_or(String::new(), |first| first.to_uppercase().chain(chars).collect())
+        })
+        .collect()
+}
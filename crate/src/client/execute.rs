@@ -0,0 +1,232 @@
+//! Builder for the accounts of `execute_transaction`.
+//!
+//! The program expects the `remaining_accounts` to be deduplicated, and the `account_list`
+//! argument to map every expected account to its index in `remaining_accounts`, following
+//! this layout for each attached instruction, in order:
+//! `[ms_instruction, program_id, instruction_key_1, instruction_key_2, ...]`
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::instruction::Instruction,
+};
+use squads_mpl::{
+    errors::MsError,
+    state::{MsInstruction, MsTransaction},
+};
+
+use crate::client::instructions::{self, get_signing_authority};
+use crate::pda::{get_ix_pda, get_tx_pda};
+
+/// Accounts expected by `execute_transaction` for a given transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecuteTransactionAccounts {
+    /// Deduplicated accounts to append to the instruction, in order.
+    pub remaining_accounts: Vec<AccountMeta>,
+    /// Index in `remaining_accounts` of every account expected by the program.
+    pub account_list: Vec<u8>,
+}
+
+impl ExecuteTransactionAccounts {
+    /// Builds the remaining accounts and the account list of a transaction.
+    /// `instructions` must contain every attached instruction, ordered by instruction index.
+    ///
+    /// An account used by several instructions is only passed once, writable if
+    /// any instruction writes to it. The authority of the transaction is never flagged
+    /// as a signer, as the program signs for it.
+    pub fn new(transaction: &MsTransaction, instructions: &[MsInstruction]) -> Result<Self> {
+        if instructions.len() != usize::from(transaction.instruction_index) {
+            return err!(MsError::InvalidNumberOfAccounts);
+        }
+
+        let (transaction_key, _) = get_tx_pda(&transaction.ms, transaction.transaction_index, &squads_mpl::ID);
+        let authority = get_signing_authority(&transaction.ms, transaction.authority_index);
+
+        let mut accounts = ExecuteTransactionAccounts {
+            remaining_accounts: Vec::new(),
+            account_list: Vec::new(),
+        };
+        for (index, instruction) in (1..=transaction.instruction_index).zip(instructions) {
+            if instruction.instruction_index != index {
+                return err!(MsError::InvalidInstructionAccount);
+            }
+            let (instruction_key, _) = get_ix_pda(&transaction_key, index, &squads_mpl::ID);
+
+            accounts.push(AccountMeta::new_readonly(instruction_key, false))?;
+            accounts.push(AccountMeta::new_readonly(instruction.program_id, false))?;
+            for key in instruction.keys.iter() {
+                accounts.push(AccountMeta {
+                    pubkey: key.pubkey,
+                    is_signer: key.is_signer && key.pubkey != authority,
+                    is_writable: key.is_writable,
+                })?;
+            }
+        }
+        Ok(accounts)
+    }
+
+    /// Adds an expected account, merging its flags with a previous occurrence
+    fn push(&mut self, meta: AccountMeta) -> Result<()> {
+        let index = match self.remaining_accounts.iter().position(|a| a.pubkey == meta.pubkey) {
+            Some(index) => {
+                let existing = &mut self.remaining_accounts[index];
+                existing.is_signer |= meta.is_signer;
+                existing.is_writable |= meta.is_writable;
+                index
+            }
+            None => {
+                self.remaining_accounts.push(meta);
+                self.remaining_accounts.len() - 1
+            }
+        };
+        let index = u8::try_from(index).map_err(|_| error!(MsError::InvalidNumberOfAccounts))?;
+        self.account_list.push(index);
+        Ok(())
+    }
+}
+
+/// Builds the `execute_transaction` instruction of a transaction, with its
+/// remaining accounts and account list.
+pub fn execute_transaction(
+    member: Pubkey,
+    transaction: &MsTransaction,
+    instructions: &[MsInstruction],
+) -> Result<Instruction> {
+    let (transaction_key, _) = get_tx_pda(&transaction.ms, transaction.transaction_index, &squads_mpl::ID);
+    let accounts = ExecuteTransactionAccounts::new(transaction, instructions)?;
+    Ok(instructions::execute_transaction(
+        transaction.ms,
+        transaction_key,
+        member,
+        accounts.account_list,
+        accounts.remaining_accounts,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use squads_mpl::state::{MsAccountMeta, MsTransactionStatus};
+
+    fn transaction(authority_index: u32, instruction_index: u8) -> MsTransaction {
+        MsTransaction {
+            creator: Pubkey::new_unique(),
+            ms: Pubkey::new_unique(),
+            transaction_index: 1,
+            authority_index,
+            authority_bump: 255,
+            status: MsTransactionStatus::ExecuteReady { timestamp: 0 },
+            instruction_index,
+            bump: 255,
+            approved: Vec::new(),
+            rejected: Vec::new(),
+            cancelled: Vec::new(),
+            executed_index: 0,
+            execution_delay: 0,
+            activated_at: 0,
+        }
+    }
+
+    fn instruction(instruction_index: u8, program_id: Pubkey, keys: Vec<MsAccountMeta>) -> MsInstruction {
+        MsInstruction {
+            program_id,
+            keys,
+            data: Vec::new(),
+            instruction_index,
+            bump: 255,
+        }
+    }
+
+    fn meta(pubkey: Pubkey, is_signer: bool, is_writable: bool) -> MsAccountMeta {
+        MsAccountMeta { pubkey, is_signer, is_writable }
+    }
+
+    /// Resolves the account list the way the program does
+    fn expected_keys(accounts: &ExecuteTransactionAccounts) -> Vec<Pubkey> {
+        accounts
+            .account_list
+            .iter()
+            .map(|&i| accounts.remaining_accounts[usize::from(i)].pubkey)
+            .collect()
+    }
+
+    #[test]
+    fn shared_accounts_are_deduplicated() {
+        let tx = transaction(1, 2);
+        let (vault, _) = crate::pda::get_authority_pda(&tx.ms, 1, &squads_mpl::ID);
+        let program = Pubkey::new_unique();
+        let first_destination = Pubkey::new_unique();
+        let second_destination = Pubkey::new_unique();
+        let instructions = vec![
+            instruction(1, program, vec![meta(vault, true, true), meta(first_destination, false, true)]),
+            instruction(2, program, vec![meta(vault, true, true), meta(second_destination, false, true)]),
+        ];
+
+        let accounts = ExecuteTransactionAccounts::new(&tx, &instructions).unwrap();
+
+        let (tx_key, _) = get_tx_pda(&tx.ms, 1, &squads_mpl::ID);
+        let (first_ix, _) = get_ix_pda(&tx_key, 1, &squads_mpl::ID);
+        let (second_ix, _) = get_ix_pda(&tx_key, 2, &squads_mpl::ID);
+        assert_eq!(
+            expected_keys(&accounts),
+            vec![first_ix, program, vault, first_destination, second_ix, program, vault, second_destination]
+        );
+        assert_eq!(accounts.remaining_accounts.len(), 6);
+        assert_eq!(accounts.account_list, vec![0, 1, 2, 3, 4, 1, 2, 5]);
+    }
+
+    #[test]
+    fn flags_are_merged_and_authority_does_not_sign() {
+        let tx = transaction(1, 2);
+        let (vault, _) = crate::pda::get_authority_pda(&tx.ms, 1, &squads_mpl::ID);
+        let shared = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let instructions = vec![
+            instruction(1, Pubkey::new_unique(), vec![meta(vault, true, false), meta(shared, false, false)]),
+            instruction(2, Pubkey::new_unique(), vec![meta(shared, false, true), meta(payer, true, true)]),
+        ];
+
+        let accounts = ExecuteTransactionAccounts::new(&tx, &instructions).unwrap();
+
+        let find = |key: Pubkey| accounts.remaining_accounts.iter().find(|a| a.pubkey == key).unwrap().clone();
+        assert_eq!(find(vault), AccountMeta::new_readonly(vault, false));
+        assert_eq!(find(shared), AccountMeta::new(shared, false));
+        assert_eq!(find(payer), AccountMeta::new(payer, true));
+    }
+
+    #[test]
+    fn internal_transactions_do_not_sign_for_the_multisig() {
+        let tx = transaction(0, 1);
+        let instructions = vec![instruction(1, squads_mpl::ID, vec![meta(tx.ms, true, true)])];
+
+        let accounts = ExecuteTransactionAccounts::new(&tx, &instructions).unwrap();
+
+        assert!(accounts.remaining_accounts.iter().all(|a| !a.is_signer));
+        // the program id of the attached instruction is the squads program itself
+        assert_eq!(accounts.account_list, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn instructions_must_match_the_transaction() {
+        let tx = transaction(1, 2);
+        let program = Pubkey::new_unique();
+
+        let missing = vec![instruction(1, program, Vec::new())];
+        assert!(ExecuteTransactionAccounts::new(&tx, &missing).is_err());
+
+        let unordered = vec![instruction(2, program, Vec::new()), instruction(1, program, Vec::new())];
+        assert!(ExecuteTransactionAccounts::new(&tx, &unordered).is_err());
+    }
+
+    #[test]
+    fn execute_transaction_appends_remaining_accounts() {
+        let tx = transaction(1, 1);
+        let member = Pubkey::new_unique();
+        let instructions = vec![instruction(1, Pubkey::new_unique(), vec![meta(Pubkey::new_unique(), false, true)])];
+
+        let ix = execute_transaction(member, &tx, &instructions).unwrap();
+
+        // multisig, transaction and member, followed by the 3 remaining accounts
+        assert_eq!(ix.accounts.len(), 6);
+        assert_eq!(ix.accounts[2], AccountMeta::new(member, true));
+    }
+}
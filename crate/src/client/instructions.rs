@@ -0,0 +1,989 @@
+//! Instruction builders for every squads-mpl instruction.
+//!
+//! Each builder derives the PDAs involved and fills in the account metas, returning
+//! a `solana_program::instruction::Instruction` ready to be added to a transaction.
+//! Builders of internal instructions (signed by the multisig PDA) are meant to be
+//! attached to an internal transaction (authority index 0) with [`add_instruction`],
+//! after being converted with [`to_incoming_instruction`].
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{hash::hash, instruction::Instruction, system_program, sysvar},
+    InstructionData,
+};
+use anchor_spl::{associated_token::get_associated_token_address, token};
+use squads_mpl::{
+    accounts,
+    instruction as ix,
+    state::{
+        GuardianAction, IncomingInstruction, Member, MemberReplacement, MsAccountMeta, MsInstruction,
+        ExecutionPolicy, Period, SpendingLimit, SpendingLimitMode, TimeLockMode,
+    },
+};
+
+use crate::pda::{
+    get_authority_pda, get_authority_policy_pda, get_guardian_action_pda, get_guardian_set_pda,
+    get_instruction_buffer_pda, get_ix_pda, get_ms_pda, get_recovery_pda, get_spending_limit_pda, get_tx_pda,
+};
+
+fn build_instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: squads_mpl::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// Converts an instruction to the schema expected by `add_instruction`.
+pub fn to_incoming_instruction(instruction: Instruction) -> IncomingInstruction {
+    IncomingInstruction {
+        program_id: instruction.program_id,
+        keys: instruction
+            .accounts
+            .iter()
+            .map(|meta| MsAccountMeta {
+                pubkey: meta.pubkey,
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+            .collect(),
+        data: instruction.data,
+    }
+}
+
+/// Address signing the instructions of a transaction: the multisig itself for
+/// internal transactions (authority index 0), the authority PDA otherwise.
+pub fn get_signing_authority(multisig: &Pubkey, authority_index: u32) -> Pubkey {
+    match authority_index {
+        0 => *multisig,
+        _ => get_authority_pda(multisig, authority_index, &squads_mpl::ID).0,
+    }
+}
+
+// Multisig
+
+/// Creates a new multisig seeded by `create_key`.
+pub fn create(
+    creator: Pubkey,
+    create_key: Pubkey,
+    threshold: u16,
+    members: Vec<Member>,
+    meta: String,
+    time_lock: u32,
+) -> Instruction {
+    let (multisig, _) = get_ms_pda(&create_key, &squads_mpl::ID);
+    build_instruction(
+        accounts::Create {
+            multisig,
+            creator,
+            system_program: system_program::ID,
+        },
+        ix::Create {
+            threshold,
+            create_key,
+            members,
+            _meta: meta,
+            time_lock,
+        },
+    )
+}
+
+/// Internal instruction adding a member, the multisig must be prefunded if it needs to be reallocated.
+pub fn add_member(multisig: Pubkey, new_member: Member) -> Instruction {
+    build_instruction(
+        accounts::MsAuthRealloc {
+            multisig,
+            rent: sysvar::rent::ID,
+            system_program: system_program::ID,
+        },
+        ix::AddMember { new_member },
+    )
+}
+
+/// Internal instruction removing a member.
+pub fn remove_member(multisig: Pubkey, old_member: Pubkey) -> Instruction {
+    build_instruction(accounts::MsAuth { multisig }, ix::RemoveMember { old_member })
+}
+
+/// Internal instruction changing the threshold.
+pub fn change_threshold(multisig: Pubkey, new_threshold: u16) -> Instruction {
+    build_instruction(accounts::MsAuth { multisig }, ix::ChangeThreshold { new_threshold })
+}
+
+/// Internal instruction bumping the authority index tracked by the multisig.
+pub fn add_authority(multisig: Pubkey) -> Instruction {
+    build_instruction(accounts::MsAuth { multisig }, ix::AddAuthority {})
+}
+
+/// Internal instruction setting the rent collector of closed transactions and instructions.
+pub fn set_rent_collector(multisig: Pubkey, rent_collector: Option<Pubkey>) -> Instruction {
+    build_instruction(accounts::MsAuth { multisig }, ix::SetRentCollector { rent_collector })
+}
+
+/// Internal instruction setting the lifetime of the transactions.
+pub fn set_proposal_lifetime(multisig: Pubkey, proposal_lifetime: u32) -> Instruction {
+    build_instruction(accounts::MsAuth { multisig }, ix::SetProposalLifetime { proposal_lifetime })
+}
+
+/// Internal instruction choosing whether the time lock delays the votes or the execution.
+pub fn set_time_lock_mode(multisig: Pubkey, time_lock_mode: TimeLockMode) -> Instruction {
+    build_instruction(accounts::MsAuth { multisig }, ix::SetTimeLockMode { time_lock_mode })
+}
+
+/// Internal instruction letting designated executors, or anyone, execute approved transactions.
+pub fn set_execution_policy(multisig: Pubkey, execution_policy: ExecutionPolicy) -> Instruction {
+    build_instruction(accounts::MsAuth { multisig }, ix::SetExecutionPolicy { execution_policy })
+}
+
+/// Internal instruction updating the time lock and spending limit flag. The guardian
+/// can only be set if the multisig has none, see [`propose_guardian_change`] otherwise.
+pub fn update_multisig_settings(
+    multisig: Pubkey,
+    new_time_lock: u32,
+    spending_limit_enabled: bool,
+    guardian: Option<Pubkey>,
+) -> Instruction {
+    build_instruction(
+        accounts::MsAuth { multisig },
+        ix::UpdateMultisigSettings {
+            new_time_lock,
+            spending_limit_enabled,
+            guardian,
+        },
+    )
+}
+
+/// Internal instruction proposing to rotate (Some) or remove (None) the guardian.
+pub fn propose_guardian_change(multisig: Pubkey, guardian: Option<Pubkey>) -> Instruction {
+    build_instruction(accounts::MsAuth { multisig }, ix::ProposeGuardianChange { guardian })
+}
+
+/// Applies the pending guardian change once its delay has passed.
+pub fn finalize_guardian_change(multisig: Pubkey) -> Instruction {
+    build_instruction(accounts::FinalizeGuardianChange { multisig }, ix::FinalizeGuardianChange {})
+}
+
+/// Vetoes the pending guardian change on behalf of the current guardian.
+pub fn veto_guardian_change(multisig: Pubkey, guardian: Pubkey) -> Instruction {
+    build_instruction(
+        accounts::VetoGuardianChange { multisig, guardian },
+        ix::VetoGuardianChange {},
+    )
+}
+
+/// Freezes the multisig for `duration` seconds on behalf of the guardian.
+pub fn freeze_multisig(multisig: Pubkey, guardian: Pubkey, duration: u32) -> Instruction {
+    build_instruction(
+        accounts::FreezeMultisig { multisig, guardian },
+        ix::FreezeMultisig { duration },
+    )
+}
+
+/// Internal instruction ending the freeze of the multisig early.
+pub fn unfreeze_multisig(multisig: Pubkey) -> Instruction {
+    build_instruction(accounts::MsAuth { multisig }, ix::UnfreezeMultisig {})
+}
+
+/// Proposes to replace the keys of members on behalf of the guardian.
+pub fn propose_recovery(
+    multisig: Pubkey,
+    guardian: Pubkey,
+    rent_payer: Pubkey,
+    replacements: Vec<MemberReplacement>,
+) -> Instruction {
+    let (recovery, _) = get_recovery_pda(&multisig, &squads_mpl::ID);
+    build_instruction(
+        accounts::ProposeRecovery {
+            multisig,
+            recovery,
+            guardian,
+            rent_payer,
+            system_program: system_program::ID,
+        },
+        ix::ProposeRecovery { replacements },
+    )
+}
+
+/// Vetoes the pending member recovery, `rent_payer` must be the rent payer of the recovery.
+pub fn veto_recovery(multisig: Pubkey, member: Pubkey, rent_payer: Pubkey) -> Instruction {
+    let (recovery, _) = get_recovery_pda(&multisig, &squads_mpl::ID);
+    build_instruction(
+        accounts::VetoRecovery {
+            multisig,
+            recovery,
+            rent_payer,
+            member,
+        },
+        ix::VetoRecovery {},
+    )
+}
+
+/// Applies the pending member recovery once its delay has passed, `rent_payer` must be
+/// the rent payer of the recovery.
+pub fn finalize_recovery(multisig: Pubkey, rent_payer: Pubkey) -> Instruction {
+    let (recovery, _) = get_recovery_pda(&multisig, &squads_mpl::ID);
+    build_instruction(
+        accounts::FinalizeRecovery {
+            multisig,
+            recovery,
+            rent_payer,
+        },
+        ix::FinalizeRecovery {},
+    )
+}
+
+/// Removes a member on behalf of the guardian.
+pub fn remove_member_with_guardian(multisig: Pubkey, guardian: Pubkey, old_member: Pubkey) -> Instruction {
+    build_instruction(
+        accounts::RemoveMemberWithGuardian {
+            multisig,
+            remover: guardian,
+        },
+        ix::RemoveMemberWithGuardian { old_member },
+    )
+}
+
+// Transactions
+
+/// Creates the transaction `transaction_index`, which must be the multisig transaction index + 1.
+pub fn create_transaction(
+    multisig: Pubkey,
+    creator: Pubkey,
+    transaction_index: u32,
+    authority_index: u32,
+) -> Instruction {
+    let (transaction, _) = get_tx_pda(&multisig, transaction_index, &squads_mpl::ID);
+    build_instruction(
+        accounts::CreateTransaction {
+            multisig,
+            transaction,
+            creator,
+            system_program: system_program::ID,
+        },
+        ix::CreateTransaction { authority_index },
+    )
+}
+
+/// Attaches the instruction `instruction_index`, which must be the transaction instruction index + 1.
+pub fn add_instruction(
+    multisig: Pubkey,
+    transaction: Pubkey,
+    creator: Pubkey,
+    instruction_index: u8,
+    incoming_instruction: IncomingInstruction,
+) -> Instruction {
+    let (instruction, _) = get_ix_pda(&transaction, instruction_index, &squads_mpl::ID);
+    build_instruction(
+        accounts::AddInstruction {
+            multisig,
+            transaction,
+            instruction,
+            creator,
+            system_program: system_program::ID,
+        },
+        ix::AddInstruction { incoming_instruction },
+    )
+}
+
+/// Replaces the instruction at `instruction_index` of a draft transaction.
+pub fn update_instruction(
+    multisig: Pubkey,
+    transaction: Pubkey,
+    creator: Pubkey,
+    instruction_index: u8,
+    incoming_instruction: IncomingInstruction,
+) -> Instruction {
+    let (instruction, _) = get_ix_pda(&transaction, instruction_index, &squads_mpl::ID);
+    build_instruction(
+        accounts::UpdateInstruction {
+            multisig,
+            transaction,
+            instruction,
+            creator,
+            system_program: system_program::ID,
+        },
+        ix::UpdateInstruction { incoming_instruction },
+    )
+}
+
+/// Removes the instruction at `instruction_index` of a draft transaction, every following
+/// instruction being moved to the previous index by the program. `last_index` is the
+/// current instruction index of the transaction.
+pub fn remove_instruction(
+    multisig: Pubkey,
+    transaction: Pubkey,
+    creator: Pubkey,
+    instruction_index: u8,
+    last_index: u8,
+) -> Instruction {
+    let (instruction, _) = get_ix_pda(&transaction, last_index, &squads_mpl::ID);
+    let mut remove = build_instruction(
+        accounts::RemoveInstruction {
+            multisig,
+            transaction,
+            instruction,
+            creator,
+        },
+        ix::RemoveInstruction { instruction_index },
+    );
+    remove.accounts.extend(
+        (instruction_index..last_index)
+            .map(|index| AccountMeta::new(get_ix_pda(&transaction, index, &squads_mpl::ID).0, false)),
+    );
+    remove
+}
+
+/// Creates the instruction buffer of a draft transaction, writing its first chunk.
+/// `final_hash` is the sha256 hash of the complete serialized [`IncomingInstruction`].
+pub fn create_instruction_buffer(
+    multisig: Pubkey,
+    transaction: Pubkey,
+    creator: Pubkey,
+    final_hash: [u8; 32],
+    final_size: u32,
+    chunk: Vec<u8>,
+) -> Instruction {
+    let (instruction_buffer, _) = get_instruction_buffer_pda(&transaction, &squads_mpl::ID);
+    build_instruction(
+        accounts::CreateInstructionBuffer {
+            multisig,
+            transaction,
+            instruction_buffer,
+            creator,
+            system_program: system_program::ID,
+        },
+        ix::CreateInstructionBuffer { final_hash, final_size, chunk },
+    )
+}
+
+/// Appends a chunk to the instruction buffer of a draft transaction.
+pub fn write_instruction_buffer(multisig: Pubkey, transaction: Pubkey, creator: Pubkey, chunk: Vec<u8>) -> Instruction {
+    let (instruction_buffer, _) = get_instruction_buffer_pda(&transaction, &squads_mpl::ID);
+    build_instruction(
+        accounts::WriteInstructionBuffer {
+            multisig,
+            transaction,
+            instruction_buffer,
+            creator,
+        },
+        ix::WriteInstructionBuffer { chunk },
+    )
+}
+
+/// Attaches the instruction of a complete instruction buffer at `instruction_index`
+/// (the current instruction index of the transaction + 1), closing the buffer.
+pub fn add_instruction_from_buffer(
+    multisig: Pubkey,
+    transaction: Pubkey,
+    creator: Pubkey,
+    instruction_index: u8,
+) -> Instruction {
+    let (instruction_buffer, _) = get_instruction_buffer_pda(&transaction, &squads_mpl::ID);
+    let (instruction, _) = get_ix_pda(&transaction, instruction_index, &squads_mpl::ID);
+    build_instruction(
+        accounts::AddInstructionFromBuffer {
+            multisig,
+            transaction,
+            instruction_buffer,
+            instruction,
+            creator,
+            system_program: system_program::ID,
+        },
+        ix::AddInstructionFromBuffer {},
+    )
+}
+
+/// Closes an instruction buffer that won't be attached.
+pub fn close_instruction_buffer(transaction: Pubkey, creator: Pubkey) -> Instruction {
+    let (instruction_buffer, _) = get_instruction_buffer_pda(&transaction, &squads_mpl::ID);
+    build_instruction(
+        accounts::CloseInstructionBuffer {
+            transaction,
+            instruction_buffer,
+            creator,
+        },
+        ix::CloseInstructionBuffer {},
+    )
+}
+
+/// Attaches an instruction too large for [`add_instruction`] through an instruction buffer.
+/// Returns the instructions to send in order, one per transaction: the creation of the
+/// buffer, the writes of the next chunks of up to `chunk_size` bytes, and the attachment.
+pub fn add_instruction_with_buffer(
+    multisig: Pubkey,
+    transaction: Pubkey,
+    creator: Pubkey,
+    instruction_index: u8,
+    incoming_instruction: &IncomingInstruction,
+    chunk_size: usize,
+) -> Result<Vec<Instruction>> {
+    let data = incoming_instruction.try_to_vec()?;
+    let final_size = u32::try_from(data.len()).map_err(|_| error!(squads_mpl::errors::MsError::InvalidBufferSize))?;
+    let final_hash = hash(&data).to_bytes();
+
+    let mut chunks = data.chunks(chunk_size.max(1));
+    let first = chunks.next().unwrap_or_default().to_vec();
+    let mut instructions = vec![create_instruction_buffer(multisig, transaction, creator, final_hash, final_size, first)];
+    instructions.extend(chunks.map(|chunk| write_instruction_buffer(multisig, transaction, creator, chunk.to_vec())));
+    instructions.push(add_instruction_from_buffer(multisig, transaction, creator, instruction_index));
+    Ok(instructions)
+}
+
+/// Activates a draft transaction so it can be voted on.
+pub fn activate_transaction(multisig: Pubkey, transaction: Pubkey, creator: Pubkey) -> Instruction {
+    build_instruction(
+        accounts::ActivateTransaction {
+            multisig,
+            transaction,
+            creator,
+        },
+        ix::ActivateTransaction {},
+    )
+}
+
+fn vote_accounts(
+    multisig: Pubkey,
+    transaction: Pubkey,
+    member: Pubkey,
+    authority_index: u32,
+) -> accounts::VoteTransaction {
+    let (authority_policy, _) = get_authority_policy_pda(&multisig, authority_index, &squads_mpl::ID);
+    accounts::VoteTransaction {
+        multisig,
+        transaction,
+        member,
+        authority_policy,
+    }
+}
+
+/// Approves an active transaction, `authority_index` is the one of the transaction.
+pub fn approve_transaction(
+    multisig: Pubkey,
+    transaction: Pubkey,
+    member: Pubkey,
+    authority_index: u32,
+) -> Instruction {
+    build_instruction(
+        vote_accounts(multisig, transaction, member, authority_index),
+        ix::ApproveTransaction {},
+    )
+}
+
+/// Rejects an active transaction, `authority_index` is the one of the transaction.
+pub fn reject_transaction(
+    multisig: Pubkey,
+    transaction: Pubkey,
+    member: Pubkey,
+    authority_index: u32,
+) -> Instruction {
+    build_instruction(
+        vote_accounts(multisig, transaction, member, authority_index),
+        ix::RejectTransaction {},
+    )
+}
+
+/// Votes to cancel an approved transaction, `authority_index` is the one of the transaction.
+pub fn cancel_transaction(
+    multisig: Pubkey,
+    transaction: Pubkey,
+    member: Pubkey,
+    authority_index: u32,
+) -> Instruction {
+    let (authority_policy, _) = get_authority_policy_pda(&multisig, authority_index, &squads_mpl::ID);
+    build_instruction(
+        accounts::CancelTransaction {
+            multisig,
+            transaction,
+            member,
+            authority_policy,
+            system_program: system_program::ID,
+        },
+        ix::CancelTransaction {},
+    )
+}
+
+/// Executes an approved transaction. `remaining_accounts` and `account_list` must follow
+/// the layout expected by the program, see the program README.
+pub fn execute_transaction(
+    multisig: Pubkey,
+    transaction: Pubkey,
+    member: Pubkey,
+    account_list: Vec<u8>,
+    remaining_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let mut instruction = build_instruction(
+        accounts::ExecuteTransaction {
+            multisig,
+            transaction,
+            member,
+        },
+        ix::ExecuteTransaction { account_list },
+    );
+    instruction.accounts.extend(remaining_accounts);
+    instruction
+}
+
+/// Executes the next instruction of an approved transaction, `ms_instruction` must be
+/// the loaded instruction account at index `executed_index + 1`.
+pub fn execute_instruction(
+    multisig: Pubkey,
+    transaction: Pubkey,
+    member: Pubkey,
+    authority_index: u32,
+    ms_instruction: &MsInstruction,
+) -> Instruction {
+    let (instruction, _) = get_ix_pda(&transaction, ms_instruction.instruction_index, &squads_mpl::ID);
+    let authority = get_signing_authority(&multisig, authority_index);
+    let mut ix = build_instruction(
+        accounts::ExecuteInstruction {
+            multisig,
+            transaction,
+            instruction,
+            member,
+        },
+        ix::ExecuteInstruction {},
+    );
+    ix.accounts.push(AccountMeta::new_readonly(ms_instruction.program_id, false));
+    ix.accounts.extend(ms_instruction.keys.iter().map(|key| AccountMeta {
+        pubkey: key.pubkey,
+        // the authority signs through the program, any other signer must sign the transaction
+        is_signer: key.is_signer && key.pubkey != authority,
+        is_writable: key.is_writable,
+    }));
+    ix
+}
+
+/// Marks an active or approved transaction past its deadline as expired.
+pub fn expire_transaction(multisig: Pubkey, transaction: Pubkey) -> Instruction {
+    build_instruction(
+        accounts::ExpireTransaction {
+            multisig,
+            transaction,
+        },
+        ix::ExpireTransaction {},
+    )
+}
+
+/// Cancels an active or approved transaction on behalf of the guardian.
+pub fn veto_transaction(multisig: Pubkey, transaction: Pubkey, guardian: Pubkey) -> Instruction {
+    build_instruction(
+        accounts::VetoTransaction {
+            multisig,
+            transaction,
+            guardian,
+        },
+        ix::VetoTransaction {},
+    )
+}
+
+/// Closes a transaction once all of its instructions were closed. `rent_collector` must
+/// be the multisig rent collector, or the transaction creator if it isn't set.
+pub fn close_transaction(multisig: Pubkey, transaction: Pubkey, rent_collector: Pubkey) -> Instruction {
+    build_instruction(
+        accounts::CloseTransaction {
+            multisig,
+            transaction,
+            rent_collector,
+        },
+        ix::CloseTransaction {},
+    )
+}
+
+/// Closes the last instruction `instruction_index` still attached to a closable transaction.
+pub fn close_instruction(
+    multisig: Pubkey,
+    transaction: Pubkey,
+    instruction_index: u8,
+    rent_collector: Pubkey,
+) -> Instruction {
+    let (instruction, _) = get_ix_pda(&transaction, instruction_index, &squads_mpl::ID);
+    build_instruction(
+        accounts::CloseInstruction {
+            multisig,
+            transaction,
+            instruction,
+            rent_collector,
+        },
+        ix::CloseInstruction {},
+    )
+}
+
+// Authority policies
+
+/// Internal instruction creating the policy of `authority_index`.
+pub fn add_authority_policy(
+    multisig: Pubkey,
+    rent_payer: Pubkey,
+    authority_index: u32,
+    threshold: u16,
+    time_lock: u32,
+) -> Instruction {
+    let (authority_policy, _) = get_authority_policy_pda(&multisig, authority_index, &squads_mpl::ID);
+    build_instruction(
+        accounts::CreateAuthorityPolicy {
+            authority_policy,
+            multisig,
+            rent_payer,
+            system_program: system_program::ID,
+        },
+        ix::AddAuthorityPolicy {
+            authority_index,
+            threshold,
+            time_lock,
+        },
+    )
+}
+
+/// Internal instruction updating the policy of `authority_index`.
+pub fn update_authority_policy(
+    multisig: Pubkey,
+    authority_index: u32,
+    threshold: u16,
+    time_lock: u32,
+) -> Instruction {
+    let (authority_policy, _) = get_authority_policy_pda(&multisig, authority_index, &squads_mpl::ID);
+    build_instruction(
+        accounts::UpdateAuthorityPolicy {
+            authority_policy,
+            multisig,
+        },
+        ix::UpdateAuthorityPolicy { threshold, time_lock },
+    )
+}
+
+/// Internal instruction removing the policy of `authority_index`.
+pub fn remove_authority_policy(multisig: Pubkey, authority_index: u32) -> Instruction {
+    let (authority_policy, _) = get_authority_policy_pda(&multisig, authority_index, &squads_mpl::ID);
+    build_instruction(
+        accounts::RemoveAuthorityPolicy {
+            authority_policy,
+            multisig,
+        },
+        ix::RemoveAuthorityPolicy {},
+    )
+}
+
+// Spending limits
+
+/// Internal instruction creating a spending limit seeded by `create_key`.
+#[allow(clippy::too_many_arguments)]
+pub fn add_spending_limit(
+    multisig: Pubkey,
+    rent_payer: Pubkey,
+    create_key: Pubkey,
+    mint: Pubkey,
+    authority_index: u32,
+    amount: u64,
+    members: Vec<Pubkey>,
+    destinations: Vec<Pubkey>,
+    period: Period,
+    mode: SpendingLimitMode,
+    expiration: Option<i64>,
+) -> Instruction {
+    let (spending_limit, _) = get_spending_limit_pda(&multisig, &create_key, &squads_mpl::ID);
+    build_instruction(
+        accounts::CreateSpendingLimit {
+            spending_limit,
+            multisig,
+            rent_payer,
+            system_program: system_program::ID,
+        },
+        ix::AddSpendingLimit {
+            create_key,
+            mint,
+            authority_index,
+            amount,
+            members,
+            destinations,
+            period,
+            mode,
+            expiration,
+        },
+    )
+}
+
+/// Internal instruction removing the spending limit seeded by `create_key`.
+pub fn remove_spending_limit(multisig: Pubkey, create_key: Pubkey) -> Instruction {
+    let (spending_limit, _) = get_spending_limit_pda(&multisig, &create_key, &squads_mpl::ID);
+    build_instruction(
+        accounts::RemoveSpendingLimit {
+            spending_limit,
+            multisig,
+            system_program: system_program::ID,
+        },
+        ix::RemoveSpendingLimit {},
+    )
+}
+
+/// Disables the spending limits of the multisig on behalf of the guardian.
+pub fn pause_spending_limit(multisig: Pubkey, guardian: Pubkey) -> Instruction {
+    build_instruction(
+        accounts::PauseSpendingLimit {
+            multisig,
+            disabler: guardian,
+        },
+        ix::PauseSpendingLimit {},
+    )
+}
+
+/// Transfers `amount` from the vault of the spending limit to `destination`.
+/// For SPL tokens, the associated token accounts of the vault and the destination are used.
+pub fn spending_limit_use(
+    spending_limit: &SpendingLimit,
+    member: Pubkey,
+    destination: Pubkey,
+    amount: u64,
+    decimals: u8,
+) -> Instruction {
+    let multisig = spending_limit.multisig;
+    let (spending_limit_pda, _) =
+        get_spending_limit_pda(&multisig, &spending_limit.create_key, &squads_mpl::ID);
+    let (vault, _) = get_authority_pda(&multisig, spending_limit.authority_index, &squads_mpl::ID);
+    let is_sol = spending_limit.mint == Pubkey::default();
+    let spl = |account: Pubkey| (!is_sol).then_some(account);
+    build_instruction(
+        accounts::SpendingLimitUse {
+            multisig,
+            spending_limit: spending_limit_pda,
+            vault,
+            member,
+            destination,
+            system_program: is_sol.then_some(system_program::ID),
+            mint: spl(spending_limit.mint),
+            vault_token_account: spl(get_associated_token_address(&vault, &spending_limit.mint)),
+            destination_token_account: spl(get_associated_token_address(&destination, &spending_limit.mint)),
+            token_program: spl(token::ID),
+        },
+        ix::SpendingLimitUse { amount, decimals },
+    )
+}
+
+// Guardian set
+
+/// Internal instruction creating the guardian set of the multisig.
+pub fn create_guardian_set(
+    multisig: Pubkey,
+    rent_payer: Pubkey,
+    guardians: Vec<Pubkey>,
+    threshold: u16,
+) -> Instruction {
+    let (guardian_set, _) = get_guardian_set_pda(&multisig, &squads_mpl::ID);
+    build_instruction(
+        accounts::CreateGuardianSet {
+            guardian_set,
+            multisig,
+            rent_payer,
+            system_program: system_program::ID,
+        },
+        ix::CreateGuardianSet { guardians, threshold },
+    )
+}
+
+/// Internal instruction replacing the guardians and the threshold of the guardian set.
+pub fn update_guardian_set(multisig: Pubkey, guardians: Vec<Pubkey>, threshold: u16) -> Instruction {
+    let (guardian_set, _) = get_guardian_set_pda(&multisig, &squads_mpl::ID);
+    build_instruction(
+        accounts::UpdateGuardianSet { guardian_set, multisig },
+        ix::UpdateGuardianSet { guardians, threshold },
+    )
+}
+
+/// Proposes `instruction` as a guardian action, `action_index` must be the next
+/// action index of the guardian set. `instruction` is a guardian instruction built
+/// with the guardian set address as the guardian (ie, [`pause_spending_limit`]).
+pub fn create_guardian_action(
+    multisig: Pubkey,
+    creator: Pubkey,
+    action_index: u32,
+    instruction: Instruction,
+) -> Instruction {
+    let (guardian_set, _) = get_guardian_set_pda(&multisig, &squads_mpl::ID);
+    let (action, _) = get_guardian_action_pda(&guardian_set, action_index, &squads_mpl::ID);
+    build_instruction(
+        accounts::CreateGuardianAction {
+            guardian_set,
+            action,
+            creator,
+            system_program: system_program::ID,
+        },
+        ix::CreateGuardianAction {
+            instruction: to_incoming_instruction(instruction),
+        },
+    )
+}
+
+/// Approves the guardian action at `action_index`.
+pub fn approve_guardian_action(multisig: Pubkey, guardian: Pubkey, action_index: u32) -> Instruction {
+    let (guardian_set, _) = get_guardian_set_pda(&multisig, &squads_mpl::ID);
+    let (action, _) = get_guardian_action_pda(&guardian_set, action_index, &squads_mpl::ID);
+    build_instruction(
+        accounts::ApproveGuardianAction {
+            guardian_set,
+            action,
+            guardian,
+        },
+        ix::ApproveGuardianAction {},
+    )
+}
+
+/// Executes an approved guardian action, `guardian_action` being the loaded action account.
+pub fn execute_guardian_action(multisig: Pubkey, guardian: Pubkey, guardian_action: &GuardianAction) -> Instruction {
+    let (guardian_set, _) = get_guardian_set_pda(&multisig, &squads_mpl::ID);
+    let (action, _) = get_guardian_action_pda(&guardian_set, guardian_action.action_index, &squads_mpl::ID);
+    let mut ix = build_instruction(
+        accounts::ExecuteGuardianAction {
+            guardian_set,
+            action,
+            creator: guardian_action.creator,
+            guardian,
+        },
+        ix::ExecuteGuardianAction {},
+    );
+    ix.accounts.push(AccountMeta::new_readonly(squads_mpl::ID, false));
+    ix.accounts.extend(guardian_action.keys.iter().map(|key| AccountMeta {
+        pubkey: key.pubkey,
+        // the guardian set signs through the program
+        is_signer: key.is_signer && key.pubkey != guardian_set,
+        is_writable: key.is_writable,
+    }));
+    ix
+}
+
+/// Converts a multisig created with the legacy layout to the current layout,
+/// `rent_payer` tops up the rent of the reallocated account.
+pub fn migrate_multisig(multisig: Pubkey, rent_payer: Pubkey) -> Instruction {
+    build_instruction(
+        accounts::MigrateMultisig {
+            multisig,
+            rent_payer,
+            system_program: system_program::ID,
+        },
+        ix::MigrateMultisig {},
+    )
+}
+
+/// Converts a spending limit created with the legacy layout to the current layout,
+/// `rent_payer` tops up the rent of the reallocated account.
+pub fn migrate_spending_limit(multisig: Pubkey, create_key: Pubkey, rent_payer: Pubkey) -> Instruction {
+    let (spending_limit, _) = get_spending_limit_pda(&multisig, &create_key, &squads_mpl::ID);
+    build_instruction(
+        accounts::MigrateSpendingLimit {
+            spending_limit,
+            rent_payer,
+            system_program: system_program::ID,
+        },
+        ix::MigrateSpendingLimit {},
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_transaction_derives_next_transaction() {
+        let multisig = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let instruction = create_transaction(multisig, creator, 3, 1);
+        let (transaction, _) = get_tx_pda(&multisig, 3, &squads_mpl::ID);
+
+        assert_eq!(instruction.program_id, squads_mpl::ID);
+        assert_eq!(instruction.accounts[1], AccountMeta::new(transaction, false));
+        assert_eq!(instruction.accounts[2], AccountMeta::new(creator, true));
+    }
+
+    #[test]
+    fn approve_transaction_passes_authority_policy() {
+        let multisig = Pubkey::new_unique();
+        let instruction = approve_transaction(multisig, Pubkey::new_unique(), Pubkey::new_unique(), 2);
+        let (authority_policy, _) = get_authority_policy_pda(&multisig, 2, &squads_mpl::ID);
+
+        assert_eq!(instruction.accounts.len(), 4);
+        assert_eq!(instruction.accounts[3], AccountMeta::new_readonly(authority_policy, false));
+    }
+
+    #[test]
+    fn internal_instructions_are_signed_by_the_multisig() {
+        let multisig = Pubkey::new_unique();
+        let incoming = to_incoming_instruction(change_threshold(multisig, 2));
+
+        assert_eq!(incoming.program_id, squads_mpl::ID);
+        assert_eq!(incoming.keys.len(), 1);
+        assert!(incoming.keys[0].is_signer);
+        assert_eq!(incoming.keys[0].pubkey, multisig);
+    }
+
+    #[test]
+    fn guardian_actions_are_signed_by_the_guardian_set() {
+        let multisig = Pubkey::new_unique();
+        let guardian = Pubkey::new_unique();
+        let (guardian_set, _) = get_guardian_set_pda(&multisig, &squads_mpl::ID);
+        let create = create_guardian_action(multisig, guardian, 1, pause_spending_limit(multisig, guardian_set));
+        let incoming = ix::CreateGuardianAction::try_from_slice(&create.data[8..]).unwrap().instruction;
+
+        let action = GuardianAction {
+            guardian_set,
+            action_index: 1,
+            creator: guardian,
+            keys: incoming.keys,
+            data: incoming.data,
+            approved: vec![guardian],
+            bump: 255,
+        };
+        let execute = execute_guardian_action(multisig, guardian, &action);
+
+        // guardian set, action, creator and guardian, followed by the program and the 2 instruction keys
+        assert_eq!(execute.accounts.len(), 7);
+        assert_eq!(execute.accounts[4], AccountMeta::new_readonly(squads_mpl::ID, false));
+        assert_eq!(execute.accounts[5], AccountMeta::new(multisig, false));
+        assert_eq!(execute.accounts[6], AccountMeta::new(guardian_set, false));
+    }
+
+    #[test]
+    fn large_instructions_are_written_in_chunks() {
+        let multisig = Pubkey::new_unique();
+        let transaction = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let incoming = IncomingInstruction {
+            program_id: Pubkey::new_unique(),
+            keys: vec![MsAccountMeta { pubkey: Pubkey::new_unique(), is_signer: false, is_writable: true }],
+            data: vec![7; 2000],
+        };
+
+        let instructions = add_instruction_with_buffer(multisig, transaction, creator, 3, &incoming, 900).unwrap();
+
+        // create with the first chunk, 2 writes, then the attachment
+        assert_eq!(instructions.len(), 4);
+        let create = ix::CreateInstructionBuffer::try_from_slice(&instructions[0].data[8..]).unwrap();
+        let mut buffer = create.chunk;
+        for write in &instructions[1..3] {
+            buffer.extend(ix::WriteInstructionBuffer::try_from_slice(&write.data[8..]).unwrap().chunk);
+        }
+        assert_eq!(buffer.len(), create.final_size as usize);
+        assert_eq!(hash(&buffer).to_bytes(), create.final_hash);
+        // the instruction account is sized like the ones of add_instruction
+        assert_eq!(buffer.len() + 2, incoming.get_max_size());
+
+        let (instruction, _) = get_ix_pda(&transaction, 3, &squads_mpl::ID);
+        assert_eq!(instructions[3].accounts[3], AccountMeta::new(instruction, false));
+    }
+
+    #[test]
+    fn removed_instructions_are_replaced_by_the_next_ones() {
+        let multisig = Pubkey::new_unique();
+        let transaction = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+
+        let remove = remove_instruction(multisig, transaction, creator, 2, 4);
+
+        // the last instruction is closed, the removed one and the next are passed to be moved
+        let [second, third, fourth] = [2, 3, 4].map(|index| get_ix_pda(&transaction, index, &squads_mpl::ID).0);
+        assert_eq!(remove.accounts.len(), 6);
+        assert_eq!(remove.accounts[2], AccountMeta::new(fourth, false));
+        assert_eq!(remove.accounts[4..], [AccountMeta::new(second, false), AccountMeta::new(third, false)]);
+        assert_eq!(remove.data, ix::RemoveInstruction { instruction_index: 2 }.data());
+
+        // removing the last instruction moves nothing
+        assert_eq!(remove_instruction(multisig, transaction, creator, 4, 4).accounts.len(), 4);
+    }
+}
@@ -0,0 +1,188 @@
+//! Address lookup tables for the execution of large transactions.
+//!
+//! A legacy Solana transaction only fits around 35 accounts, which limits the instructions
+//! `execute_transaction` can execute at once. Storing the accounts of a transaction in an
+//! address lookup table lets a v0 transaction reference each of them with a single byte.
+//!
+//! The table is created and extended with [`create_lookup_table`], and can be used one slot
+//! after its last extension. The accounts of a lookup table are resolved before the program
+//! runs, so the instruction built by [`execute_transaction`](crate::client::execute::execute_transaction)
+//! can be used as is:
+//!
+//! ```ignore
+//! let addresses = lookup_table_addresses(&transaction, &instructions)?;
+//! let (table, setup) = create_lookup_table(authority, payer, recent_slot, &addresses);
+//! // send `setup`, one instruction per transaction, then
+//! let ix = execute_transaction(member, &transaction, &instructions)?;
+//! let message = v0::Message::try_compile(&member, &[ix], &[AddressLookupTableAccount { key: table, addresses }], blockhash)?;
+//! ```
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{address_lookup_table::instruction as alt, clock::Slot, instruction::Instruction},
+};
+use squads_mpl::state::{MsInstruction, MsTransaction};
+
+use crate::client::execute::ExecuteTransactionAccounts;
+use crate::pda::get_tx_pda;
+
+/// Maximum number of addresses added by a single extend instruction, so that it fits
+/// in a transaction with its signatures.
+pub const MAX_EXTEND_ADDRESSES: usize = 20;
+
+/// Returns the addresses to store in the lookup table of a transaction: the multisig,
+/// the transaction and the remaining accounts of `execute_transaction`. Signers are left
+/// out, as a lookup table can't provide them.
+/// `instructions` must contain every attached instruction, ordered by instruction index.
+pub fn lookup_table_addresses(transaction: &MsTransaction, instructions: &[MsInstruction]) -> Result<Vec<Pubkey>> {
+    let (transaction_key, _) = get_tx_pda(&transaction.ms, transaction.transaction_index, &squads_mpl::ID);
+    let accounts = ExecuteTransactionAccounts::new(transaction, instructions)?;
+
+    let mut addresses = vec![transaction.ms, transaction_key];
+    addresses.extend(
+        accounts
+            .remaining_accounts
+            .iter()
+            .filter(|meta| !meta.is_signer)
+            .map(|meta| meta.pubkey),
+    );
+    Ok(addresses)
+}
+
+/// Creates a lookup table owned by `authority` holding `addresses`. Returns the address
+/// of the table and the instructions to send, the create instruction followed by the
+/// extend instructions of up to [`MAX_EXTEND_ADDRESSES`] addresses each.
+pub fn create_lookup_table(
+    authority: Pubkey,
+    payer: Pubkey,
+    recent_slot: Slot,
+    addresses: &[Pubkey],
+) -> (Pubkey, Vec<Instruction>) {
+    let (create, lookup_table) = alt::create_lookup_table(authority, payer, recent_slot);
+    let mut instructions = vec![create];
+    instructions.extend(extend_lookup_table(lookup_table, authority, payer, addresses));
+    (lookup_table, instructions)
+}
+
+/// Adds `addresses` to an existing lookup table, in chunks of [`MAX_EXTEND_ADDRESSES`].
+pub fn extend_lookup_table(
+    lookup_table: Pubkey,
+    authority: Pubkey,
+    payer: Pubkey,
+    addresses: &[Pubkey],
+) -> Vec<Instruction> {
+    addresses
+        .chunks(MAX_EXTEND_ADDRESSES)
+        .map(|chunk| alt::extend_lookup_table(lookup_table, authority, Some(payer), chunk.to_vec()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::{
+        address_lookup_table::{self, AddressLookupTableAccount},
+        hash::Hash,
+        message::{v0, Message, VersionedMessage},
+    };
+    use squads_mpl::state::{MsAccountMeta, MsTransactionStatus};
+
+    use crate::client::execute::execute_transaction;
+
+    // maximum size of a transaction, minus the signature of the member
+    const MAX_MESSAGE_SIZE: usize = 1232 - 1 - 64;
+
+    fn transaction(instruction_index: u8) -> MsTransaction {
+        MsTransaction {
+            creator: Pubkey::new_unique(),
+            ms: Pubkey::new_unique(),
+            transaction_index: 1,
+            authority_index: 1,
+            authority_bump: 255,
+            status: MsTransactionStatus::ExecuteReady { timestamp: 0 },
+            instruction_index,
+            bump: 255,
+            approved: Vec::new(),
+            rejected: Vec::new(),
+            cancelled: Vec::new(),
+            executed_index: 0,
+            execution_delay: 0,
+            activated_at: 0,
+        }
+    }
+
+    /// A transaction of `count` instructions touching `keys` new accounts each, ie. an airdrop
+    fn batch(count: u8, keys: usize) -> (MsTransaction, Vec<MsInstruction>) {
+        let tx = transaction(count);
+        let (vault, _) = crate::pda::get_authority_pda(&tx.ms, 1, &squads_mpl::ID);
+        let program = Pubkey::new_unique();
+        let instructions = (1..=count)
+            .map(|instruction_index| {
+                let mut metas = vec![MsAccountMeta { pubkey: vault, is_signer: true, is_writable: true }];
+                metas.extend((0..keys).map(|_| MsAccountMeta {
+                    pubkey: Pubkey::new_unique(),
+                    is_signer: false,
+                    is_writable: true,
+                }));
+                MsInstruction {
+                    program_id: program,
+                    keys: metas,
+                    data: vec![0; 8],
+                    instruction_index,
+                    bump: 255,
+                }
+            })
+            .collect();
+        (tx, instructions)
+    }
+
+    #[test]
+    fn large_transactions_fit_with_a_lookup_table() {
+        let (tx, instructions) = batch(8, 10);
+        let member = Pubkey::new_unique();
+
+        // 8 instructions of 10 destinations are way over the size of a legacy transaction
+        let ix = execute_transaction(member, &tx, &instructions).unwrap();
+        let legacy_message = Message::new(std::slice::from_ref(&ix), Some(&member));
+        assert!(legacy_message.serialize().len() > MAX_MESSAGE_SIZE);
+
+        let addresses = lookup_table_addresses(&tx, &instructions).unwrap();
+        let table = AddressLookupTableAccount { key: Pubkey::new_unique(), addresses };
+        let message = v0::Message::try_compile(&member, &[ix], &[table], Hash::default()).unwrap();
+
+        // only the member and the squads program are not loaded from the table
+        assert_eq!(message.account_keys, vec![member, squads_mpl::ID]);
+        assert!(VersionedMessage::V0(message).serialize().len() <= MAX_MESSAGE_SIZE);
+    }
+
+    #[test]
+    fn signers_are_not_stored() {
+        let (tx, mut instructions) = batch(1, 1);
+        let signer = Pubkey::new_unique();
+        instructions[0].keys.push(MsAccountMeta { pubkey: signer, is_signer: true, is_writable: false });
+
+        let addresses = lookup_table_addresses(&tx, &instructions).unwrap();
+
+        let (vault, _) = crate::pda::get_authority_pda(&tx.ms, 1, &squads_mpl::ID);
+        assert!(!addresses.contains(&signer));
+        // the authority is signed for by the program, so it can be loaded from the table
+        assert!(addresses.contains(&vault));
+        assert_eq!(addresses[0], tx.ms);
+    }
+
+    #[test]
+    fn addresses_are_added_in_chunks() {
+        let authority = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let addresses: Vec<_> = (0..45).map(|_| Pubkey::new_unique()).collect();
+
+        let (lookup_table, instructions) = create_lookup_table(authority, payer, 42, &addresses);
+
+        let (_, expected) = alt::create_lookup_table(authority, payer, 42);
+        assert_eq!(lookup_table, expected);
+        // create, then 20 + 20 + 5 addresses
+        assert_eq!(instructions.len(), 4);
+        assert!(instructions.iter().all(|ix| ix.program_id == address_lookup_table::program::ID));
+        assert_eq!(instructions[1].accounts[0].pubkey, lookup_table);
+    }
+}
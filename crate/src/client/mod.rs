@@ -0,0 +1,6 @@
+//! Off-chain client helpers, building the instructions of the squads-mpl program
+//! so they can be submitted in regular Solana transactions.
+
+pub mod execute;
+pub mod instructions;
+pub mod lookup_table;
@@ -0,0 +1,210 @@
+//! Decoders for the events emitted by the squads-mpl program.
+//!
+//! Events are emitted with `emit!`, which logs them as `Program data: <base64>`,
+//! the base64 payload being the 8 bytes discriminator of the event followed by
+//! its borsh serialized fields.
+
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+pub use squads_mpl::events::*;
+
+const PROGRAM_DATA: &str = "Program data: ";
+
+macro_rules! squads_events {
+    ($($event:ident),* $(,)?) => {
+        /// Any event emitted by the squads-mpl program.
+        #[derive(Debug, Clone)]
+        pub enum SquadsEvent {
+            $($event($event),)*
+        }
+
+        impl SquadsEvent {
+            /// Names of every decodable event.
+            pub const NAMES: &'static [&'static str] = &[$(stringify!($event),)*];
+
+            /// Decodes an event from its discriminator and borsh serialized fields.
+            /// Returns `None` if the discriminator is unknown or the data is invalid.
+            pub fn decode(data: &[u8]) -> Option<Self> {
+                if data.len() < 8 {
+                    return None;
+                }
+                let (discriminator, mut fields) = data.split_at(8);
+                $(
+                    if discriminator == $event::DISCRIMINATOR {
+                        return $event::deserialize(&mut fields).ok().map(SquadsEvent::$event);
+                    }
+                )*
+                None
+            }
+        }
+    };
+}
+
+squads_events!(
+    MultisigCreated,
+    MemberAdded,
+    MemberRemoved,
+    ThresholdChanged,
+    AuthorityAdded,
+    MultisigSettingsChanged,
+    GuardianChangeProposed,
+    GuardianChangeVetoed,
+    MultisigFrozen,
+    MultisigUnfrozen,
+    RecoveryProposed,
+    RecoveryVetoed,
+    RecoveryFinalized,
+    AuthorityPolicySet,
+    AuthorityPolicyRemoved,
+    TransactionCreated,
+    InstructionAdded,
+    InstructionUpdated,
+    InstructionRemoved,
+    TransactionActivated,
+    TransactionVoted,
+    TransactionCancelVoted,
+    TransactionExecuted,
+    TransactionExpired,
+    TransactionVetoed,
+    TransactionClosed,
+    InstructionClosed,
+    SpendingLimitCreated,
+    SpendingLimitRemoved,
+    SpendingLimitPaused,
+    SpendingLimitUsed,
+    GuardianSetChanged,
+    GuardianActionCreated,
+    GuardianActionApproved,
+    GuardianActionExecuted,
+    MultisigMigrated,
+    SpendingLimitMigrated,
+);
+
+// fails to compile when an event of the program has no decoder above
+#[cfg(program_source)]
+macro_rules! decoded {
+    ($($event:ident),*) => { $(const _: fn($event) -> SquadsEvent = SquadsEvent::$event;)* };
+}
+#[cfg(program_source)]
+program_events!(decoded);
+
+/// Decodes the payload of a `Program data: ` log line.
+pub fn decode_log_data(data: &str) -> Option<SquadsEvent> {
+    let data = base64::decode(data).ok()?;
+    SquadsEvent::decode(&data)
+}
+
+/// Extracts the squads-mpl events from the log messages of a transaction.
+///
+/// The invocation stack is tracked from the `invoke` / `success` / `failed` lines, so that
+/// only the data logged while the squads-mpl program is executing is decoded, including
+/// when it is invoked through a CPI.
+pub fn parse_logs<S: AsRef<str>>(logs: &[S]) -> Vec<SquadsEvent> {
+    let program_id = squads_mpl::ID.to_string();
+    let mut stack: Vec<&str> = Vec::new();
+    let mut events = Vec::new();
+
+    for log in logs.iter().map(AsRef::as_ref) {
+        if let Some(data) = log.strip_prefix(PROGRAM_DATA) {
+            if stack.last() == Some(&program_id.as_str()) {
+                events.extend(decode_log_data(data));
+            }
+        } else if let Some(rest) = log.strip_prefix("Program ") {
+            let mut words = rest.split_whitespace();
+            let (Some(program), Some(status)) = (words.next(), words.next()) else {
+                continue;
+            };
+            if status == "invoke" {
+                stack.push(program);
+            } else if status == "success" || status.starts_with("failed") {
+                stack.pop();
+            }
+        }
+    }
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Event;
+    use squads_mpl::state::MsTransactionStatus;
+
+    fn log_data(event: &impl Event) -> String {
+        format!("{}{}", PROGRAM_DATA, base64::encode(event.data()))
+    }
+
+    #[test]
+    fn events_roundtrip() {
+        let voted = TransactionVoted {
+            multisig: Pubkey::new_unique(),
+            transaction: Pubkey::new_unique(),
+            member: Pubkey::new_unique(),
+            approve: true,
+            status: MsTransactionStatus::ExecuteReady { timestamp: 42 },
+        };
+        match SquadsEvent::decode(&voted.data()) {
+            Some(SquadsEvent::TransactionVoted(decoded)) => {
+                assert_eq!(decoded.transaction, voted.transaction);
+                assert_eq!(decoded.member, voted.member);
+                assert!(decoded.approve);
+                assert_eq!(decoded.status, voted.status);
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+
+        let threshold = ThresholdChanged { multisig: Pubkey::new_unique(), threshold: 3 };
+        assert!(matches!(
+            SquadsEvent::decode(&threshold.data()),
+            Some(SquadsEvent::ThresholdChanged(ThresholdChanged { threshold: 3, .. }))
+        ));
+    }
+
+    #[test]
+    fn unknown_or_invalid_data_is_ignored() {
+        assert!(SquadsEvent::decode(&[1, 2, 3]).is_none());
+        assert!(SquadsEvent::decode(&[0; 64]).is_none());
+
+        // valid discriminator, truncated fields
+        let mut data = ThresholdChanged { multisig: Pubkey::new_unique(), threshold: 3 }.data();
+        data.truncate(20);
+        assert!(SquadsEvent::decode(&data).is_none());
+
+        assert!(decode_log_data("not base64!").is_none());
+    }
+
+    #[test]
+    fn logs_are_filtered_by_program() {
+        let other_program = Pubkey::new_unique();
+        let squads = squads_mpl::ID;
+        let multisig = Pubkey::new_unique();
+        let emitted = AuthorityAdded { multisig, authority_index: 2 };
+        let cpi_emitted = ThresholdChanged { multisig, threshold: 2 };
+        let logs = vec![
+            format!("Program {} invoke [1]", squads),
+            "Program log: Instruction: AddAuthority".to_string(),
+            log_data(&emitted),
+            format!("Program {} consumed 5000 of 200000 compute units", squads),
+            format!("Program {} success", squads),
+            format!("Program {} invoke [1]", other_program),
+            // same payload logged by another program, must be ignored
+            log_data(&cpi_emitted),
+            format!("Program {} invoke [2]", squads),
+            log_data(&cpi_emitted),
+            format!("Program {} success", squads),
+            format!("Program {} success", other_program),
+        ];
+
+        let events = parse_logs(&logs);
+        assert_eq!(events.len(), 2);
+        assert!(matches!(
+            &events[0],
+            SquadsEvent::AuthorityAdded(AuthorityAdded { authority_index: 2, .. })
+        ));
+        assert!(matches!(
+            &events[1],
+            SquadsEvent::ThresholdChanged(ThresholdChanged { threshold: 2, .. })
+        ));
+    }
+}
@@ -2,21 +2,52 @@ pub use squads_mpl::errors;
 pub use squads_mpl::ID;
 pub use squads_mpl::program::SquadsMpl;
 
+// program_instructions!, program_accounts! and program_events!, listing the instructions, accounts
+// and events of the program
+include!(concat!(env!("OUT_DIR"), "/program.rs"));
+
+pub mod pda;
+pub mod client;
+pub mod events;
+
 
 pub mod state {
     pub use squads_mpl::state::{
-       IncomingInstruction, MsTransaction, MsAccountMeta, Ms, MsTransactionStatus, Member
+       IncomingInstruction, MsTransaction, MsInstruction, MsAccountMeta, Ms, MsTransactionStatus, Member, TimeLockMode, ExecutionPolicy,
+       AuthorityPolicy, SpendingLimit, Period, SpendingLimitMode, Spend, GuardianSet, GuardianAction, PendingGuardianChange,
+       MemberRecovery, MemberReplacement,
+       PERMISSION_INITIATE, PERMISSION_VOTE, PERMISSION_EXECUTE, PERMISSION_ALL,
+       MAX_TIME_LOCK, MAX_PROPOSAL_LIFETIME, MAX_GUARDIANS, GUARDIAN_CHANGE_DELAY, MAX_GUARDIAN_VETOES,
+       MAX_FREEZE_DURATION, FREEZE_COOLDOWN, RECOVERY_DELAY,
+       MAX_SPENDING_LIMIT_DESTINATIONS, MAX_SPENDING_LIMIT_MEMBERS, MAX_ROLLING_SPENDS, MAX_EXECUTORS,
+       InstructionBuffer, MAX_INSTRUCTION_BUFFER_SIZE, LegacyMs, LegacyMember, LegacySpendingLimit
     };
+
+    // fails to compile when an account of the program is not re-exported above
+    #[cfg(program_source)]
+    macro_rules! reexported {
+        ($($account:ident),*) => { $(const _: Option<$account> = None;)* };
+    }
+    #[cfg(program_source)]
+    program_accounts!(reexported);
 }
 
 pub mod cpi {
     use anchor_lang::prelude::{CpiContext, Result, Pubkey};
 
     pub use squads_mpl::cpi::accounts::{
-        ActivateTransaction, VoteTransaction, AddInstruction,CancelTransaction, Create, CreateTransaction, ExecuteInstruction, MsAuth, MsAuthRealloc, ExecuteTransaction
+        ActivateTransaction, VoteTransaction, AddInstruction,CancelTransaction,
+        UpdateInstruction, RemoveInstruction, CreateInstructionBuffer, WriteInstructionBuffer, AddInstructionFromBuffer, CloseInstructionBuffer, Create, CreateTransaction, ExecuteInstruction, MsAuth, MsAuthRealloc, ExecuteTransaction,
+        ExpireTransaction, CloseTransaction, CloseInstruction, RemoveMemberWithGuardian,
+        FinalizeGuardianChange, VetoGuardianChange, VetoTransaction, FreezeMultisig,
+        ProposeRecovery, VetoRecovery, FinalizeRecovery,
+        CreateAuthorityPolicy, UpdateAuthorityPolicy, RemoveAuthorityPolicy,
+        CreateSpendingLimit, RemoveSpendingLimit, PauseSpendingLimit, SpendingLimitUse,
+        CreateGuardianSet, UpdateGuardianSet, CreateGuardianAction, ApproveGuardianAction, ExecuteGuardianAction,
+        MigrateMultisig, MigrateSpendingLimit
     };
 
-    use squads_mpl::state::Member;
+    use squads_mpl::state::{IncomingInstruction, Member, MemberReplacement, Period, SpendingLimitMode, TimeLockMode, ExecutionPolicy};
     
     pub fn create_multisig<'info>(
         ctx: CpiContext<'_, '_, '_, 'info, Create<'info>>,
@@ -61,6 +92,48 @@ pub mod cpi {
         squads_mpl::cpi::add_instruction(ctx, incoming_instruction)
     }
 
+    pub fn update_instruction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, UpdateInstruction<'info>>,
+        incoming_instruction: IncomingInstruction,
+    ) -> Result<()> {
+        squads_mpl::cpi::update_instruction(ctx, incoming_instruction)
+    }
+
+    pub fn remove_instruction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, RemoveInstruction<'info>>,
+        instruction_index: u8,
+    ) -> Result<()> {
+        squads_mpl::cpi::remove_instruction(ctx, instruction_index)
+    }
+
+    pub fn create_instruction_buffer<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CreateInstructionBuffer<'info>>,
+        final_hash: [u8; 32],
+        final_size: u32,
+        chunk: Vec<u8>,
+    ) -> Result<()> {
+        squads_mpl::cpi::create_instruction_buffer(ctx, final_hash, final_size, chunk)
+    }
+
+    pub fn write_instruction_buffer<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, WriteInstructionBuffer<'info>>,
+        chunk: Vec<u8>,
+    ) -> Result<()> {
+        squads_mpl::cpi::write_instruction_buffer(ctx, chunk)
+    }
+
+    pub fn add_instruction_from_buffer<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, AddInstructionFromBuffer<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::add_instruction_from_buffer(ctx)
+    }
+
+    pub fn close_instruction_buffer<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CloseInstructionBuffer<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::close_instruction_buffer(ctx)
+    }
+
 
     pub fn approve_transaction<'info>(
         ctx: CpiContext<'_, '_, '_, 'info, VoteTransaction<'info>>,
@@ -108,5 +181,299 @@ pub mod cpi {
         squads_mpl::cpi::remove_member(ctx, member)
     }
 
+    pub fn set_rent_collector<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+        rent_collector: Option<Pubkey>,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_rent_collector(ctx, rent_collector)
+    }
+
+    pub fn set_proposal_lifetime<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+        proposal_lifetime: u32,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_proposal_lifetime(ctx, proposal_lifetime)
+    }
+
+    pub fn set_time_lock_mode<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+        time_lock_mode: TimeLockMode,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_time_lock_mode(ctx, time_lock_mode)
+    }
+
+    pub fn set_execution_policy<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+        execution_policy: ExecutionPolicy,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_execution_policy(ctx, execution_policy)
+    }
+
+    pub fn update_multisig_settings<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+        new_time_lock: u32,
+        spending_limit_enabled: bool,
+        guardian: Option<Pubkey>,
+    ) -> Result<()> {
+        squads_mpl::cpi::update_multisig_settings(ctx, new_time_lock, spending_limit_enabled, guardian)
+    }
+
+    pub fn propose_guardian_change<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+        guardian: Option<Pubkey>,
+    ) -> Result<()> {
+        squads_mpl::cpi::propose_guardian_change(ctx, guardian)
+    }
+
+    pub fn finalize_guardian_change<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, FinalizeGuardianChange<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::finalize_guardian_change(ctx)
+    }
+
+    pub fn veto_guardian_change<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, VetoGuardianChange<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::veto_guardian_change(ctx)
+    }
+
+    pub fn freeze_multisig<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, FreezeMultisig<'info>>,
+        duration: u32,
+    ) -> Result<()> {
+        squads_mpl::cpi::freeze_multisig(ctx, duration)
+    }
+
+    pub fn unfreeze_multisig<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::unfreeze_multisig(ctx)
+    }
+
+    pub fn propose_recovery<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ProposeRecovery<'info>>,
+        replacements: Vec<MemberReplacement>,
+    ) -> Result<()> {
+        squads_mpl::cpi::propose_recovery(ctx, replacements)
+    }
+
+    pub fn veto_recovery<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, VetoRecovery<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::veto_recovery(ctx)
+    }
+
+    pub fn finalize_recovery<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, FinalizeRecovery<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::finalize_recovery(ctx)
+    }
+
+    pub fn remove_member_with_guardian<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, RemoveMemberWithGuardian<'info>>,
+        old_member: Pubkey,
+    ) -> Result<()> {
+        squads_mpl::cpi::remove_member_with_guardian(ctx, old_member)
+    }
+
+    pub fn expire_transaction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ExpireTransaction<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::expire_transaction(ctx)
+    }
+
+    pub fn veto_transaction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, VetoTransaction<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::veto_transaction(ctx)
+    }
+
+    pub fn close_transaction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CloseTransaction<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::close_transaction(ctx)
+    }
+
+    pub fn close_instruction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CloseInstruction<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::close_instruction(ctx)
+    }
+
+    pub fn add_authority_policy<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CreateAuthorityPolicy<'info>>,
+        authority_index: u32,
+        threshold: u16,
+        time_lock: u32,
+    ) -> Result<()> {
+        squads_mpl::cpi::add_authority_policy(ctx, authority_index, threshold, time_lock)
+    }
+
+    pub fn update_authority_policy<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, UpdateAuthorityPolicy<'info>>,
+        threshold: u16,
+        time_lock: u32,
+    ) -> Result<()> {
+        squads_mpl::cpi::update_authority_policy(ctx, threshold, time_lock)
+    }
+
+    pub fn remove_authority_policy<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, RemoveAuthorityPolicy<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::remove_authority_policy(ctx)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_spending_limit<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CreateSpendingLimit<'info>>,
+        create_key: Pubkey,
+        mint: Pubkey,
+        authority_index: u32,
+        amount: u64,
+        members: Vec<Pubkey>,
+        destinations: Vec<Pubkey>,
+        period: Period,
+        mode: SpendingLimitMode,
+        expiration: Option<i64>,
+    ) -> Result<()> {
+        squads_mpl::cpi::add_spending_limit(ctx, create_key, mint, authority_index, amount, members, destinations, period, mode, expiration)
+    }
+
+    pub fn remove_spending_limit<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, RemoveSpendingLimit<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::remove_spending_limit(ctx)
+    }
 
+    pub fn pause_spending_limit<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, PauseSpendingLimit<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::pause_spending_limit(ctx)
+    }
+
+    pub fn spending_limit_use<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, SpendingLimitUse<'info>>,
+        amount: u64,
+        decimals: u8,
+    ) -> Result<()> {
+        squads_mpl::cpi::spending_limit_use(ctx, amount, decimals)
+    }
+
+    pub fn create_guardian_set<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CreateGuardianSet<'info>>,
+        guardians: Vec<Pubkey>,
+        threshold: u16,
+    ) -> Result<()> {
+        squads_mpl::cpi::create_guardian_set(ctx, guardians, threshold)
+    }
+
+    pub fn update_guardian_set<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, UpdateGuardianSet<'info>>,
+        guardians: Vec<Pubkey>,
+        threshold: u16,
+    ) -> Result<()> {
+        squads_mpl::cpi::update_guardian_set(ctx, guardians, threshold)
+    }
+
+    pub fn create_guardian_action<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CreateGuardianAction<'info>>,
+        instruction: IncomingInstruction,
+    ) -> Result<()> {
+        squads_mpl::cpi::create_guardian_action(ctx, instruction)
+    }
+
+    pub fn approve_guardian_action<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ApproveGuardianAction<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::approve_guardian_action(ctx)
+    }
+
+    pub fn execute_guardian_action<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ExecuteGuardianAction<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::execute_guardian_action(ctx)
+    }
+
+    pub fn migrate_multisig<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MigrateMultisig<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::migrate_multisig(ctx)
+    }
+
+    pub fn migrate_spending_limit<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MigrateSpendingLimit<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::migrate_spending_limit(ctx)
+    }
+
+
+    #[cfg(program_source)]
+    macro_rules! instruction_enum {
+        ($($instruction:ident),*) => {
+            #[allow(dead_code)]
+            enum Instruction { $($instruction(squads_mpl::instruction::$instruction),)* }
+        };
+    }
+    #[cfg(program_source)]
+    program_instructions!(instruction_enum);
+
+    /// Maps every instruction of the program to its wrapper, an instruction without a
+    /// wrapper makes the match non exhaustive and fails to compile.
+    #[cfg(program_source)]
+    #[allow(dead_code)]
+    fn wrapper(instruction: Instruction) {
+        match instruction {
+            Instruction::Create(_) => { let _ = create_multisig; }
+            Instruction::AddMember(_) => { let _ = add_member; }
+            Instruction::RemoveMember(_) => { let _ = remove_member; }
+            Instruction::ChangeThreshold(_) => { let _ = change_threshold; }
+            Instruction::AddAuthority(_) => { let _ = add_authority; }
+            Instruction::SetRentCollector(_) => { let _ = set_rent_collector; }
+            Instruction::SetProposalLifetime(_) => { let _ = set_proposal_lifetime; }
+            Instruction::SetTimeLockMode(_) => { let _ = set_time_lock_mode; }
+            Instruction::SetExecutionPolicy(_) => { let _ = set_execution_policy; }
+            Instruction::CreateTransaction(_) => { let _ = create_transaction; }
+            Instruction::ActivateTransaction(_) => { let _ = activate_transaction; }
+            Instruction::AddInstruction(_) => { let _ = add_instruction; }
+            Instruction::UpdateInstruction(_) => { let _ = update_instruction; }
+            Instruction::RemoveInstruction(_) => { let _ = remove_instruction; }
+            Instruction::CreateInstructionBuffer(_) => { let _ = create_instruction_buffer; }
+            Instruction::WriteInstructionBuffer(_) => { let _ = write_instruction_buffer; }
+            Instruction::AddInstructionFromBuffer(_) => { let _ = add_instruction_from_buffer; }
+            Instruction::CloseInstructionBuffer(_) => { let _ = close_instruction_buffer; }
+            Instruction::ApproveTransaction(_) => { let _ = approve_transaction; }
+            Instruction::RejectTransaction(_) => { let _ = reject_transaction; }
+            Instruction::CancelTransaction(_) => { let _ = cancel_transaction; }
+            Instruction::ExecuteTransaction(_) => { let _ = execute_transaction; }
+            Instruction::ExecuteInstruction(_) => { let _ = execute_instruction; }
+            Instruction::ExpireTransaction(_) => { let _ = expire_transaction; }
+            Instruction::VetoTransaction(_) => { let _ = veto_transaction; }
+            Instruction::CloseTransaction(_) => { let _ = close_transaction; }
+            Instruction::CloseInstruction(_) => { let _ = close_instruction; }
+            Instruction::UpdateMultisigSettings(_) => { let _ = update_multisig_settings; }
+            Instruction::RemoveMemberWithGuardian(_) => { let _ = remove_member_with_guardian; }
+            Instruction::AddAuthorityPolicy(_) => { let _ = add_authority_policy; }
+            Instruction::UpdateAuthorityPolicy(_) => { let _ = update_authority_policy; }
+            Instruction::RemoveAuthorityPolicy(_) => { let _ = remove_authority_policy; }
+            Instruction::AddSpendingLimit(_) => { let _ = add_spending_limit; }
+            Instruction::RemoveSpendingLimit(_) => { let _ = remove_spending_limit; }
+            Instruction::PauseSpendingLimit(_) => { let _ = pause_spending_limit; }
+            Instruction::SpendingLimitUse(_) => { let _ = spending_limit_use; }
+            Instruction::ProposeGuardianChange(_) => { let _ = propose_guardian_change; }
+            Instruction::FinalizeGuardianChange(_) => { let _ = finalize_guardian_change; }
+            Instruction::VetoGuardianChange(_) => { let _ = veto_guardian_change; }
+            Instruction::FreezeMultisig(_) => { let _ = freeze_multisig; }
+            Instruction::UnfreezeMultisig(_) => { let _ = unfreeze_multisig; }
+            Instruction::ProposeRecovery(_) => { let _ = propose_recovery; }
+            Instruction::VetoRecovery(_) => { let _ = veto_recovery; }
+            Instruction::FinalizeRecovery(_) => { let _ = finalize_recovery; }
+            Instruction::CreateGuardianSet(_) => { let _ = create_guardian_set; }
+            Instruction::UpdateGuardianSet(_) => { let _ = update_guardian_set; }
+            Instruction::CreateGuardianAction(_) => { let _ = create_guardian_action; }
+            Instruction::ApproveGuardianAction(_) => { let _ = approve_guardian_action; }
+            Instruction::ExecuteGuardianAction(_) => { let _ = execute_guardian_action; }
+            Instruction::MigrateMultisig(_) => { let _ = migrate_multisig; }
+            Instruction::MigrateSpendingLimit(_) => { let _ = migrate_spending_limit; }
+        }
+    }
 }
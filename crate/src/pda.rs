@@ -0,0 +1,233 @@
+//! PDA derivation helpers, matching the seeds used by the squads-mpl program.
+//! Every function returns the address along with its canonical bump.
+
+use anchor_lang::prelude::Pubkey;
+
+/// Prefix shared by every squads-mpl PDA.
+pub const SEED_PREFIX: &[u8] = b"squad";
+pub const SEED_MULTISIG: &[u8] = b"multisig";
+pub const SEED_TRANSACTION: &[u8] = b"transaction";
+pub const SEED_INSTRUCTION: &[u8] = b"instruction";
+pub const SEED_AUTHORITY: &[u8] = b"authority";
+pub const SEED_SPENDING_LIMIT: &[u8] = b"spending_limit";
+pub const SEED_AUTHORITY_POLICY: &[u8] = b"policy";
+pub const SEED_GUARDIAN_SET: &[u8] = b"guardian_set";
+pub const SEED_GUARDIAN_ACTION: &[u8] = b"guardian_action";
+pub const SEED_RECOVERY: &[u8] = b"recovery";
+pub const SEED_INSTRUCTION_BUFFER: &[u8] = b"instruction_buffer";
+
+/// Multisig (Ms) account: `[b"squad", create_key, b"multisig"]`
+pub fn get_ms_pda(create_key: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, create_key.as_ref(), SEED_MULTISIG],
+        program_id,
+    )
+}
+
+/// Transaction (MsTransaction) account: `[b"squad", multisig, transaction_index (u32 le), b"transaction"]`
+pub fn get_tx_pda(multisig: &Pubkey, transaction_index: u32, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEED_PREFIX,
+            multisig.as_ref(),
+            &transaction_index.to_le_bytes(),
+            SEED_TRANSACTION,
+        ],
+        program_id,
+    )
+}
+
+/// Instruction (MsInstruction) account: `[b"squad", transaction, instruction_index (u8), b"instruction"]`
+pub fn get_ix_pda(transaction: &Pubkey, instruction_index: u8, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEED_PREFIX,
+            transaction.as_ref(),
+            &instruction_index.to_le_bytes(),
+            SEED_INSTRUCTION,
+        ],
+        program_id,
+    )
+}
+
+/// Authority (vault, upgrade authority, etc.): `[b"squad", multisig, authority_index (u32 le), b"authority"]`
+///
+/// The authority index 0 is reserved for internal transactions, which are signed by the multisig PDA itself.
+pub fn get_authority_pda(multisig: &Pubkey, authority_index: u32, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEED_PREFIX,
+            multisig.as_ref(),
+            &authority_index.to_le_bytes(),
+            SEED_AUTHORITY,
+        ],
+        program_id,
+    )
+}
+
+/// Spending limit account: `[b"squad", multisig, create_key, b"spending_limit"]`
+pub fn get_spending_limit_pda(multisig: &Pubkey, create_key: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEED_PREFIX,
+            multisig.as_ref(),
+            create_key.as_ref(),
+            SEED_SPENDING_LIMIT,
+        ],
+        program_id,
+    )
+}
+
+/// Authority policy account: `[b"squad", multisig, authority_index (u32 le), b"policy"]`
+pub fn get_authority_policy_pda(multisig: &Pubkey, authority_index: u32, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEED_PREFIX,
+            multisig.as_ref(),
+            &authority_index.to_le_bytes(),
+            SEED_AUTHORITY_POLICY,
+        ],
+        program_id,
+    )
+}
+
+/// Guardian set account: `[b"squad", multisig, b"guardian_set"]`
+pub fn get_guardian_set_pda(multisig: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, multisig.as_ref(), SEED_GUARDIAN_SET],
+        program_id,
+    )
+}
+
+/// Guardian action account: `[b"squad", guardian_set, action_index (u32 le), b"guardian_action"]`
+pub fn get_guardian_action_pda(guardian_set: &Pubkey, action_index: u32, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEED_PREFIX,
+            guardian_set.as_ref(),
+            &action_index.to_le_bytes(),
+            SEED_GUARDIAN_ACTION,
+        ],
+        program_id,
+    )
+}
+
+/// Member recovery account: `[b"squad", multisig, b"recovery"]`
+pub fn get_recovery_pda(multisig: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, multisig.as_ref(), SEED_RECOVERY],
+        program_id,
+    )
+}
+
+/// Instruction buffer of a draft transaction: `[b"squad", transaction, b"instruction_buffer"]`
+pub fn get_instruction_buffer_pda(transaction: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, transaction.as_ref(), SEED_INSTRUCTION_BUFFER],
+        program_id,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    // Known addresses for the program id declared by squads-mpl and the multisig created
+    // with the create key [1; 32]. They must never change: with different seeds, the SDK
+    // would no longer find the accounts created by the program.
+
+    fn key(address: &str) -> Pubkey {
+        Pubkey::from_str(address).unwrap()
+    }
+
+    fn multisig() -> Pubkey {
+        key("BhCkUkjPgihvGtZsuzCqhA3RJWSHGiC4cQgpaczbRXW5")
+    }
+
+    fn transaction() -> Pubkey {
+        key("6uFgDzRHmb81x75ygEhhfoquZaAhKpqJsi49hUSxJsr4")
+    }
+
+    #[test]
+    fn ms_pda() {
+        let create_key = Pubkey::new_from_array([1; 32]);
+        assert_eq!(get_ms_pda(&create_key, &squads_mpl::ID), (multisig(), 252));
+    }
+
+    #[test]
+    fn tx_pda() {
+        assert_eq!(get_tx_pda(&multisig(), 1, &squads_mpl::ID), (transaction(), 255));
+    }
+
+    #[test]
+    fn ix_pda() {
+        assert_eq!(
+            get_ix_pda(&transaction(), 1, &squads_mpl::ID),
+            (key("Dahj7ReeXjVeTtqTtDxCkkTxUYpKr44QE9jpar1Z36DA"), 254)
+        );
+    }
+
+    #[test]
+    fn authority_pda() {
+        assert_eq!(
+            get_authority_pda(&multisig(), 1, &squads_mpl::ID),
+            (key("AUYDniHZmZsLY5Y2XVvaJyMKWhqzBXtB3EXPQXHayEwj"), 255)
+        );
+    }
+
+    #[test]
+    fn spending_limit_pda() {
+        let create_key = Pubkey::new_from_array([2; 32]);
+        assert_eq!(
+            get_spending_limit_pda(&multisig(), &create_key, &squads_mpl::ID),
+            (key("FqsUnTArjK4EmDmpbhBwVDokw6dgRPY9FqadFLBKZZsT"), 255)
+        );
+    }
+
+    #[test]
+    fn authority_policy_pda() {
+        assert_eq!(
+            get_authority_policy_pda(&multisig(), 1, &squads_mpl::ID),
+            (key("EEUv1BmnKYN8obsCVDteJzxVXCX9xW27Ac3D3oPskp3k"), 254)
+        );
+    }
+
+    #[test]
+    fn guardian_pdas() {
+        let guardian_set = key("E4UL4rtpkMQH8weBLLvYbob4X5c6j812HgpaCCecoyKY");
+        assert_eq!(get_guardian_set_pda(&multisig(), &squads_mpl::ID), (guardian_set, 254));
+        assert_eq!(
+            get_guardian_action_pda(&guardian_set, 1, &squads_mpl::ID),
+            (key("DiQbMEsL3TMoXq4MrTYLppkipMcMCc6XtfFpTbtbSXga"), 254)
+        );
+    }
+
+    #[test]
+    fn recovery_pda() {
+        assert_eq!(
+            get_recovery_pda(&multisig(), &squads_mpl::ID),
+            (key("2g8Tx8XNFb1HBMzbntyqSzrftJPEcQh32TNZXBpdLKDC"), 254)
+        );
+    }
+
+    #[test]
+    fn instruction_buffer_pda() {
+        assert_eq!(
+            get_instruction_buffer_pda(&transaction(), &squads_mpl::ID),
+            (key("BT6fssTsCERuHmBZGt7bw8JTvNpL7skFiQqZAx7MZTXW"), 255)
+        );
+    }
+
+    #[test]
+    fn bumps_recreate_the_addresses() {
+        let create_key = Pubkey::new_unique();
+        let (multisig, bump) = get_ms_pda(&create_key, &squads_mpl::ID);
+        let recreated = Pubkey::create_program_address(
+            &[SEED_PREFIX, create_key.as_ref(), SEED_MULTISIG, &[bump]],
+            &squads_mpl::ID,
+        )
+        .unwrap();
+        assert_eq!(recreated, multisig);
+    }
+}
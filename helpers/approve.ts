@@ -2,10 +2,18 @@
 // runs through a multisig we pre-specified member list to approve a transaction
 
 import * as anchor from "@coral-xyz/anchor";
+import BN from "bn.js";
+import { getAuthorityPolicyPDA } from "../sdk/src/address";
 
 
 export const memberListApprove = async (memberList, msPDA, txPDA, squads, provider, program ) => {
     let msState = await program.account.ms.fetch(msPDA);
+    const txState = await squads.getTransaction(txPDA);
+    const [authorityPolicyPDA] = getAuthorityPolicyPDA(
+        msPDA,
+        new BN(txState.authorityIndex, 10),
+        program.programId
+    );
     for (let i = 0; i < memberList.length; i++) {
         // check to see if we need more signers
         const approvalState = await squads.getTransaction(txPDA);
@@ -13,9 +21,9 @@ export const memberListApprove = async (memberList, msPDA, txPDA, squads, provid
             break;
         }
 
-        const inMultisig = (msState.keys as anchor.web3.PublicKey[]).findIndex(
+        const inMultisig = (msState.keys as { key: anchor.web3.PublicKey }[]).findIndex(
         (k) => {
-            return k.toBase58() == memberList[i].publicKey.toBase58();
+            return k.key.toBase58() == memberList[i].publicKey.toBase58();
         }
         );
         if (inMultisig < 0) {
@@ -32,6 +40,7 @@ export const memberListApprove = async (memberList, msPDA, txPDA, squads, provid
                 multisig: msPDA,
                 transaction: txPDA,
                 member: memberList[i].publicKey,
+                authorityPolicy: authorityPolicyPDA,
                 })
                 .signers([memberList[i]])
                 .transaction();
@@ -141,6 +141,97 @@ export type SquadsMpl = {
       ],
       "args": []
     },
+    {
+      "name": "setRentCollector",
+      "docs": [
+        "The instruction to set the account receiving the rent of closed",
+        "transactions and instructions. If set to None, the rent goes back",
+        "to the creator of each transaction."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "rentCollector",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setProposalLifetime",
+      "docs": [
+        "The instruction to change the lifetime of the multisig transactions.",
+        "Active and ExecuteReady transactions activated more than the lifetime ago can no",
+        "longer be approved or executed. A lifetime of 0 disables the expiry."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "proposalLifetime",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "setTimeLockMode",
+      "docs": [
+        "The instruction to choose when the time lock applies. In the voting mode",
+        "(the default) members wait the time lock after activation before approving,",
+        "in the execution mode they wait it after approval before executing, during",
+        "which the transaction can still be cancelled."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "timeLockMode",
+          "type": {
+            "defined": "TimeLockMode"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setExecutionPolicy",
+      "docs": [
+        "The instruction to let designated executors, or anyone, execute the",
+        "ExecuteReady transactions, ie. keeper bots that are not members.",
+        "Members with the execute permission can always execute them."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "executionPolicy",
+          "type": {
+            "defined": "ExecutionPolicy"
+          }
+        }
+      ]
+    },
     {
       "name": "createTransaction",
       "docs": [
@@ -249,10 +340,10 @@ export type SquadsMpl = {
       ]
     },
     {
-      "name": "approveTransaction",
+      "name": "updateInstruction",
       "docs": [
-        "Instruction to approve a transaction on behalf of a member.",
-        "The transaction must have an \"active\" status"
+        "Instruction to replace an instruction attached to a draft transaction,",
+        "ie. to fix a mistake without abandoning the transaction."
       ],
       "accounts": [
         {
@@ -262,22 +353,42 @@ export type SquadsMpl = {
         },
         {
           "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "member",
+          "name": "creator",
           "isMut": true,
           "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "incomingInstruction",
+          "type": {
+            "defined": "IncomingInstruction"
+          }
+        }
+      ]
     },
     {
-      "name": "rejectTransaction",
+      "name": "removeInstruction",
       "docs": [
-        "Instruction to reject a transaction.",
-        "The transaction must have an \"active\" status."
+        "Instruction to remove an instruction attached to a draft transaction and reclaim",
+        "its rent. Every following instruction is moved to the previous index, so that the",
+        "instruction indexes stay contiguous, and the last instruction account is closed.",
+        "The instruction accounts from the removed one to the one before the last are",
+        "expected in the remaining accounts."
       ],
       "accounts": [
         {
@@ -291,35 +402,48 @@ export type SquadsMpl = {
           "isSigner": false
         },
         {
-          "name": "member",
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
           "isMut": true,
           "isSigner": true
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "instructionIndex",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "cancelTransaction",
+      "name": "createInstructionBuffer",
       "docs": [
-        "Instruction to cancel a transaction.",
-        "Transactions must be in the \"executeReady\" status.",
-        "Transaction will only be cancelled if the number of",
-        "cancellations reaches the threshold. A cancelled",
-        "transaction will no longer be able to be executed."
+        "Instruction to create the buffer of an instruction too large to be attached with",
+        "add_instruction, with the sha256 hash and size of the serialized IncomingInstruction.",
+        "The first chunk is written right away, the others with write_instruction_buffer."
       ],
       "accounts": [
         {
           "name": "multisig",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
           "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructionBuffer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "member",
+          "name": "creator",
           "isMut": true,
           "isSigner": true
         },
@@ -329,63 +453,70 @@ export type SquadsMpl = {
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "finalHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "finalSize",
+          "type": "u32"
+        },
+        {
+          "name": "chunk",
+          "type": "bytes"
+        }
+      ]
     },
     {
-      "name": "executeTransaction",
+      "name": "writeInstructionBuffer",
       "docs": [
-        "Instruction to execute a transaction.",
-        "Transaction status must be \"executeReady\", and the account list must match",
-        "the unique indexed accounts in the following manner:",
-        "[ix_1_account, ix_1_program_account, ix_1_remaining_account_1, ix_1_remaining_account_2, ...]",
-        "",
-        "Refer to the README for more information on how to construct the account list."
+        "Instruction to append a chunk to the instruction buffer of a draft transaction."
       ],
       "accounts": [
         {
           "name": "multisig",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
           "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructionBuffer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "member",
+          "name": "creator",
           "isMut": true,
           "isSigner": true
         }
       ],
       "args": [
         {
-          "name": "accountList",
+          "name": "chunk",
           "type": "bytes"
         }
       ]
     },
     {
-      "name": "executeInstruction",
+      "name": "addInstructionFromBuffer",
       "docs": [
-        "Instruction to sequentially execute attached instructions.",
-        "Instructions executed in this matter must be executed in order,",
-        "this may be helpful for processing large batch transfers.",
-        "This instruction can only be used for transactions with an authority",
-        "index of 1 or greater.",
-        "",
-        "NOTE - do not use this instruction if there is not total clarity around",
-        "potential side effects, as this instruction implies that the approved",
-        "transaction will be executed partially, and potentially spread out over",
-        "a period of time. This could introduce problems with state and failed",
-        "transactions. For example: a program invoked in one of these instructions",
-        "may be upgraded between executions and potentially leave one of the",
-        "necessary accounts in an invalid state."
+        "Instruction to attach the instruction of a complete buffer to a draft transaction,",
+        "once validated against its hash. The buffer is closed."
       ],
       "accounts": [
         {
           "name": "multisig",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -393,768 +524,709 @@ export type SquadsMpl = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "instructionBuffer",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "instruction",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "member",
+          "name": "creator",
           "isMut": true,
           "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": []
     },
     {
-      "name": "updateMultisigSettings",
+      "name": "closeInstructionBuffer",
       "docs": [
-        "The instruction to update the multisig settings."
+        "Instruction to close an instruction buffer that won't be attached and reclaim its rent,",
+        "including once its transaction is closed."
       ],
       "accounts": [
         {
-          "name": "multisig",
-          "isMut": true,
-          "isSigner": true
-        }
-      ],
-      "args": [
-        {
-          "name": "newTimeLock",
-          "type": "u32"
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "spendingLimitEnabled",
-          "type": "bool"
+          "name": "instructionBuffer",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "guardian",
-          "type": {
-            "option": "publicKey"
-          }
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "removeMemberWithGuardian",
+      "name": "approveTransaction",
+      "docs": [
+        "Instruction to approve a transaction on behalf of a member.",
+        "The transaction must have an \"active\" status"
+      ],
       "accounts": [
         {
           "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "remover",
+          "name": "member",
           "isMut": true,
           "isSigner": true
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "oldMember",
-          "type": "publicKey"
+          "name": "authorityPolicy",
+          "isMut": false,
+          "isSigner": false
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "addSpendingLimit",
+      "name": "rejectTransaction",
+      "docs": [
+        "Instruction to reject a transaction.",
+        "The transaction must have an \"active\" status."
+      ],
       "accounts": [
         {
-          "name": "spendingLimit",
-          "isMut": true,
+          "name": "multisig",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "multisig",
+          "name": "transaction",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "rentPayer",
+          "name": "member",
           "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "This is usually the same as `config_authority`, but can be a different account if needed."
-          ]
+          "isSigner": true
         },
         {
-          "name": "systemProgram",
+          "name": "authorityPolicy",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "createKey",
-          "type": "publicKey"
+      "args": []
+    },
+    {
+      "name": "cancelTransaction",
+      "docs": [
+        "Instruction to cancel a transaction.",
+        "Transactions must be in the \"executeReady\" status.",
+        "Transaction will only be cancelled if the number of",
+        "cancellations reaches the threshold. A cancelled",
+        "transaction will no longer be able to be executed."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "mint",
-          "type": "publicKey"
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "authorityIndex",
-          "type": "u32"
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "authorityPolicy",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "member",
-          "type": "publicKey"
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeTransaction",
+      "docs": [
+        "Instruction to execute a transaction.",
+        "Transaction status must be \"executeReady\", and the account list must match",
+        "the unique indexed accounts in the following manner:",
+        "[ix_1_account, ix_1_program_account, ix_1_remaining_account_1, ix_1_remaining_account_2, ...]",
+        "",
+        "Refer to the README for more information on how to construct the account list."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "period",
-          "type": {
-            "defined": "Period"
-          }
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "accountList",
+          "type": "bytes"
         }
       ]
     },
     {
-      "name": "removeSpendingLimit",
+      "name": "executeInstruction",
       "docs": [
-        "Method to remove a spending limit"
+        "Instruction to sequentially execute attached instructions.",
+        "Instructions executed in this matter must be executed in order,",
+        "this may be helpful for processing large batch transfers.",
+        "This instruction can only be used for transactions with an authority",
+        "index of 1 or greater.",
+        "",
+        "NOTE - do not use this instruction if there is not total clarity around",
+        "potential side effects, as this instruction implies that the approved",
+        "transaction will be executed partially, and potentially spread out over",
+        "a period of time. This could introduce problems with state and failed",
+        "transactions. For example: a program invoked in one of these instructions",
+        "may be upgraded between executions and potentially leave one of the",
+        "necessary accounts in an invalid state."
       ],
       "accounts": [
         {
-          "name": "spendingLimit",
+          "name": "multisig",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "multisig",
+          "name": "transaction",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "instruction",
+          "isMut": true,
           "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
         }
       ],
       "args": []
     },
     {
-      "name": "pauseSpendingLimit",
+      "name": "expireTransaction",
+      "docs": [
+        "Instruction to mark a transaction past its deadline as \"expired\".",
+        "The transaction must be \"active\" or \"executeReady\", and can be",
+        "expired by anyone once the multisig proposal lifetime has elapsed."
+      ],
       "accounts": [
         {
           "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "vetoTransaction",
+      "docs": [
+        "Instruction for the guardian to veto an active or approved transaction of a vault",
+        "(authority index 1 and above), setting it to Cancelled without a vote of the members"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "disabler",
+          "name": "transaction",
           "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "guardian",
+          "isMut": false,
           "isSigner": true
         }
       ],
       "args": []
     },
     {
-      "name": "spendingLimitUse",
+      "name": "closeTransaction",
+      "docs": [
+        "Instruction to close a transaction and reclaim its rent.",
+        "The transaction must be \"executed\", \"rejected\", \"cancelled\" or \"expired\", or be",
+        "deprecated by a change of the multisig. All attached instructions",
+        "must be closed beforehand with close_instruction."
+      ],
       "accounts": [
         {
           "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "spendingLimit",
+          "name": "rentCollector",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeInstruction",
+      "docs": [
+        "Instruction to close the last instruction attached to a closable transaction",
+        "and reclaim its rent. Instructions are closed from the last one to the first",
+        "one, decrementing the transaction instruction index each time."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "vault",
+          "name": "transaction",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "member",
+          "name": "instruction",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "destination",
+          "name": "rentCollector",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateMultisigSettings",
+      "docs": [
+        "The instruction to update the multisig settings."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newTimeLock",
+          "type": "u32"
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false,
-          "isOptional": true
+          "name": "spendingLimitEnabled",
+          "type": "bool"
         },
         {
-          "name": "mint",
-          "isMut": false,
-          "isSigner": false,
-          "isOptional": true
+          "name": "guardian",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeMemberWithGuardian",
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "vaultTokenAccount",
+          "name": "remover",
           "isMut": true,
-          "isSigner": false,
-          "isOptional": true,
-          "docs": [
-            "Multisig vault token account to transfer tokens from in case `spending_limit.mint` is an SPL token."
-          ]
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "oldMember",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "addAuthorityPolicy",
+      "docs": [
+        "Instruction to create a policy overriding the multisig threshold and time lock",
+        "for the transactions of a given authority. Authority 0 (internal transactions)",
+        "always uses the multisig threshold and time lock."
+      ],
+      "accounts": [
+        {
+          "name": "authorityPolicy",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "destinationTokenAccount",
+          "name": "multisig",
           "isMut": true,
-          "isSigner": false,
-          "isOptional": true
+          "isSigner": true
         },
         {
-          "name": "tokenProgram",
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
-          "isSigner": false,
-          "isOptional": true,
-          "docs": [
-            "In case `spending_limit.mint` is an SPL token."
-          ]
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "authorityIndex",
+          "type": "u32"
         },
         {
-          "name": "decimals",
-          "type": "u8"
-        }
-      ]
-    }
-  ],
-  "accounts": [
+          "name": "threshold",
+          "type": "u16"
+        },
+        {
+          "name": "timeLock",
+          "type": "u32"
+        }
+      ]
+    },
     {
-      "name": "ms",
+      "name": "updateAuthorityPolicy",
       "docs": [
-        "Ms is the basic state account for a multisig."
+        "Instruction to update the threshold and time lock of an authority policy"
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "threshold",
-            "type": "u16"
-          },
-          {
-            "name": "authorityIndex",
-            "type": "u16"
-          },
-          {
-            "name": "transactionIndex",
-            "type": "u32"
-          },
-          {
-            "name": "msChangeIndex",
-            "type": "u32"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "createKey",
-            "type": "publicKey"
-          },
-          {
-            "name": "keys",
-            "type": {
-              "vec": {
-                "defined": "Member"
-              }
-            }
-          },
-          {
-            "name": "timeLock",
-            "type": "u32"
-          },
-          {
-            "name": "spendingLimitEnabled",
-            "type": "bool"
-          },
-          {
-            "name": "guardian",
-            "type": {
-              "option": "publicKey"
-            }
-          }
-        ]
-      }
+      "accounts": [
+        {
+          "name": "authorityPolicy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "threshold",
+          "type": "u16"
+        },
+        {
+          "name": "timeLock",
+          "type": "u32"
+        }
+      ]
     },
     {
-      "name": "msTransaction",
+      "name": "removeAuthorityPolicy",
       "docs": [
-        "The MsTransaction is the state account for a multisig transaction"
+        "Instruction to remove an authority policy, the authority",
+        "then falls back to the multisig threshold and time lock"
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "creator",
-            "type": "publicKey"
-          },
-          {
-            "name": "ms",
-            "type": "publicKey"
-          },
-          {
-            "name": "transactionIndex",
-            "type": "u32"
-          },
-          {
-            "name": "authorityIndex",
-            "type": "u32"
-          },
-          {
-            "name": "authorityBump",
-            "type": "u8"
-          },
-          {
-            "name": "status",
-            "type": {
-              "defined": "MsTransactionStatus"
-            }
-          },
-          {
-            "name": "instructionIndex",
-            "type": "u8"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "approved",
-            "type": {
-              "vec": "publicKey"
-            }
-          },
-          {
-            "name": "rejected",
-            "type": {
-              "vec": "publicKey"
-            }
-          },
-          {
-            "name": "cancelled",
-            "type": {
-              "vec": "publicKey"
-            }
-          },
-          {
-            "name": "executedIndex",
-            "type": "u8"
-          }
-        ]
-      }
+      "accounts": [
+        {
+          "name": "authorityPolicy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
     },
     {
-      "name": "msInstruction",
-      "docs": [
-        "The state account for an instruction that is attached to a transaction.",
-        "Almost analagous to the native Instruction struct for solana, but with an extra",
-        "field for the bump."
+      "name": "addSpendingLimit",
+      "accounts": [
+        {
+          "name": "spendingLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "This is usually the same as `config_authority`, but can be a different account if needed."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "programId",
-            "type": "publicKey"
-          },
-          {
-            "name": "keys",
-            "type": {
-              "vec": {
-                "defined": "MsAccountMeta"
-              }
-            }
-          },
-          {
-            "name": "data",
-            "type": "bytes"
-          },
-          {
-            "name": "instructionIndex",
-            "type": "u8"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
+      "args": [
+        {
+          "name": "createKey",
+          "type": "publicKey"
+        },
+        {
+          "name": "mint",
+          "type": "publicKey"
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "members",
+          "type": {
+            "vec": "publicKey"
           }
-        ]
-      }
+        },
+        {
+          "name": "destinations",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "period",
+          "type": {
+            "defined": "Period"
+          }
+        },
+        {
+          "name": "mode",
+          "type": {
+            "defined": "SpendingLimitMode"
+          }
+        },
+        {
+          "name": "expiration",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
     },
     {
-      "name": "spendingLimit",
+      "name": "removeSpendingLimit",
       "docs": [
-        "Spending Limit struct"
+        "Method to remove a spending limit"
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "createKey",
-            "docs": [
-              "create key used to seed the spending limit pda"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "multisig",
-            "docs": [
-              "The multisig this belongs to."
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "authorityIndex",
-            "docs": [
-              "The index of the vault that the spending limit is for."
-            ],
-            "type": "u32"
-          },
-          {
-            "name": "mint",
-            "docs": [
-              "The token mint the spending limit is for.",
-              "Pubkey::default() means SOL.",
-              "use NATIVE_MINT for Wrapped SOL."
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "amount",
-            "docs": [
-              "The amount of tokens that can be spent in a period.",
-              "This amount is in decimals of the mint,",
-              "so 1 SOL would be `1_000_000_000` and 1 USDC would be `1_000_000`."
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "member",
-            "type": "publicKey"
-          },
-          {
-            "name": "period",
-            "docs": [
-              "The reset period of the spending limit.",
-              "When it passes, the remaining amount is reset, unless it's `Period::OneTime`."
-            ],
-            "type": {
-              "defined": "Period"
-            }
-          },
-          {
-            "name": "remainingAmount",
-            "docs": [
-              "The remaining amount of tokens that can be spent in the current period.",
-              "When reaches 0, the spending limit cannot be used anymore until the period reset."
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "lastReset",
-            "docs": [
-              "Unix timestamp marking the last time the spending limit was reset (or created)."
-            ],
-            "type": "i64"
-          },
-          {
-            "name": "bump",
-            "docs": [
-              "PDA bump."
-            ],
-            "type": "u8"
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
-    {
-      "name": "Member",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "key",
-            "type": "publicKey"
-          },
-          {
-            "name": "guardianCanRemove",
-            "type": "bool"
-          }
-        ]
-      }
+      "accounts": [
+        {
+          "name": "spendingLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "MsAccountMeta",
-      "docs": [
-        "Wrapper for our internal MsInstruction key serialization schema",
-        "MsAccount meta is identical to the AccountMeta struct, but defined",
-        "here for serialization purposes."
+      "name": "pauseSpendingLimit",
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "disabler",
+          "isMut": true,
+          "isSigner": true
+        }
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "pubkey",
-            "type": "publicKey"
-          },
-          {
-            "name": "isSigner",
-            "type": "bool"
-          },
-          {
-            "name": "isWritable",
-            "type": "bool"
-          }
-        ]
-      }
+      "args": []
     },
     {
-      "name": "IncomingInstruction",
-      "docs": [
-        "Incoming instruction schema, used as an argument in the attach_instruction.",
-        "Identical to the solana struct for Instruction, but uses the MsAccountMeta.",
-        "Provided for de/serialization purposes."
+      "name": "spendingLimitUse",
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "spendingLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "vaultTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Multisig vault token account to transfer tokens from in case `spending_limit.mint` is an SPL token."
+          ]
+        },
+        {
+          "name": "destinationTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "In case `spending_limit.mint` is an SPL token."
+          ]
+        }
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "programId",
-            "type": "publicKey"
-          },
-          {
-            "name": "keys",
-            "type": {
-              "vec": {
-                "defined": "MsAccountMeta"
-              }
-            }
-          },
-          {
-            "name": "data",
-            "type": "bytes"
-          }
-        ]
-      }
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "decimals",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "MsTransactionStatus",
+      "name": "proposeGuardianChange",
       "docs": [
-        "MsTransactionStatus enum of the current status of the Multisig Transaction."
+        "Instruction to rotate (Some) or remove (None) the guardian of the multisig.",
+        "The change can be finalized once the multisig time lock and the guardian",
+        "change delay have passed, and can be vetoed by the current guardian until then,",
+        "up to MAX_GUARDIAN_VETOES changes in a row."
       ],
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Draft",
-            "fields": [
-              {
-                "name": "timestamp",
-                "type": "i64"
-              }
-            ]
-          },
-          {
-            "name": "Active",
-            "fields": [
-              {
-                "name": "timestamp",
-                "type": "i64"
-              }
-            ]
-          },
-          {
-            "name": "ExecuteReady",
-            "fields": [
-              {
-                "name": "timestamp",
-                "type": "i64"
-              }
-            ]
-          },
-          {
-            "name": "Executed",
-            "fields": [
-              {
-                "name": "timestamp",
-                "type": "i64"
-              }
-            ]
-          },
-          {
-            "name": "Rejected",
-            "fields": [
-              {
-                "name": "timestamp",
-                "type": "i64"
-              }
-            ]
-          },
-          {
-            "name": "Cancelled",
-            "fields": [
-              {
-                "name": "timestamp",
-                "type": "i64"
-              }
-            ]
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "guardian",
+          "type": {
+            "option": "publicKey"
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "Period",
+      "name": "finalizeGuardianChange",
       "docs": [
-        "Period enum"
+        "Instruction applying a pending guardian change once its delay has passed, can be called by anyone"
       ],
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Daily"
-          },
-          {
-            "name": "Weekly"
-          },
-          {
-            "name": "Monthly"
-          }
-        ]
-      }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "KeyNotInMultisig"
-    },
-    {
-      "code": 6001,
-      "name": "InvalidTransactionState"
-    },
-    {
-      "code": 6002,
-      "name": "InvalidNumberOfAccounts"
-    },
-    {
-      "code": 6003,
-      "name": "InvalidInstructionAccount"
-    },
-    {
-      "code": 6004,
-      "name": "InvalidAuthorityIndex"
-    },
-    {
-      "code": 6005,
-      "name": "TransactionAlreadyExecuted"
-    },
-    {
-      "code": 6006,
-      "name": "CannotRemoveSoloMember"
-    },
-    {
-      "code": 6007,
-      "name": "InvalidThreshold"
-    },
-    {
-      "code": 6008,
-      "name": "DeprecatedTransaction"
-    },
-    {
-      "code": 6009,
-      "name": "InstructionFailed"
-    },
-    {
-      "code": 6010,
-      "name": "MaxMembersReached"
-    },
-    {
-      "code": 6011,
-      "name": "MemberNotFound"
-    },
-    {
-      "code": 6012,
-      "name": "PartialExecution"
-    },
-    {
-      "code": 6013,
-      "name": "NotEnoughLamports"
-    },
-    {
-      "code": 6014,
-      "name": "TimeLockNotSatisfied"
-    },
-    {
-      "code": 6015,
-      "name": "UnauthorizedMember"
-    },
-    {
-      "code": 6016,
-      "name": "TimeLockExceedsMaximum"
-    },
-    {
-      "code": 6017,
-      "name": "TimeError"
-    },
-    {
-      "code": 6018,
-      "name": "MissingAccount"
-    },
-    {
-      "code": 6019,
-      "name": "SpendingLimitNotFound"
-    },
-    {
-      "code": 6020,
-      "name": "SpendingLimitExceeded"
-    },
-    {
-      "code": 6021,
-      "name": "InvalidMint"
-    },
-    {
-      "code": 6022,
-      "name": "InvalidAmount"
-    },
-    {
-      "code": 6023,
-      "name": "InvalidDecimals"
-    },
-    {
-      "code": 6024,
-      "name": "SpendingLimitDisabled"
-    },
-    {
-      "code": 6025,
-      "name": "DuplicateMembers"
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6026,
-      "name": "ChangeIndexExceedsTransactionIndex"
-    }
-  ]
-};
-
-export const IDL: SquadsMpl = {
-  "version": "1.3.1",
-  "name": "squads_mpl",
-  "instructions": [
-    {
-      "name": "create",
+      "name": "vetoGuardianChange",
       "docs": [
-        "Creates a new multisig account"
+        "Instruction for the current guardian to veto a pending guardian change.",
+        "The guardian can't veto more than MAX_GUARDIAN_VETOES changes in a row,",
+        "the count is reset once a change is finalized."
       ],
       "accounts": [
         {
@@ -1163,62 +1235,81 @@ export const IDL: SquadsMpl = {
           "isSigner": false
         },
         {
-          "name": "creator",
-          "isMut": true,
+          "name": "guardian",
+          "isMut": false,
           "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "freezeMultisig",
+      "docs": [
+        "Instruction for the guardian to freeze the multisig for `duration` seconds, blocking",
+        "the execution of non internal transactions and the use of spending limits.",
+        "Members can still vote on and cancel transactions."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "guardian",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         }
       ],
       "args": [
         {
-          "name": "threshold",
-          "type": "u16"
-        },
-        {
-          "name": "createKey",
-          "type": "publicKey"
-        },
-        {
-          "name": "members",
-          "type": {
-            "vec": {
-              "defined": "Member"
-            }
-          }
-        },
-        {
-          "name": "meta",
-          "type": "string"
-        },
-        {
-          "name": "timeLock",
+          "name": "duration",
           "type": "u32"
         }
       ]
     },
     {
-      "name": "addMember",
+      "name": "unfreezeMultisig",
       "docs": [
-        "The instruction to add a new member to the multisig.",
-        "Adds member/key to the multisig and reallocates space if neccessary",
-        "If the multisig needs to be reallocated, it must be prefunded with",
-        "enough lamports to cover the new size."
+        "Instruction to end a freeze early, needs to be executed as an internal transaction"
       ],
       "accounts": [
         {
           "name": "multisig",
           "isMut": true,
           "isSigner": true
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "proposeRecovery",
+      "docs": [
+        "Instruction for the guardian to propose replacing the keys of members, ie. when members lost their keys.",
+        "The recovery can be finalized once the multisig time lock and the recovery delay have passed,",
+        "and can be vetoed until then by any member whose key is not replaced."
+      ],
+      "accounts": [
         {
-          "name": "rent",
+          "name": "multisig",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "recovery",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "guardian",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -1227,91 +1318,88 @@ export const IDL: SquadsMpl = {
       ],
       "args": [
         {
-          "name": "newMember",
+          "name": "replacements",
           "type": {
-            "defined": "Member"
+            "vec": {
+              "defined": "MemberReplacement"
+            }
           }
         }
       ]
     },
     {
-      "name": "removeMember",
+      "name": "vetoRecovery",
       "docs": [
-        "The instruction to remove a member from the multisig"
+        "Instruction for any member not replaced by a pending member recovery to veto it"
       ],
       "accounts": [
         {
           "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recovery",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
           "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
           "isSigner": true
         }
       ],
-      "args": [
-        {
-          "name": "oldMember",
-          "type": "publicKey"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "changeThreshold",
+      "name": "finalizeRecovery",
       "docs": [
-        "The instruction to change the threshold of the multisig"
+        "Instruction applying a member recovery once its delay has passed, can be called by anyone.",
+        "The replaced members keep their permissions and weight."
       ],
       "accounts": [
         {
           "name": "multisig",
           "isMut": true,
-          "isSigner": true
-        }
-      ],
-      "args": [
+          "isSigner": false
+        },
         {
-          "name": "newThreshold",
-          "type": "u16"
-        }
-      ]
-    },
-    {
-      "name": "addAuthority",
-      "docs": [
-        "instruction to increase the authority value tracked in the multisig",
-        "This is optional, as authorities are simply PDAs, however it may be helpful",
-        "to keep track of commonly used authorities in a UI.",
-        "This has no functional impact on the multisig or its functionality, but",
-        "can be used to track commonly used authorities (ie, vault 1, vault 2, etc.)"
-      ],
-      "accounts": [
+          "name": "recovery",
+          "isMut": true,
+          "isSigner": false
+        },
         {
-          "name": "multisig",
+          "name": "rentPayer",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         }
       ],
       "args": []
     },
     {
-      "name": "createTransaction",
+      "name": "createGuardianSet",
       "docs": [
-        "Instruction to create a multisig transaction.",
-        "Each transaction is tied to a single authority, and must be specified when",
-        "creating the instruction below. authority 0 is reserved for internal",
-        "instructions, whereas authorities 1 or greater refer to a vault,",
-        "upgrade authority, or other."
+        "Instruction to create a M-of-N guardian set for the multisig. The set becomes",
+        "the guardian of the multisig once its address is set as the multisig guardian."
       ],
       "accounts": [
         {
-          "name": "multisig",
+          "name": "guardianSet",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "transaction",
+          "name": "multisig",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "creator",
+          "name": "rentPayer",
           "isMut": true,
           "isSigner": true
         },
@@ -1323,57 +1411,63 @@ export const IDL: SquadsMpl = {
       ],
       "args": [
         {
-          "name": "authorityIndex",
-          "type": "u32"
+          "name": "guardians",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "threshold",
+          "type": "u16"
         }
       ]
     },
     {
-      "name": "activateTransaction",
+      "name": "updateGuardianSet",
       "docs": [
-        "Instruction to set the state of a transaction \"active\".",
-        "\"active\" transactions can then be signed off by multisig members"
+        "Instruction to replace the guardians and the threshold of the guardian set.",
+        "Pending actions only count the approvals of the new guardians."
       ],
       "accounts": [
         {
-          "name": "multisig",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "transaction",
+          "name": "guardianSet",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "creator",
+          "name": "multisig",
           "isMut": true,
           "isSigner": true
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "guardians",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "threshold",
+          "type": "u16"
+        }
+      ]
     },
     {
-      "name": "addInstruction",
+      "name": "createGuardianAction",
       "docs": [
-        "Instruction to attach an instruction to a transaction.",
-        "Transactions must be in the \"draft\" status, and any",
-        "signer (aside from execution payer) specified in an instruction",
-        "must match the authority PDA specified during the transaction creation."
+        "Instruction for a guardian to propose an action of the guardian set, an instruction",
+        "of this program signed by the guardian set (ie, pause_spending_limit, remove_member_with_guardian).",
+        "The proposing guardian approves the action."
       ],
       "accounts": [
         {
-          "name": "multisig",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "transaction",
+          "name": "guardianSet",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "instruction",
+          "name": "action",
           "isMut": true,
           "isSigner": false
         },
@@ -1390,7 +1484,7 @@ export const IDL: SquadsMpl = {
       ],
       "args": [
         {
-          "name": "incomingInstruction",
+          "name": "instruction",
           "type": {
             "defined": "IncomingInstruction"
           }
@@ -1398,77 +1492,78 @@ export const IDL: SquadsMpl = {
       ]
     },
     {
-      "name": "approveTransaction",
+      "name": "approveGuardianAction",
       "docs": [
-        "Instruction to approve a transaction on behalf of a member.",
-        "The transaction must have an \"active\" status"
+        "Instruction for a guardian to approve a guardian action"
       ],
       "accounts": [
         {
-          "name": "multisig",
+          "name": "guardianSet",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "transaction",
+          "name": "action",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "member",
-          "isMut": true,
+          "name": "guardian",
+          "isMut": false,
           "isSigner": true
         }
       ],
       "args": []
     },
     {
-      "name": "rejectTransaction",
+      "name": "executeGuardianAction",
       "docs": [
-        "Instruction to reject a transaction.",
-        "The transaction must have an \"active\" status."
+        "Instruction for a guardian to execute a guardian action approved by the guardian set threshold.",
+        "The instruction is signed by the guardian set, and the action account is closed."
       ],
       "accounts": [
         {
-          "name": "multisig",
+          "name": "guardianSet",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "transaction",
+          "name": "action",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "member",
+          "name": "creator",
           "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "guardian",
+          "isMut": false,
           "isSigner": true
         }
       ],
       "args": []
     },
     {
-      "name": "cancelTransaction",
+      "name": "migrateMultisig",
       "docs": [
-        "Instruction to cancel a transaction.",
-        "Transactions must be in the \"executeReady\" status.",
-        "Transaction will only be cancelled if the number of",
-        "cancellations reaches the threshold. A cancelled",
-        "transaction will no longer be able to be executed."
+        "Instruction to convert a multisig created with the legacy layout, before members",
+        "had permissions and weights, to the current layout. Legacy members get every",
+        "permission and a weight of 1. The account is reallocated, the rent payer tops",
+        "up its rent. Can be called by anyone as the conversion is deterministic."
       ],
       "accounts": [
         {
           "name": "multisig",
           "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "transaction",
-          "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "create accounts with the multisig discriminator."
+          ]
         },
         {
-          "name": "member",
+          "name": "rentPayer",
           "isMut": true,
           "isSigner": true
         },
@@ -1481,308 +1576,40 @@ export const IDL: SquadsMpl = {
       "args": []
     },
     {
-      "name": "executeTransaction",
+      "name": "migrateSpendingLimit",
       "docs": [
-        "Instruction to execute a transaction.",
-        "Transaction status must be \"executeReady\", and the account list must match",
-        "the unique indexed accounts in the following manner:",
-        "[ix_1_account, ix_1_program_account, ix_1_remaining_account_1, ix_1_remaining_account_2, ...]",
-        "",
-        "Refer to the README for more information on how to construct the account list."
+        "Instruction to convert a spending limit created with the legacy layout, for a single",
+        "member, to the current layout. The account is reallocated, the rent payer tops up its rent.",
+        "Can be called by anyone as the conversion is deterministic."
       ],
       "accounts": [
         {
-          "name": "multisig",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "transaction",
+          "name": "spendingLimit",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "create accounts with the spending limit discriminator."
+          ]
         },
         {
-          "name": "member",
+          "name": "rentPayer",
           "isMut": true,
           "isSigner": true
-        }
-      ],
-      "args": [
-        {
-          "name": "accountList",
-          "type": "bytes"
-        }
-      ]
-    },
-    {
-      "name": "executeInstruction",
-      "docs": [
-        "Instruction to sequentially execute attached instructions.",
-        "Instructions executed in this matter must be executed in order,",
-        "this may be helpful for processing large batch transfers.",
-        "This instruction can only be used for transactions with an authority",
-        "index of 1 or greater.",
-        "",
-        "NOTE - do not use this instruction if there is not total clarity around",
-        "potential side effects, as this instruction implies that the approved",
-        "transaction will be executed partially, and potentially spread out over",
-        "a period of time. This could introduce problems with state and failed",
-        "transactions. For example: a program invoked in one of these instructions",
-        "may be upgraded between executions and potentially leave one of the",
-        "necessary accounts in an invalid state."
-      ],
-      "accounts": [
-        {
-          "name": "multisig",
-          "isMut": true,
-          "isSigner": false
         },
         {
-          "name": "transaction",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "instruction",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
-        },
-        {
-          "name": "member",
-          "isMut": true,
-          "isSigner": true
         }
       ],
       "args": []
-    },
+    }
+  ],
+  "accounts": [
     {
-      "name": "updateMultisigSettings",
+      "name": "ms",
       "docs": [
-        "The instruction to update the multisig settings."
-      ],
-      "accounts": [
-        {
-          "name": "multisig",
-          "isMut": true,
-          "isSigner": true
-        }
-      ],
-      "args": [
-        {
-          "name": "newTimeLock",
-          "type": "u32"
-        },
-        {
-          "name": "spendingLimitEnabled",
-          "type": "bool"
-        },
-        {
-          "name": "guardian",
-          "type": {
-            "option": "publicKey"
-          }
-        }
-      ]
-    },
-    {
-      "name": "removeMemberWithGuardian",
-      "accounts": [
-        {
-          "name": "multisig",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "remover",
-          "isMut": true,
-          "isSigner": true
-        }
-      ],
-      "args": [
-        {
-          "name": "oldMember",
-          "type": "publicKey"
-        }
-      ]
-    },
-    {
-      "name": "addSpendingLimit",
-      "accounts": [
-        {
-          "name": "spendingLimit",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "multisig",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "rentPayer",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "This is usually the same as `config_authority`, but can be a different account if needed."
-          ]
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "createKey",
-          "type": "publicKey"
-        },
-        {
-          "name": "mint",
-          "type": "publicKey"
-        },
-        {
-          "name": "authorityIndex",
-          "type": "u32"
-        },
-        {
-          "name": "amount",
-          "type": "u64"
-        },
-        {
-          "name": "member",
-          "type": "publicKey"
-        },
-        {
-          "name": "period",
-          "type": {
-            "defined": "Period"
-          }
-        }
-      ]
-    },
-    {
-      "name": "removeSpendingLimit",
-      "docs": [
-        "Method to remove a spending limit"
-      ],
-      "accounts": [
-        {
-          "name": "spendingLimit",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "multisig",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "pauseSpendingLimit",
-      "accounts": [
-        {
-          "name": "multisig",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "disabler",
-          "isMut": true,
-          "isSigner": true
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "spendingLimitUse",
-      "accounts": [
-        {
-          "name": "multisig",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "spendingLimit",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "vault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "member",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "destination",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false,
-          "isOptional": true
-        },
-        {
-          "name": "mint",
-          "isMut": false,
-          "isSigner": false,
-          "isOptional": true
-        },
-        {
-          "name": "vaultTokenAccount",
-          "isMut": true,
-          "isSigner": false,
-          "isOptional": true,
-          "docs": [
-            "Multisig vault token account to transfer tokens from in case `spending_limit.mint` is an SPL token."
-          ]
-        },
-        {
-          "name": "destinationTokenAccount",
-          "isMut": true,
-          "isSigner": false,
-          "isOptional": true
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false,
-          "isOptional": true,
-          "docs": [
-            "In case `spending_limit.mint` is an SPL token."
-          ]
-        }
-      ],
-      "args": [
-        {
-          "name": "amount",
-          "type": "u64"
-        },
-        {
-          "name": "decimals",
-          "type": "u8"
-        }
-      ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "ms",
-      "docs": [
-        "Ms is the basic state account for a multisig."
+        "Ms is the basic state account for a multisig."
       ],
       "type": {
         "kind": "struct",
@@ -1832,6 +1659,44 @@ export const IDL: SquadsMpl = {
             "type": {
               "option": "publicKey"
             }
+          },
+          {
+            "name": "rentCollector",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "proposalLifetime",
+            "type": "u32"
+          },
+          {
+            "name": "pendingGuardianChange",
+            "type": {
+              "option": {
+                "defined": "PendingGuardianChange"
+              }
+            }
+          },
+          {
+            "name": "frozenUntil",
+            "type": "i64"
+          },
+          {
+            "name": "timeLockMode",
+            "type": {
+              "defined": "TimeLockMode"
+            }
+          },
+          {
+            "name": "executionPolicy",
+            "type": {
+              "defined": "ExecutionPolicy"
+            }
+          },
+          {
+            "name": "guardianVetoes",
+            "type": "u16"
           }
         ]
       }
@@ -1899,6 +1764,14 @@ export const IDL: SquadsMpl = {
           {
             "name": "executedIndex",
             "type": "u8"
+          },
+          {
+            "name": "executionDelay",
+            "type": "u32"
+          },
+          {
+            "name": "activatedAt",
+            "type": "i64"
           }
         ]
       }
@@ -1941,7 +1814,291 @@ export const IDL: SquadsMpl = {
       }
     },
     {
-      "name": "spendingLimit",
+      "name": "instructionBuffer",
+      "docs": [
+        "Instruction buffer account, holding a serialized IncomingInstruction written in chunks",
+        "by the creator of a draft transaction, for instructions too large to be attached",
+        "with a single add_instruction (ie. program buffer writes)."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "docs": [
+              "The multisig this belongs to."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "transaction",
+            "docs": [
+              "The draft transaction the instruction will be attached to."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "creator",
+            "docs": [
+              "The creator of the transaction, writing the buffer."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "finalHash",
+            "docs": [
+              "The sha256 hash of the complete serialized IncomingInstruction."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "finalSize",
+            "docs": [
+              "The size of the complete serialized IncomingInstruction."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "buffer",
+            "docs": [
+              "The chunks written so far."
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "authorityPolicy",
+      "docs": [
+        "Authority policy account, overriding the multisig threshold and time lock",
+        "for the transactions using a specific authority (vault, upgrade authority, etc.)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "docs": [
+              "The multisig this belongs to."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "authorityIndex",
+            "docs": [
+              "The index of the authority the policy applies to."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "The vote weight threshold for the transactions of this authority."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "timeLock",
+            "docs": [
+              "The time lock duration in seconds for the transactions of this authority."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "guardianSet",
+      "docs": [
+        "Guardian set account, a M-of-N set of guardian keys for a multisig.",
+        "When the guardian of the multisig is set to the address of this account,",
+        "guardian actions must be approved by `threshold` guardians of the set,",
+        "and are then signed by this account."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "docs": [
+              "The multisig this belongs to."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "guardians",
+            "docs": [
+              "The keys of the guardians, sorted."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "The number of guardian approvals needed to execute a guardian action."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "actionIndex",
+            "docs": [
+              "Look up and seed reference for guardian actions."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "guardianAction",
+      "docs": [
+        "Guardian action account, an instruction of the squads program to be signed",
+        "by a guardian set once approved by enough guardians."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSet",
+            "docs": [
+              "The guardian set this belongs to."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "actionIndex",
+            "docs": [
+              "The index of the action in the guardian set."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "creator",
+            "docs": [
+              "The guardian who created the action, receives the rent once executed."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "keys",
+            "docs": [
+              "The accounts of the instruction to sign."
+            ],
+            "type": {
+              "vec": {
+                "defined": "MsAccountMeta"
+              }
+            }
+          },
+          {
+            "name": "data",
+            "docs": [
+              "The data of the instruction to sign."
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "approved",
+            "docs": [
+              "The guardians who approved the action."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "memberRecovery",
+      "docs": [
+        "Member recovery account, a replacement of member keys proposed by the guardian.",
+        "It can be finalized once its delay has passed, and vetoed until then by any",
+        "member whose key is not replaced."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "docs": [
+              "The multisig this belongs to."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "replacements",
+            "docs": [
+              "The member keys to replace."
+            ],
+            "type": {
+              "vec": {
+                "defined": "MemberReplacement"
+              }
+            }
+          },
+          {
+            "name": "readyAt",
+            "docs": [
+              "Timestamp after which the recovery can be finalized."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "rentPayer",
+            "docs": [
+              "Receives the rent once the recovery is finalized or vetoed."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "spendingLimit",
       "docs": [
         "Spending Limit struct"
       ],
@@ -1988,8 +2145,23 @@ export const IDL: SquadsMpl = {
             "type": "u64"
           },
           {
-            "name": "member",
-            "type": "publicKey"
+            "name": "members",
+            "docs": [
+              "The members that can use the spending limit, sharing the same remaining amount."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "destinations",
+            "docs": [
+              "The destinations the spending limit can send to, any destination if empty.",
+              "These are the owners of the token accounts for SPL tokens."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
           },
           {
             "name": "period",
@@ -2001,11 +2173,30 @@ export const IDL: SquadsMpl = {
               "defined": "Period"
             }
           },
+          {
+            "name": "mode",
+            "docs": [
+              "How the amount spent in a period is accounted."
+            ],
+            "type": {
+              "defined": "SpendingLimitMode"
+            }
+          },
+          {
+            "name": "expiration",
+            "docs": [
+              "Optional unix timestamp after which the spending limit can't be used anymore."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
           {
             "name": "remainingAmount",
             "docs": [
               "The remaining amount of tokens that can be spent in the current period.",
-              "When reaches 0, the spending limit cannot be used anymore until the period reset."
+              "When reaches 0, the spending limit cannot be used anymore until the period reset.",
+              "For rolling limits, the remaining amount in the window as of the last use."
             ],
             "type": "u64"
           },
@@ -2016,6 +2207,38 @@ export const IDL: SquadsMpl = {
             ],
             "type": "i64"
           },
+          {
+            "name": "spends",
+            "docs": [
+              "The spends of the rolling window, oldest first. Only used by rolling limits."
+            ],
+            "type": {
+              "vec": {
+                "defined": "Spend"
+              }
+            }
+          },
+          {
+            "name": "totalSpent",
+            "docs": [
+              "The total amount spent since the creation of the spending limit."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "useCount",
+            "docs": [
+              "The number of times the spending limit was used."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastUsed",
+            "docs": [
+              "Unix timestamp of the last use of the spending limit, 0 if never used."
+            ],
+            "type": "i64"
+          },
           {
             "name": "bump",
             "docs": [
@@ -2040,148 +2263,5205 @@ export const IDL: SquadsMpl = {
           {
             "name": "guardianCanRemove",
             "type": "bool"
+          },
+          {
+            "name": "permissions",
+            "type": "u8"
+          },
+          {
+            "name": "weight",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PendingGuardianChange",
+      "docs": [
+        "Guardian rotation or removal waiting for its delay"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardian",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "readyAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MsAccountMeta",
+      "docs": [
+        "Wrapper for our internal MsInstruction key serialization schema",
+        "MsAccount meta is identical to the AccountMeta struct, but defined",
+        "here for serialization purposes."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pubkey",
+            "type": "publicKey"
+          },
+          {
+            "name": "isSigner",
+            "type": "bool"
+          },
+          {
+            "name": "isWritable",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "IncomingInstruction",
+      "docs": [
+        "Incoming instruction schema, used as an argument in the attach_instruction.",
+        "Identical to the solana struct for Instruction, but uses the MsAccountMeta.",
+        "Provided for de/serialization purposes."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": {
+                "defined": "MsAccountMeta"
+              }
+            }
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MemberReplacement",
+      "docs": [
+        "Replacement of the key of a member, used by member recoveries"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "oldKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "newKey",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Spend",
+      "docs": [
+        "A spend of a rolling spending limit"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyMs",
+      "docs": [
+        "Layout of the Ms accounts created before members had permissions and weights,",
+        "only deserialized by `migrate_multisig` to convert them to the current layout."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "threshold",
+            "type": "u16"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u16"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "msChangeIndex",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "createKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": {
+                "defined": "LegacyMember"
+              }
+            }
+          },
+          {
+            "name": "timeLock",
+            "type": "u32"
+          },
+          {
+            "name": "spendingLimitEnabled",
+            "type": "bool"
+          },
+          {
+            "name": "guardian",
+            "type": {
+              "option": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyMember",
+      "docs": [
+        "Layout of the members of a LegacyMs"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "key",
+            "type": "publicKey"
+          },
+          {
+            "name": "guardianCanRemove",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacySpendingLimit",
+      "docs": [
+        "Layout of the SpendingLimit accounts created before they could be shared by several members,",
+        "only deserialized by `migrate_spending_limit` to convert them to the current layout."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "createKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "member",
+            "type": "publicKey"
+          },
+          {
+            "name": "period",
+            "type": {
+              "defined": "Period"
+            }
+          },
+          {
+            "name": "remainingAmount",
+            "type": "u64"
+          },
+          {
+            "name": "lastReset",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "MsAccountMeta",
-      "docs": [
-        "Wrapper for our internal MsInstruction key serialization schema",
-        "MsAccount meta is identical to the AccountMeta struct, but defined",
-        "here for serialization purposes."
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "pubkey",
-            "type": "publicKey"
-          },
-          {
-            "name": "isSigner",
-            "type": "bool"
-          },
-          {
-            "name": "isWritable",
-            "type": "bool"
-          }
-        ]
-      }
+      "name": "TimeLockMode",
+      "docs": [
+        "TimeLockMode enum of when the time lock of the multisig applies."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Voting"
+          },
+          {
+            "name": "Execution"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ExecutionPolicy",
+      "docs": [
+        "ExecutionPolicy enum of who can execute the ExecuteReady transactions,",
+        "members with the execute permission can always execute them."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Members"
+          },
+          {
+            "name": "Executors",
+            "fields": [
+              {
+                "name": "keys",
+                "type": {
+                  "vec": "publicKey"
+                }
+              }
+            ]
+          },
+          {
+            "name": "Permissionless"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MsTransactionStatus",
+      "docs": [
+        "MsTransactionStatus enum of the current status of the Multisig Transaction."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Draft",
+            "fields": [
+              {
+                "name": "timestamp",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "Active",
+            "fields": [
+              {
+                "name": "timestamp",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "ExecuteReady",
+            "fields": [
+              {
+                "name": "timestamp",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "Executed",
+            "fields": [
+              {
+                "name": "timestamp",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "Rejected",
+            "fields": [
+              {
+                "name": "timestamp",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "Cancelled",
+            "fields": [
+              {
+                "name": "timestamp",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "Expired",
+            "fields": [
+              {
+                "name": "timestamp",
+                "type": "i64"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "SpendingLimitMode",
+      "docs": [
+        "How the amount spent in a period is accounted"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Fixed"
+          },
+          {
+            "name": "Rolling"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Period",
+      "docs": [
+        "Period enum"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Daily"
+          },
+          {
+            "name": "Weekly"
+          },
+          {
+            "name": "Monthly"
+          },
+          {
+            "name": "OneTime"
+          },
+          {
+            "name": "Custom",
+            "fields": [
+              {
+                "name": "seconds",
+                "type": "u32"
+              }
+            ]
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "MultisigCreated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "createKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "members",
+          "type": {
+            "vec": {
+              "defined": "Member"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "timeLock",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MemberAdded",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": {
+            "defined": "Member"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MemberRemoved",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "byGuardian",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ThresholdChanged",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u16",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityAdded",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u16",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MultisigSettingsChanged",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timeLock",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "spendingLimitEnabled",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "rentCollector",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "proposalLifetime",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "timeLockMode",
+          "type": {
+            "defined": "TimeLockMode"
+          },
+          "index": false
+        },
+        {
+          "name": "executionPolicy",
+          "type": {
+            "defined": "ExecutionPolicy"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GuardianChangeProposed",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "readyAt",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GuardianChangeVetoed",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MultisigFrozen",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "frozenUntil",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MultisigUnfrozen",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RecoveryProposed",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "replacements",
+          "type": {
+            "vec": {
+              "defined": "MemberReplacement"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "readyAt",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RecoveryVetoed",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RecoveryFinalized",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "replacements",
+          "type": {
+            "vec": {
+              "defined": "MemberReplacement"
+            }
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityPolicySet",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "timeLock",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityPolicyRemoved",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionCreated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InstructionAdded",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "instruction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "instructionIndex",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InstructionUpdated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "instruction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "instructionIndex",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InstructionRemoved",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "instruction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "instructionIndex",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionActivated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionVoted",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "approve",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "MsTransactionStatus"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionCancelVoted",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "MsTransactionStatus"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionExecuted",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "executor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "executedIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "MsTransactionStatus"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionExpired",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionVetoed",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionClosed",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "rentCollector",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InstructionClosed",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "instruction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "rentCollector",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SpendingLimitCreated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "spendingLimit",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "createKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "members",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "destinations",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "period",
+          "type": {
+            "defined": "Period"
+          },
+          "index": false
+        },
+        {
+          "name": "mode",
+          "type": {
+            "defined": "SpendingLimitMode"
+          },
+          "index": false
+        },
+        {
+          "name": "expiration",
+          "type": {
+            "option": "i64"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SpendingLimitRemoved",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "spendingLimit",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SpendingLimitPaused",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SpendingLimitUsed",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "spendingLimit",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "destination",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "remainingAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalSpent",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "useCount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GuardianSetChanged",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardianSet",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardians",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u16",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GuardianActionCreated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardianSet",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "action",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "actionIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GuardianActionApproved",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardianSet",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "action",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "approvals",
+          "type": "u16",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GuardianActionExecuted",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardianSet",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "action",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "executor",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MultisigMigrated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "members",
+          "type": {
+            "vec": {
+              "defined": "Member"
+            }
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SpendingLimitMigrated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "spendingLimit",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "members",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "KeyNotInMultisig"
+    },
+    {
+      "code": 6001,
+      "name": "InvalidTransactionState"
+    },
+    {
+      "code": 6002,
+      "name": "InvalidNumberOfAccounts"
+    },
+    {
+      "code": 6003,
+      "name": "InvalidInstructionAccount"
+    },
+    {
+      "code": 6004,
+      "name": "InvalidAuthorityIndex"
+    },
+    {
+      "code": 6005,
+      "name": "TransactionAlreadyExecuted"
+    },
+    {
+      "code": 6006,
+      "name": "CannotRemoveSoloMember"
+    },
+    {
+      "code": 6007,
+      "name": "InvalidThreshold"
+    },
+    {
+      "code": 6008,
+      "name": "DeprecatedTransaction"
+    },
+    {
+      "code": 6009,
+      "name": "InstructionFailed"
+    },
+    {
+      "code": 6010,
+      "name": "MaxMembersReached"
+    },
+    {
+      "code": 6011,
+      "name": "MemberNotFound"
+    },
+    {
+      "code": 6012,
+      "name": "PartialExecution"
+    },
+    {
+      "code": 6013,
+      "name": "NotEnoughLamports"
+    },
+    {
+      "code": 6014,
+      "name": "TimeLockNotSatisfied"
+    },
+    {
+      "code": 6015,
+      "name": "UnauthorizedMember"
+    },
+    {
+      "code": 6016,
+      "name": "TimeLockExceedsMaximum"
+    },
+    {
+      "code": 6017,
+      "name": "TimeError"
+    },
+    {
+      "code": 6018,
+      "name": "MissingAccount"
+    },
+    {
+      "code": 6019,
+      "name": "SpendingLimitNotFound"
+    },
+    {
+      "code": 6020,
+      "name": "SpendingLimitExceeded"
+    },
+    {
+      "code": 6021,
+      "name": "InvalidMint"
+    },
+    {
+      "code": 6022,
+      "name": "InvalidAmount"
+    },
+    {
+      "code": 6023,
+      "name": "InvalidDecimals"
+    },
+    {
+      "code": 6024,
+      "name": "SpendingLimitDisabled"
+    },
+    {
+      "code": 6025,
+      "name": "DuplicateMembers"
+    },
+    {
+      "code": 6026,
+      "name": "ChangeIndexExceedsTransactionIndex"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidRentCollector"
+    },
+    {
+      "code": 6028,
+      "name": "InstructionsNotClosed"
+    },
+    {
+      "code": 6029,
+      "name": "TransactionExpired"
+    },
+    {
+      "code": 6030,
+      "name": "TransactionNotExpired"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidProposalLifetime"
+    },
+    {
+      "code": 6032,
+      "name": "InvalidPermissions"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidWeight"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidGuardians"
+    },
+    {
+      "code": 6035,
+      "name": "GuardianActionNotApproved"
+    },
+    {
+      "code": 6036,
+      "name": "GuardianChangeRequiresDelay"
+    },
+    {
+      "code": 6037,
+      "name": "NoPendingGuardianChange"
+    },
+    {
+      "code": 6038,
+      "name": "GuardianChangeNotReady"
+    },
+    {
+      "code": 6039,
+      "name": "MultisigFrozen"
+    },
+    {
+      "code": 6040,
+      "name": "InvalidFreezeDuration"
+    },
+    {
+      "code": 6041,
+      "name": "FreezeCooldown"
+    },
+    {
+      "code": 6042,
+      "name": "NotFrozen"
+    },
+    {
+      "code": 6043,
+      "name": "InvalidRecovery"
+    },
+    {
+      "code": 6044,
+      "name": "RecoveryNotReady"
+    },
+    {
+      "code": 6045,
+      "name": "InvalidDestinations"
+    },
+    {
+      "code": 6046,
+      "name": "DestinationNotAllowed"
+    },
+    {
+      "code": 6047,
+      "name": "InvalidSpendingLimitMembers"
+    },
+    {
+      "code": 6048,
+      "name": "InvalidPeriod"
+    },
+    {
+      "code": 6049,
+      "name": "InvalidExpiration"
+    },
+    {
+      "code": 6050,
+      "name": "SpendingLimitExpired"
+    },
+    {
+      "code": 6051,
+      "name": "UnauthorizedExecutor"
+    },
+    {
+      "code": 6052,
+      "name": "InvalidExecutors"
+    },
+    {
+      "code": 6053,
+      "name": "InvalidBufferSize"
+    },
+    {
+      "code": 6054,
+      "name": "InstructionBufferIncomplete"
+    },
+    {
+      "code": 6055,
+      "name": "InvalidBufferHash"
+    },
+    {
+      "code": 6056,
+      "name": "InvalidInstructionBuffer"
+    },
+    {
+      "code": 6057,
+      "name": "AuthorityPolicyUnsatisfiable"
+    },
+    {
+      "code": 6058,
+      "name": "AlreadyMigrated"
+    },
+    {
+      "code": 6059,
+      "name": "GuardianVetoLimitReached"
+    }
+  ]
+};
+
+export const IDL: SquadsMpl = {
+  "version": "1.3.1",
+  "name": "squads_mpl",
+  "instructions": [
+    {
+      "name": "create",
+      "docs": [
+        "Creates a new multisig account"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "threshold",
+          "type": "u16"
+        },
+        {
+          "name": "createKey",
+          "type": "publicKey"
+        },
+        {
+          "name": "members",
+          "type": {
+            "vec": {
+              "defined": "Member"
+            }
+          }
+        },
+        {
+          "name": "meta",
+          "type": "string"
+        },
+        {
+          "name": "timeLock",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "addMember",
+      "docs": [
+        "The instruction to add a new member to the multisig.",
+        "Adds member/key to the multisig and reallocates space if neccessary",
+        "If the multisig needs to be reallocated, it must be prefunded with",
+        "enough lamports to cover the new size."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "newMember",
+          "type": {
+            "defined": "Member"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeMember",
+      "docs": [
+        "The instruction to remove a member from the multisig"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "oldMember",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "changeThreshold",
+      "docs": [
+        "The instruction to change the threshold of the multisig"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newThreshold",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "addAuthority",
+      "docs": [
+        "instruction to increase the authority value tracked in the multisig",
+        "This is optional, as authorities are simply PDAs, however it may be helpful",
+        "to keep track of commonly used authorities in a UI.",
+        "This has no functional impact on the multisig or its functionality, but",
+        "can be used to track commonly used authorities (ie, vault 1, vault 2, etc.)"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setRentCollector",
+      "docs": [
+        "The instruction to set the account receiving the rent of closed",
+        "transactions and instructions. If set to None, the rent goes back",
+        "to the creator of each transaction."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "rentCollector",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setProposalLifetime",
+      "docs": [
+        "The instruction to change the lifetime of the multisig transactions.",
+        "Active and ExecuteReady transactions activated more than the lifetime ago can no",
+        "longer be approved or executed. A lifetime of 0 disables the expiry."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "proposalLifetime",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "setTimeLockMode",
+      "docs": [
+        "The instruction to choose when the time lock applies. In the voting mode",
+        "(the default) members wait the time lock after activation before approving,",
+        "in the execution mode they wait it after approval before executing, during",
+        "which the transaction can still be cancelled."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "timeLockMode",
+          "type": {
+            "defined": "TimeLockMode"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setExecutionPolicy",
+      "docs": [
+        "The instruction to let designated executors, or anyone, execute the",
+        "ExecuteReady transactions, ie. keeper bots that are not members.",
+        "Members with the execute permission can always execute them."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "executionPolicy",
+          "type": {
+            "defined": "ExecutionPolicy"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createTransaction",
+      "docs": [
+        "Instruction to create a multisig transaction.",
+        "Each transaction is tied to a single authority, and must be specified when",
+        "creating the instruction below. authority 0 is reserved for internal",
+        "instructions, whereas authorities 1 or greater refer to a vault,",
+        "upgrade authority, or other."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "activateTransaction",
+      "docs": [
+        "Instruction to set the state of a transaction \"active\".",
+        "\"active\" transactions can then be signed off by multisig members"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addInstruction",
+      "docs": [
+        "Instruction to attach an instruction to a transaction.",
+        "Transactions must be in the \"draft\" status, and any",
+        "signer (aside from execution payer) specified in an instruction",
+        "must match the authority PDA specified during the transaction creation."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "incomingInstruction",
+          "type": {
+            "defined": "IncomingInstruction"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateInstruction",
+      "docs": [
+        "Instruction to replace an instruction attached to a draft transaction,",
+        "ie. to fix a mistake without abandoning the transaction."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "incomingInstruction",
+          "type": {
+            "defined": "IncomingInstruction"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeInstruction",
+      "docs": [
+        "Instruction to remove an instruction attached to a draft transaction and reclaim",
+        "its rent. Every following instruction is moved to the previous index, so that the",
+        "instruction indexes stay contiguous, and the last instruction account is closed.",
+        "The instruction accounts from the removed one to the one before the last are",
+        "expected in the remaining accounts."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "instructionIndex",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createInstructionBuffer",
+      "docs": [
+        "Instruction to create the buffer of an instruction too large to be attached with",
+        "add_instruction, with the sha256 hash and size of the serialized IncomingInstruction.",
+        "The first chunk is written right away, the others with write_instruction_buffer."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructionBuffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "finalHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "finalSize",
+          "type": "u32"
+        },
+        {
+          "name": "chunk",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "writeInstructionBuffer",
+      "docs": [
+        "Instruction to append a chunk to the instruction buffer of a draft transaction."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructionBuffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "chunk",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "addInstructionFromBuffer",
+      "docs": [
+        "Instruction to attach the instruction of a complete buffer to a draft transaction,",
+        "once validated against its hash. The buffer is closed."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instructionBuffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeInstructionBuffer",
+      "docs": [
+        "Instruction to close an instruction buffer that won't be attached and reclaim its rent,",
+        "including once its transaction is closed."
+      ],
+      "accounts": [
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructionBuffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "approveTransaction",
+      "docs": [
+        "Instruction to approve a transaction on behalf of a member.",
+        "The transaction must have an \"active\" status"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authorityPolicy",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "rejectTransaction",
+      "docs": [
+        "Instruction to reject a transaction.",
+        "The transaction must have an \"active\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authorityPolicy",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelTransaction",
+      "docs": [
+        "Instruction to cancel a transaction.",
+        "Transactions must be in the \"executeReady\" status.",
+        "Transaction will only be cancelled if the number of",
+        "cancellations reaches the threshold. A cancelled",
+        "transaction will no longer be able to be executed."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authorityPolicy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeTransaction",
+      "docs": [
+        "Instruction to execute a transaction.",
+        "Transaction status must be \"executeReady\", and the account list must match",
+        "the unique indexed accounts in the following manner:",
+        "[ix_1_account, ix_1_program_account, ix_1_remaining_account_1, ix_1_remaining_account_2, ...]",
+        "",
+        "Refer to the README for more information on how to construct the account list."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "accountList",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "executeInstruction",
+      "docs": [
+        "Instruction to sequentially execute attached instructions.",
+        "Instructions executed in this matter must be executed in order,",
+        "this may be helpful for processing large batch transfers.",
+        "This instruction can only be used for transactions with an authority",
+        "index of 1 or greater.",
+        "",
+        "NOTE - do not use this instruction if there is not total clarity around",
+        "potential side effects, as this instruction implies that the approved",
+        "transaction will be executed partially, and potentially spread out over",
+        "a period of time. This could introduce problems with state and failed",
+        "transactions. For example: a program invoked in one of these instructions",
+        "may be upgraded between executions and potentially leave one of the",
+        "necessary accounts in an invalid state."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "expireTransaction",
+      "docs": [
+        "Instruction to mark a transaction past its deadline as \"expired\".",
+        "The transaction must be \"active\" or \"executeReady\", and can be",
+        "expired by anyone once the multisig proposal lifetime has elapsed."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "vetoTransaction",
+      "docs": [
+        "Instruction for the guardian to veto an active or approved transaction of a vault",
+        "(authority index 1 and above), setting it to Cancelled without a vote of the members"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "guardian",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeTransaction",
+      "docs": [
+        "Instruction to close a transaction and reclaim its rent.",
+        "The transaction must be \"executed\", \"rejected\", \"cancelled\" or \"expired\", or be",
+        "deprecated by a change of the multisig. All attached instructions",
+        "must be closed beforehand with close_instruction."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeInstruction",
+      "docs": [
+        "Instruction to close the last instruction attached to a closable transaction",
+        "and reclaim its rent. Instructions are closed from the last one to the first",
+        "one, decrementing the transaction instruction index each time."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateMultisigSettings",
+      "docs": [
+        "The instruction to update the multisig settings."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newTimeLock",
+          "type": "u32"
+        },
+        {
+          "name": "spendingLimitEnabled",
+          "type": "bool"
+        },
+        {
+          "name": "guardian",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeMemberWithGuardian",
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "remover",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "oldMember",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "addAuthorityPolicy",
+      "docs": [
+        "Instruction to create a policy overriding the multisig threshold and time lock",
+        "for the transactions of a given authority. Authority 0 (internal transactions)",
+        "always uses the multisig threshold and time lock."
+      ],
+      "accounts": [
+        {
+          "name": "authorityPolicy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        },
+        {
+          "name": "threshold",
+          "type": "u16"
+        },
+        {
+          "name": "timeLock",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "updateAuthorityPolicy",
+      "docs": [
+        "Instruction to update the threshold and time lock of an authority policy"
+      ],
+      "accounts": [
+        {
+          "name": "authorityPolicy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "threshold",
+          "type": "u16"
+        },
+        {
+          "name": "timeLock",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "removeAuthorityPolicy",
+      "docs": [
+        "Instruction to remove an authority policy, the authority",
+        "then falls back to the multisig threshold and time lock"
+      ],
+      "accounts": [
+        {
+          "name": "authorityPolicy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addSpendingLimit",
+      "accounts": [
+        {
+          "name": "spendingLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "This is usually the same as `config_authority`, but can be a different account if needed."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "createKey",
+          "type": "publicKey"
+        },
+        {
+          "name": "mint",
+          "type": "publicKey"
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "members",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "destinations",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "period",
+          "type": {
+            "defined": "Period"
+          }
+        },
+        {
+          "name": "mode",
+          "type": {
+            "defined": "SpendingLimitMode"
+          }
+        },
+        {
+          "name": "expiration",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeSpendingLimit",
+      "docs": [
+        "Method to remove a spending limit"
+      ],
+      "accounts": [
+        {
+          "name": "spendingLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "pauseSpendingLimit",
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "disabler",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "spendingLimitUse",
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "spendingLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "vaultTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Multisig vault token account to transfer tokens from in case `spending_limit.mint` is an SPL token."
+          ]
+        },
+        {
+          "name": "destinationTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "In case `spending_limit.mint` is an SPL token."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "decimals",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "proposeGuardianChange",
+      "docs": [
+        "Instruction to rotate (Some) or remove (None) the guardian of the multisig.",
+        "The change can be finalized once the multisig time lock and the guardian",
+        "change delay have passed, and can be vetoed by the current guardian until then,",
+        "up to MAX_GUARDIAN_VETOES changes in a row."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "guardian",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "finalizeGuardianChange",
+      "docs": [
+        "Instruction applying a pending guardian change once its delay has passed, can be called by anyone"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "vetoGuardianChange",
+      "docs": [
+        "Instruction for the current guardian to veto a pending guardian change.",
+        "The guardian can't veto more than MAX_GUARDIAN_VETOES changes in a row,",
+        "the count is reset once a change is finalized."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "guardian",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "freezeMultisig",
+      "docs": [
+        "Instruction for the guardian to freeze the multisig for `duration` seconds, blocking",
+        "the execution of non internal transactions and the use of spending limits.",
+        "Members can still vote on and cancel transactions."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "guardian",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "duration",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "unfreezeMultisig",
+      "docs": [
+        "Instruction to end a freeze early, needs to be executed as an internal transaction"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "proposeRecovery",
+      "docs": [
+        "Instruction for the guardian to propose replacing the keys of members, ie. when members lost their keys.",
+        "The recovery can be finalized once the multisig time lock and the recovery delay have passed,",
+        "and can be vetoed until then by any member whose key is not replaced."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recovery",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "guardian",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "replacements",
+          "type": {
+            "vec": {
+              "defined": "MemberReplacement"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "vetoRecovery",
+      "docs": [
+        "Instruction for any member not replaced by a pending member recovery to veto it"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recovery",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "finalizeRecovery",
+      "docs": [
+        "Instruction applying a member recovery once its delay has passed, can be called by anyone.",
+        "The replaced members keep their permissions and weight."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recovery",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createGuardianSet",
+      "docs": [
+        "Instruction to create a M-of-N guardian set for the multisig. The set becomes",
+        "the guardian of the multisig once its address is set as the multisig guardian."
+      ],
+      "accounts": [
+        {
+          "name": "guardianSet",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "guardians",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "threshold",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "updateGuardianSet",
+      "docs": [
+        "Instruction to replace the guardians and the threshold of the guardian set.",
+        "Pending actions only count the approvals of the new guardians."
+      ],
+      "accounts": [
+        {
+          "name": "guardianSet",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "guardians",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "threshold",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "createGuardianAction",
+      "docs": [
+        "Instruction for a guardian to propose an action of the guardian set, an instruction",
+        "of this program signed by the guardian set (ie, pause_spending_limit, remove_member_with_guardian).",
+        "The proposing guardian approves the action."
+      ],
+      "accounts": [
+        {
+          "name": "guardianSet",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "action",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "instruction",
+          "type": {
+            "defined": "IncomingInstruction"
+          }
+        }
+      ]
+    },
+    {
+      "name": "approveGuardianAction",
+      "docs": [
+        "Instruction for a guardian to approve a guardian action"
+      ],
+      "accounts": [
+        {
+          "name": "guardianSet",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "action",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "guardian",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeGuardianAction",
+      "docs": [
+        "Instruction for a guardian to execute a guardian action approved by the guardian set threshold.",
+        "The instruction is signed by the guardian set, and the action account is closed."
+      ],
+      "accounts": [
+        {
+          "name": "guardianSet",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "action",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "guardian",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateMultisig",
+      "docs": [
+        "Instruction to convert a multisig created with the legacy layout, before members",
+        "had permissions and weights, to the current layout. Legacy members get every",
+        "permission and a weight of 1. The account is reallocated, the rent payer tops",
+        "up its rent. Can be called by anyone as the conversion is deterministic."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "create accounts with the multisig discriminator."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateSpendingLimit",
+      "docs": [
+        "Instruction to convert a spending limit created with the legacy layout, for a single",
+        "member, to the current layout. The account is reallocated, the rent payer tops up its rent.",
+        "Can be called by anyone as the conversion is deterministic."
+      ],
+      "accounts": [
+        {
+          "name": "spendingLimit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "create accounts with the spending limit discriminator."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "ms",
+      "docs": [
+        "Ms is the basic state account for a multisig."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "threshold",
+            "type": "u16"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u16"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "msChangeIndex",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "createKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": {
+                "defined": "Member"
+              }
+            }
+          },
+          {
+            "name": "timeLock",
+            "type": "u32"
+          },
+          {
+            "name": "spendingLimitEnabled",
+            "type": "bool"
+          },
+          {
+            "name": "guardian",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "rentCollector",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "proposalLifetime",
+            "type": "u32"
+          },
+          {
+            "name": "pendingGuardianChange",
+            "type": {
+              "option": {
+                "defined": "PendingGuardianChange"
+              }
+            }
+          },
+          {
+            "name": "frozenUntil",
+            "type": "i64"
+          },
+          {
+            "name": "timeLockMode",
+            "type": {
+              "defined": "TimeLockMode"
+            }
+          },
+          {
+            "name": "executionPolicy",
+            "type": {
+              "defined": "ExecutionPolicy"
+            }
+          },
+          {
+            "name": "guardianVetoes",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "msTransaction",
+      "docs": [
+        "The MsTransaction is the state account for a multisig transaction"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "ms",
+            "type": "publicKey"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "authorityBump",
+            "type": "u8"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": "MsTransactionStatus"
+            }
+          },
+          {
+            "name": "instructionIndex",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "approved",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "rejected",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "cancelled",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "executedIndex",
+            "type": "u8"
+          },
+          {
+            "name": "executionDelay",
+            "type": "u32"
+          },
+          {
+            "name": "activatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "msInstruction",
+      "docs": [
+        "The state account for an instruction that is attached to a transaction.",
+        "Almost analagous to the native Instruction struct for solana, but with an extra",
+        "field for the bump."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": {
+                "defined": "MsAccountMeta"
+              }
+            }
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          },
+          {
+            "name": "instructionIndex",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "instructionBuffer",
+      "docs": [
+        "Instruction buffer account, holding a serialized IncomingInstruction written in chunks",
+        "by the creator of a draft transaction, for instructions too large to be attached",
+        "with a single add_instruction (ie. program buffer writes)."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "docs": [
+              "The multisig this belongs to."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "transaction",
+            "docs": [
+              "The draft transaction the instruction will be attached to."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "creator",
+            "docs": [
+              "The creator of the transaction, writing the buffer."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "finalHash",
+            "docs": [
+              "The sha256 hash of the complete serialized IncomingInstruction."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "finalSize",
+            "docs": [
+              "The size of the complete serialized IncomingInstruction."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "buffer",
+            "docs": [
+              "The chunks written so far."
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "authorityPolicy",
+      "docs": [
+        "Authority policy account, overriding the multisig threshold and time lock",
+        "for the transactions using a specific authority (vault, upgrade authority, etc.)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "docs": [
+              "The multisig this belongs to."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "authorityIndex",
+            "docs": [
+              "The index of the authority the policy applies to."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "The vote weight threshold for the transactions of this authority."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "timeLock",
+            "docs": [
+              "The time lock duration in seconds for the transactions of this authority."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "guardianSet",
+      "docs": [
+        "Guardian set account, a M-of-N set of guardian keys for a multisig.",
+        "When the guardian of the multisig is set to the address of this account,",
+        "guardian actions must be approved by `threshold` guardians of the set,",
+        "and are then signed by this account."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "docs": [
+              "The multisig this belongs to."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "guardians",
+            "docs": [
+              "The keys of the guardians, sorted."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "The number of guardian approvals needed to execute a guardian action."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "actionIndex",
+            "docs": [
+              "Look up and seed reference for guardian actions."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "guardianAction",
+      "docs": [
+        "Guardian action account, an instruction of the squads program to be signed",
+        "by a guardian set once approved by enough guardians."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSet",
+            "docs": [
+              "The guardian set this belongs to."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "actionIndex",
+            "docs": [
+              "The index of the action in the guardian set."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "creator",
+            "docs": [
+              "The guardian who created the action, receives the rent once executed."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "keys",
+            "docs": [
+              "The accounts of the instruction to sign."
+            ],
+            "type": {
+              "vec": {
+                "defined": "MsAccountMeta"
+              }
+            }
+          },
+          {
+            "name": "data",
+            "docs": [
+              "The data of the instruction to sign."
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "approved",
+            "docs": [
+              "The guardians who approved the action."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "memberRecovery",
+      "docs": [
+        "Member recovery account, a replacement of member keys proposed by the guardian.",
+        "It can be finalized once its delay has passed, and vetoed until then by any",
+        "member whose key is not replaced."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "docs": [
+              "The multisig this belongs to."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "replacements",
+            "docs": [
+              "The member keys to replace."
+            ],
+            "type": {
+              "vec": {
+                "defined": "MemberReplacement"
+              }
+            }
+          },
+          {
+            "name": "readyAt",
+            "docs": [
+              "Timestamp after which the recovery can be finalized."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "rentPayer",
+            "docs": [
+              "Receives the rent once the recovery is finalized or vetoed."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "spendingLimit",
+      "docs": [
+        "Spending Limit struct"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "createKey",
+            "docs": [
+              "create key used to seed the spending limit pda"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "multisig",
+            "docs": [
+              "The multisig this belongs to."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "authorityIndex",
+            "docs": [
+              "The index of the vault that the spending limit is for."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The token mint the spending limit is for.",
+              "Pubkey::default() means SOL.",
+              "use NATIVE_MINT for Wrapped SOL."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The amount of tokens that can be spent in a period.",
+              "This amount is in decimals of the mint,",
+              "so 1 SOL would be `1_000_000_000` and 1 USDC would be `1_000_000`."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "members",
+            "docs": [
+              "The members that can use the spending limit, sharing the same remaining amount."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "destinations",
+            "docs": [
+              "The destinations the spending limit can send to, any destination if empty.",
+              "These are the owners of the token accounts for SPL tokens."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "period",
+            "docs": [
+              "The reset period of the spending limit.",
+              "When it passes, the remaining amount is reset, unless it's `Period::OneTime`."
+            ],
+            "type": {
+              "defined": "Period"
+            }
+          },
+          {
+            "name": "mode",
+            "docs": [
+              "How the amount spent in a period is accounted."
+            ],
+            "type": {
+              "defined": "SpendingLimitMode"
+            }
+          },
+          {
+            "name": "expiration",
+            "docs": [
+              "Optional unix timestamp after which the spending limit can't be used anymore."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "remainingAmount",
+            "docs": [
+              "The remaining amount of tokens that can be spent in the current period.",
+              "When reaches 0, the spending limit cannot be used anymore until the period reset.",
+              "For rolling limits, the remaining amount in the window as of the last use."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastReset",
+            "docs": [
+              "Unix timestamp marking the last time the spending limit was reset (or created)."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "spends",
+            "docs": [
+              "The spends of the rolling window, oldest first. Only used by rolling limits."
+            ],
+            "type": {
+              "vec": {
+                "defined": "Spend"
+              }
+            }
+          },
+          {
+            "name": "totalSpent",
+            "docs": [
+              "The total amount spent since the creation of the spending limit."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "useCount",
+            "docs": [
+              "The number of times the spending limit was used."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastUsed",
+            "docs": [
+              "Unix timestamp of the last use of the spending limit, 0 if never used."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Member",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "key",
+            "type": "publicKey"
+          },
+          {
+            "name": "guardianCanRemove",
+            "type": "bool"
+          },
+          {
+            "name": "permissions",
+            "type": "u8"
+          },
+          {
+            "name": "weight",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PendingGuardianChange",
+      "docs": [
+        "Guardian rotation or removal waiting for its delay"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardian",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "readyAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MsAccountMeta",
+      "docs": [
+        "Wrapper for our internal MsInstruction key serialization schema",
+        "MsAccount meta is identical to the AccountMeta struct, but defined",
+        "here for serialization purposes."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pubkey",
+            "type": "publicKey"
+          },
+          {
+            "name": "isSigner",
+            "type": "bool"
+          },
+          {
+            "name": "isWritable",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "IncomingInstruction",
+      "docs": [
+        "Incoming instruction schema, used as an argument in the attach_instruction.",
+        "Identical to the solana struct for Instruction, but uses the MsAccountMeta.",
+        "Provided for de/serialization purposes."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": {
+                "defined": "MsAccountMeta"
+              }
+            }
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MemberReplacement",
+      "docs": [
+        "Replacement of the key of a member, used by member recoveries"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "oldKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "newKey",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Spend",
+      "docs": [
+        "A spend of a rolling spending limit"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyMs",
+      "docs": [
+        "Layout of the Ms accounts created before members had permissions and weights,",
+        "only deserialized by `migrate_multisig` to convert them to the current layout."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "threshold",
+            "type": "u16"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u16"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "msChangeIndex",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "createKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": {
+                "defined": "LegacyMember"
+              }
+            }
+          },
+          {
+            "name": "timeLock",
+            "type": "u32"
+          },
+          {
+            "name": "spendingLimitEnabled",
+            "type": "bool"
+          },
+          {
+            "name": "guardian",
+            "type": {
+              "option": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyMember",
+      "docs": [
+        "Layout of the members of a LegacyMs"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "key",
+            "type": "publicKey"
+          },
+          {
+            "name": "guardianCanRemove",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacySpendingLimit",
+      "docs": [
+        "Layout of the SpendingLimit accounts created before they could be shared by several members,",
+        "only deserialized by `migrate_spending_limit` to convert them to the current layout."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "createKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "member",
+            "type": "publicKey"
+          },
+          {
+            "name": "period",
+            "type": {
+              "defined": "Period"
+            }
+          },
+          {
+            "name": "remainingAmount",
+            "type": "u64"
+          },
+          {
+            "name": "lastReset",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TimeLockMode",
+      "docs": [
+        "TimeLockMode enum of when the time lock of the multisig applies."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Voting"
+          },
+          {
+            "name": "Execution"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ExecutionPolicy",
+      "docs": [
+        "ExecutionPolicy enum of who can execute the ExecuteReady transactions,",
+        "members with the execute permission can always execute them."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Members"
+          },
+          {
+            "name": "Executors",
+            "fields": [
+              {
+                "name": "keys",
+                "type": {
+                  "vec": "publicKey"
+                }
+              }
+            ]
+          },
+          {
+            "name": "Permissionless"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MsTransactionStatus",
+      "docs": [
+        "MsTransactionStatus enum of the current status of the Multisig Transaction."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Draft",
+            "fields": [
+              {
+                "name": "timestamp",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "Active",
+            "fields": [
+              {
+                "name": "timestamp",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "ExecuteReady",
+            "fields": [
+              {
+                "name": "timestamp",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "Executed",
+            "fields": [
+              {
+                "name": "timestamp",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "Rejected",
+            "fields": [
+              {
+                "name": "timestamp",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "Cancelled",
+            "fields": [
+              {
+                "name": "timestamp",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "Expired",
+            "fields": [
+              {
+                "name": "timestamp",
+                "type": "i64"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "SpendingLimitMode",
+      "docs": [
+        "How the amount spent in a period is accounted"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Fixed"
+          },
+          {
+            "name": "Rolling"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Period",
+      "docs": [
+        "Period enum"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Daily"
+          },
+          {
+            "name": "Weekly"
+          },
+          {
+            "name": "Monthly"
+          },
+          {
+            "name": "OneTime"
+          },
+          {
+            "name": "Custom",
+            "fields": [
+              {
+                "name": "seconds",
+                "type": "u32"
+              }
+            ]
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "MultisigCreated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "createKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "members",
+          "type": {
+            "vec": {
+              "defined": "Member"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "timeLock",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MemberAdded",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": {
+            "defined": "Member"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MemberRemoved",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "byGuardian",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ThresholdChanged",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u16",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityAdded",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u16",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MultisigSettingsChanged",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timeLock",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "spendingLimitEnabled",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "rentCollector",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "proposalLifetime",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "timeLockMode",
+          "type": {
+            "defined": "TimeLockMode"
+          },
+          "index": false
+        },
+        {
+          "name": "executionPolicy",
+          "type": {
+            "defined": "ExecutionPolicy"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GuardianChangeProposed",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "readyAt",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GuardianChangeVetoed",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MultisigFrozen",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "frozenUntil",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MultisigUnfrozen",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RecoveryProposed",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "replacements",
+          "type": {
+            "vec": {
+              "defined": "MemberReplacement"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "readyAt",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RecoveryVetoed",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RecoveryFinalized",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "replacements",
+          "type": {
+            "vec": {
+              "defined": "MemberReplacement"
+            }
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityPolicySet",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "timeLock",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityPolicyRemoved",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionCreated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InstructionAdded",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "instruction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "instructionIndex",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InstructionUpdated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "instruction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "instructionIndex",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InstructionRemoved",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "instruction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "instructionIndex",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionActivated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionVoted",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "approve",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "MsTransactionStatus"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionCancelVoted",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "MsTransactionStatus"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionExecuted",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "executor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "executedIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "MsTransactionStatus"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionExpired",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionVetoed",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionClosed",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "rentCollector",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InstructionClosed",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "instruction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "rentCollector",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SpendingLimitCreated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "spendingLimit",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "createKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "members",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "destinations",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "period",
+          "type": {
+            "defined": "Period"
+          },
+          "index": false
+        },
+        {
+          "name": "mode",
+          "type": {
+            "defined": "SpendingLimitMode"
+          },
+          "index": false
+        },
+        {
+          "name": "expiration",
+          "type": {
+            "option": "i64"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SpendingLimitRemoved",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "spendingLimit",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SpendingLimitPaused",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SpendingLimitUsed",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "spendingLimit",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "destination",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "remainingAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalSpent",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "useCount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GuardianSetChanged",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardianSet",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardians",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u16",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GuardianActionCreated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardianSet",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "action",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "actionIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "IncomingInstruction",
-      "docs": [
-        "Incoming instruction schema, used as an argument in the attach_instruction.",
-        "Identical to the solana struct for Instruction, but uses the MsAccountMeta.",
-        "Provided for de/serialization purposes."
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "programId",
-            "type": "publicKey"
-          },
-          {
-            "name": "keys",
-            "type": {
-              "vec": {
-                "defined": "MsAccountMeta"
-              }
-            }
-          },
-          {
-            "name": "data",
-            "type": "bytes"
-          }
-        ]
-      }
+      "name": "GuardianActionApproved",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardianSet",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "action",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "approvals",
+          "type": "u16",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "MsTransactionStatus",
-      "docs": [
-        "MsTransactionStatus enum of the current status of the Multisig Transaction."
-      ],
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Draft",
-            "fields": [
-              {
-                "name": "timestamp",
-                "type": "i64"
-              }
-            ]
-          },
-          {
-            "name": "Active",
-            "fields": [
-              {
-                "name": "timestamp",
-                "type": "i64"
-              }
-            ]
-          },
-          {
-            "name": "ExecuteReady",
-            "fields": [
-              {
-                "name": "timestamp",
-                "type": "i64"
-              }
-            ]
-          },
-          {
-            "name": "Executed",
-            "fields": [
-              {
-                "name": "timestamp",
-                "type": "i64"
-              }
-            ]
-          },
-          {
-            "name": "Rejected",
-            "fields": [
-              {
-                "name": "timestamp",
-                "type": "i64"
-              }
-            ]
-          },
-          {
-            "name": "Cancelled",
-            "fields": [
-              {
-                "name": "timestamp",
-                "type": "i64"
-              }
-            ]
-          }
-        ]
-      }
+      "name": "GuardianActionExecuted",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardianSet",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "action",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "executor",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "Period",
-      "docs": [
-        "Period enum"
-      ],
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Daily"
+      "name": "MultisigMigrated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "members",
+          "type": {
+            "vec": {
+              "defined": "Member"
+            }
           },
-          {
-            "name": "Weekly"
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SpendingLimitMigrated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "spendingLimit",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "members",
+          "type": {
+            "vec": "publicKey"
           },
-          {
-            "name": "Monthly"
-          }
-        ]
-      }
+          "index": false
+        }
+      ]
     }
   ],
   "errors": [
@@ -2292,6 +7572,138 @@ export const IDL: SquadsMpl = {
     {
       "code": 6026,
       "name": "ChangeIndexExceedsTransactionIndex"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidRentCollector"
+    },
+    {
+      "code": 6028,
+      "name": "InstructionsNotClosed"
+    },
+    {
+      "code": 6029,
+      "name": "TransactionExpired"
+    },
+    {
+      "code": 6030,
+      "name": "TransactionNotExpired"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidProposalLifetime"
+    },
+    {
+      "code": 6032,
+      "name": "InvalidPermissions"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidWeight"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidGuardians"
+    },
+    {
+      "code": 6035,
+      "name": "GuardianActionNotApproved"
+    },
+    {
+      "code": 6036,
+      "name": "GuardianChangeRequiresDelay"
+    },
+    {
+      "code": 6037,
+      "name": "NoPendingGuardianChange"
+    },
+    {
+      "code": 6038,
+      "name": "GuardianChangeNotReady"
+    },
+    {
+      "code": 6039,
+      "name": "MultisigFrozen"
+    },
+    {
+      "code": 6040,
+      "name": "InvalidFreezeDuration"
+    },
+    {
+      "code": 6041,
+      "name": "FreezeCooldown"
+    },
+    {
+      "code": 6042,
+      "name": "NotFrozen"
+    },
+    {
+      "code": 6043,
+      "name": "InvalidRecovery"
+    },
+    {
+      "code": 6044,
+      "name": "RecoveryNotReady"
+    },
+    {
+      "code": 6045,
+      "name": "InvalidDestinations"
+    },
+    {
+      "code": 6046,
+      "name": "DestinationNotAllowed"
+    },
+    {
+      "code": 6047,
+      "name": "InvalidSpendingLimitMembers"
+    },
+    {
+      "code": 6048,
+      "name": "InvalidPeriod"
+    },
+    {
+      "code": 6049,
+      "name": "InvalidExpiration"
+    },
+    {
+      "code": 6050,
+      "name": "SpendingLimitExpired"
+    },
+    {
+      "code": 6051,
+      "name": "UnauthorizedExecutor"
+    },
+    {
+      "code": 6052,
+      "name": "InvalidExecutors"
+    },
+    {
+      "code": 6053,
+      "name": "InvalidBufferSize"
+    },
+    {
+      "code": 6054,
+      "name": "InstructionBufferIncomplete"
+    },
+    {
+      "code": 6055,
+      "name": "InvalidBufferHash"
+    },
+    {
+      "code": 6056,
+      "name": "InvalidInstructionBuffer"
+    },
+    {
+      "code": 6057,
+      "name": "AuthorityPolicyUnsatisfiable"
+    },
+    {
+      "code": 6058,
+      "name": "AlreadyMigrated"
+    },
+    {
+      "code": 6059,
+      "name": "GuardianVetoLimitReached"
     }
   ]
 };
@@ -73,13 +73,14 @@ pub struct CreateTransaction<'info> {
     #[account(
         mut,
         constraint = multisig.is_member(creator.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.has_permission(creator.key(), PERMISSION_INITIATE) @MsError::UnauthorizedMember,
     )]
     pub creator: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
 /// The account context for adding an instruction to a transaction
-/// The transaction must be in a Draft state, and the creator must be a member of the multisig
+/// The transaction must be in a Draft state, and the creator must be a member of the multisig with the initiate permission
 /// 
 /// Expects the following accounts:
 /// 1. multisig account
@@ -134,21 +135,27 @@ pub struct AddInstruction<'info> {
     #[account(
         mut,
         constraint = multisig.is_member(creator.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.has_permission(creator.key(), PERMISSION_INITIATE) @MsError::UnauthorizedMember,
     )]
     pub creator: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
-/// The account context for activating a transaction
-/// The transaction must be in a Draft state, and the creator must be a member of the multisig
-/// 
+/// The account context for replacing an instruction attached to a draft transaction
+/// The transaction must be in a Draft state, and the creator must be the transaction creator
+///
 /// Expects the following accounts:
 /// 1. multisig account
 /// 2. transaction account
-/// 3. creator account [signer]
-/// 
+/// 3. instruction account
+/// 4. creator account [signer]
+/// 5. system program
+///
+/// Expects the following arguments:
+/// 1. incoming_instruction: IncomingInstruction
 #[derive(Accounts)]
-pub struct ActivateTransaction<'info> {
+#[instruction(incoming_instruction: IncomingInstruction)]
+pub struct UpdateInstruction<'info> {
     #[account(
         seeds = [
             b"squad",
@@ -160,7 +167,6 @@ pub struct ActivateTransaction<'info> {
     pub multisig: Account<'info, Ms>,
 
     #[account(
-        mut,
         seeds = [
             b"squad",
             multisig.key().as_ref(),
@@ -169,29 +175,47 @@ pub struct ActivateTransaction<'info> {
         ], bump = transaction.bump,
         constraint = transaction.creator == creator.key(),
         constraint = matches!(transaction.status, MsTransactionStatus::Draft { .. }) @MsError::InvalidTransactionState,
-        constraint = transaction.transaction_index > multisig.ms_change_index @MsError::DeprecatedTransaction,
         constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
     )]
     pub transaction: Account<'info, MsTransaction>,
 
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            &instruction.instruction_index.to_le_bytes(),
+            b"instruction"
+        ], bump = instruction.bump,
+        // the size follows the new instruction, the difference of rent is paid or refunded to the creator
+        realloc = 8 + incoming_instruction.get_max_size(),
+        realloc::payer = creator,
+        realloc::zero = false,
+    )]
+    pub instruction: Account<'info, MsInstruction>,
+
     #[account(
         mut,
         constraint = multisig.is_member(creator.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.has_permission(creator.key(), PERMISSION_INITIATE) @MsError::UnauthorizedMember,
     )]
     pub creator: Signer<'info>,
-    // pub system_program: Program<'info, System>,
+    pub system_program: Program<'info, System>,
 }
 
-/// The account context for voting on a transaction
-/// The transaction must be in an Active state, and the voter must be a member of the multisig
-/// 
+/// The account context for removing an instruction attached to a draft transaction
+/// The transaction must be in a Draft state, and the creator must be the transaction creator
+///
 /// Expects the following accounts:
 /// 1. multisig account
 /// 2. transaction account
-/// 3. voter account [signer]
-/// 
+/// 3. last instruction account, closed to the creator
+/// 4. creator account [signer]
+///
+/// Followed by the instruction accounts from the removed instruction to the one before
+/// the last, in order, as writable remaining accounts.
 #[derive(Accounts)]
-pub struct VoteTransaction<'info> {
+pub struct RemoveInstruction<'info> {
     #[account(
         seeds = [
             b"squad",
@@ -210,32 +234,51 @@ pub struct VoteTransaction<'info> {
             &transaction.transaction_index.to_le_bytes(),
             b"transaction"
         ], bump = transaction.bump,
-        constraint = matches!(transaction.status, MsTransactionStatus::Active { .. }) @MsError::InvalidTransactionState ,
-        constraint = transaction.transaction_index > multisig.ms_change_index @MsError::DeprecatedTransaction,
+        constraint = transaction.creator == creator.key(),
+        constraint = matches!(transaction.status, MsTransactionStatus::Draft { .. }) @MsError::InvalidTransactionState,
         constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
     )]
     pub transaction: Account<'info, MsTransaction>,
 
     #[account(
         mut,
-        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            &instruction.instruction_index.to_le_bytes(),
+            b"instruction"
+        ], bump = instruction.bump,
+        // the last instruction account is closed, keeping the instruction indexes contiguous
+        constraint = instruction.instruction_index == transaction.instruction_index @MsError::InvalidInstructionAccount,
+        close = creator
     )]
-    pub member: Signer<'info>,
-    // pub system_program: Program<'info, System>,
+    pub instruction: Account<'info, MsInstruction>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(creator.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.has_permission(creator.key(), PERMISSION_INITIATE) @MsError::UnauthorizedMember,
+    )]
+    pub creator: Signer<'info>,
 }
 
-/// The account context for submitting a vote to cancel a transaction
-/// The transaction must be in an ExecuteReady state, and the voter must be a member of the multisig
-/// 
+/// The account context for creating the instruction buffer of a draft transaction
+/// The transaction must be in a Draft state, and the creator must be the transaction creator
+///
 /// Expects the following accounts:
 /// 1. multisig account
 /// 2. transaction account
-/// 3. member account [signer]
-/// 
+/// 3. instruction buffer account
+/// 4. creator account [signer]
+/// 5. system program
+///
+/// Expects the following arguments:
+/// 1. final_hash: [u8; 32]
+/// 2. final_size: u32
 #[derive(Accounts)]
-pub struct CancelTransaction<'info> {
+#[instruction(final_hash: [u8; 32], final_size: u32)]
+pub struct CreateInstructionBuffer<'info> {
     #[account(
-        mut,
         seeds = [
             b"squad",
             multisig.create_key.as_ref(),
@@ -246,38 +289,50 @@ pub struct CancelTransaction<'info> {
     pub multisig: Account<'info, Ms>,
 
     #[account(
-        mut,
         seeds = [
             b"squad",
             multisig.key().as_ref(),
             &transaction.transaction_index.to_le_bytes(),
             b"transaction"
         ], bump = transaction.bump,
-        constraint = matches!(transaction.status, MsTransactionStatus::ExecuteReady { .. }) @MsError::InvalidTransactionState,
+        constraint = transaction.creator == creator.key(),
+        constraint = matches!(transaction.status, MsTransactionStatus::Draft { .. }) @MsError::InvalidTransactionState,
         constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
     )]
     pub transaction: Account<'info, MsTransaction>,
 
+    #[account(
+        init,
+        payer = creator,
+        space = InstructionBuffer::space(final_size),
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            b"instruction_buffer"
+        ],
+        bump
+    )]
+    pub instruction_buffer: Account<'info, InstructionBuffer>,
+
     #[account(
         mut,
-        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.is_member(creator.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.has_permission(creator.key(), PERMISSION_INITIATE) @MsError::UnauthorizedMember,
     )]
-    pub member: Signer<'info>,
+    pub creator: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
-/// The account context for executing a transaction
-/// The transaction must be in an ExecuteReady state, and the creator must be a member of the multisig
-/// 
+/// The account context for writing a chunk to the instruction buffer of a draft transaction
+///
 /// Expects the following accounts:
 /// 1. multisig account
 /// 2. transaction account
-/// 3. member account [signer]
-/// 
+/// 3. instruction buffer account
+/// 4. creator account [signer]
 #[derive(Accounts)]
-pub struct ExecuteTransaction<'info> {
+pub struct WriteInstructionBuffer<'info> {
     #[account(
-        mut,
         seeds = [
             b"squad",
             multisig.create_key.as_ref(),
@@ -285,43 +340,53 @@ pub struct ExecuteTransaction<'info> {
         ],
         bump = multisig.bump,
     )]
-    pub multisig: Box<Account<'info, Ms>>,
+    pub multisig: Account<'info, Ms>,
 
     #[account(
-        mut,
         seeds = [
             b"squad",
             multisig.key().as_ref(),
             &transaction.transaction_index.to_le_bytes(),
             b"transaction"
         ], bump = transaction.bump,
-        constraint = matches!(transaction.status, MsTransactionStatus::ExecuteReady { .. }) @MsError::InvalidTransactionState,
+        constraint = transaction.creator == creator.key(),
+        constraint = matches!(transaction.status, MsTransactionStatus::Draft { .. }) @MsError::InvalidTransactionState,
         constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
-        constraint = transaction.transaction_index > multisig.ms_change_index @MsError::DeprecatedTransaction,
-        // if they've already started sequential execution, they must continue
-        constraint = transaction.executed_index < 1 @MsError::PartialExecution,
     )]
     pub transaction: Account<'info, MsTransaction>,
 
     #[account(
         mut,
-        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            b"instruction_buffer"
+        ],
+        bump = instruction_buffer.bump,
     )]
-    pub member: Signer<'info>,
+    pub instruction_buffer: Account<'info, InstructionBuffer>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(creator.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.has_permission(creator.key(), PERMISSION_INITIATE) @MsError::UnauthorizedMember,
+    )]
+    pub creator: Signer<'info>,
 }
 
-/// The account context for executing a transaction instruction individually
-/// The transaction must be in an ExecuteReady state, and the creator must be a member of the multisig, and the instruction must correlate to the next executed index
-/// 
+/// The account context for attaching the instruction of a complete instruction buffer
+/// to a draft transaction, closing the buffer
+///
 /// Expects the following accounts:
 /// 1. multisig account
 /// 2. transaction account
-/// 3. member account [signer]
-/// 
+/// 3. instruction buffer account
+/// 4. instruction account
+/// 5. creator account [signer]
+/// 6. system program
 #[derive(Accounts)]
-pub struct ExecuteInstruction<'info> {
+pub struct AddInstructionFromBuffer<'info> {
     #[account(
-        mut,
         seeds = [
             b"squad",
             multisig.create_key.as_ref(),
@@ -329,7 +394,7 @@ pub struct ExecuteInstruction<'info> {
         ],
         bump = multisig.bump,
     )]
-    pub multisig: Box<Account<'info, Ms>>,
+    pub multisig: Account<'info, Ms>,
 
     #[account(
         mut,
@@ -339,9 +404,9 @@ pub struct ExecuteInstruction<'info> {
             &transaction.transaction_index.to_le_bytes(),
             b"transaction"
         ], bump = transaction.bump,
-        constraint = matches!(transaction.status, MsTransactionStatus::ExecuteReady { .. }) @MsError::InvalidTransactionState,
+        constraint = transaction.creator == creator.key(),
+        constraint = matches!(transaction.status, MsTransactionStatus::Draft { .. }) @MsError::InvalidTransactionState,
         constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
-        constraint = transaction.transaction_index > multisig.ms_change_index @MsError::DeprecatedTransaction,
     )]
     pub transaction: Account<'info, MsTransaction>,
 
@@ -350,43 +415,77 @@ pub struct ExecuteInstruction<'info> {
         seeds = [
             b"squad",
             transaction.key().as_ref(),
-            &transaction.executed_index.checked_add(1).unwrap().to_le_bytes(),
+            b"instruction_buffer"
+        ],
+        bump = instruction_buffer.bump,
+        close = creator
+    )]
+    pub instruction_buffer: Account<'info, InstructionBuffer>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + instruction_buffer.instruction_size(),
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            &transaction.instruction_index.checked_add(1).unwrap().to_le_bytes(),
             b"instruction"
-        ], bump = instruction.bump,
-        // it should be the next expected instruction account to be executed
-        constraint = instruction.instruction_index == transaction.executed_index.checked_add(1).unwrap() @MsError::InvalidInstructionAccount,
+        ],
+        bump
     )]
     pub instruction: Account<'info, MsInstruction>,
 
     #[account(
         mut,
-        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.is_member(creator.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.has_permission(creator.key(), PERMISSION_INITIATE) @MsError::UnauthorizedMember,
     )]
-    pub member: Signer<'info>,
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
-/// The account context for executing an internal multisig transaction (which changes the multisig account)
-/// 
+/// The account context for closing an instruction buffer that won't be attached,
+/// whatever the status of its transaction, which may already be closed
+///
 /// Expects the following accounts:
-/// 1. multisig account [signer]
+/// 1. transaction account
+/// 2. instruction buffer account
+/// 3. creator account [signer]
 #[derive(Accounts)]
-pub struct MsAuth<'info> {
+pub struct CloseInstructionBuffer<'info> {
+    /// CHECK: only used for the seeds of the buffer, the transaction may already be closed
+    pub transaction: UncheckedAccount<'info>,
+
     #[account(
         mut,
         seeds = [
             b"squad",
-            multisig.create_key.as_ref(),
-            b"multisig"
-        ], bump = multisig.bump,
-        signer
+            transaction.key().as_ref(),
+            b"instruction_buffer"
+        ],
+        bump = instruction_buffer.bump,
+        has_one = transaction @MsError::InvalidInstructionAccount,
+        constraint = instruction_buffer.creator == creator.key() @MsError::InvalidInstructionAccount,
+        close = creator
     )]
-    pub multisig: Box<Account<'info, Ms>>,
+    pub instruction_buffer: Account<'info, InstructionBuffer>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
 }
 
+/// The account context for activating a transaction
+/// The transaction must be in a Draft state, and the creator must be a member of the multisig with the initiate permission
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. creator account [signer]
+/// 
 #[derive(Accounts)]
-pub struct RemoveMemberWithGuardian<'info> {
+pub struct ActivateTransaction<'info> {
     #[account(
-        mut,
         seeds = [
             b"squad",
             multisig.create_key.as_ref(),
@@ -398,94 +497,669 @@ pub struct RemoveMemberWithGuardian<'info> {
 
     #[account(
         mut,
-        constraint = multisig.guardian == Some(remover.key()) @ MsError::UnauthorizedMember,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.creator == creator.key(),
+        constraint = matches!(transaction.status, MsTransactionStatus::Draft { .. }) @MsError::InvalidTransactionState,
+        constraint = transaction.transaction_index > multisig.ms_change_index @MsError::DeprecatedTransaction,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
     )]
-    pub remover: Signer<'info>,
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(creator.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.has_permission(creator.key(), PERMISSION_INITIATE) @MsError::UnauthorizedMember,
+    )]
+    pub creator: Signer<'info>,
+    // pub system_program: Program<'info, System>,
 }
 
-/// The account context for reallocating the multisig account (for add member, where the size may need to be adjusted)
+/// The account context for voting on a transaction
+/// The transaction must be in an Active state, and the voter must be a member of the multisig with the vote permission
 /// 
 /// Expects the following accounts:
-/// 1. multisig account [signer]
-/// 2. rent sysvar
-/// 3. system program
-/// 
+/// 1. multisig account
+/// 2. transaction account
+/// 3. voter account [signer]
+/// 4. authority policy account (may not exist)
 /// 
 #[derive(Accounts)]
-pub struct MsAuthRealloc<'info> {
+pub struct VoteTransaction<'info> {
     #[account(
-        mut,
         seeds = [
             b"squad",
             multisig.create_key.as_ref(),
             b"multisig"
-        ], bump = multisig.bump,
-        signer
+        ],
+        bump = multisig.bump,
     )]
-    pub multisig: Box<Account<'info, Ms>>,
-
-    pub rent: Sysvar<'info, Rent>,
-    pub system_program: Program<'info, System>,
-}
+    pub multisig: Account<'info, Ms>,
 
-#[derive(Accounts)]
-#[instruction(create_key: Pubkey, mint: Pubkey, authority_index: u32 )]
-pub struct CreateSpendingLimit<'info> {
     #[account(
-        init,
-        payer = rent_payer,
-        space = SpendingLimit::LEN,
+        mut,
         seeds = [
             b"squad",
             multisig.key().as_ref(),
-            create_key.as_ref(),
-            b"spending_limit",
-        ],
-        bump,
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = matches!(transaction.status, MsTransactionStatus::Active { .. }) @MsError::InvalidTransactionState ,
+        constraint = transaction.transaction_index > multisig.ms_change_index @MsError::DeprecatedTransaction,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
     )]
-    pub spending_limit: Account<'info, SpendingLimit>,
+    pub transaction: Account<'info, MsTransaction>,
+
     #[account(
         mut,
-        seeds = [b"squad", multisig.create_key.as_ref(), b"multisig"],
-        bump = multisig.bump,
-        signer
+        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.has_permission(member.key(), PERMISSION_VOTE) @MsError::UnauthorizedMember,
     )]
-    pub multisig: Account<'info, Ms>,
-    /// This is usually the same as `config_authority`, but can be a different account if needed.
-    #[account(mut)]
-    pub rent_payer: Signer<'info>,
-    pub system_program: Program<'info, System>,
-}
+    pub member: Signer<'info>,
 
-#[derive(Accounts)]
-pub struct RemoveSpendingLimit<'info> {
+    /// CHECK: The authority policy PDA of the transaction authority, verified by the seeds. It may not exist.
     #[account(
-        mut,
         seeds = [
             b"squad",
             multisig.key().as_ref(),
-            &spending_limit.create_key.as_ref(),
-            b"spending_limit",
+            &transaction.authority_index.to_le_bytes(),
+            b"policy"
         ],
         bump,
-        close = multisig
-    )]
-    pub spending_limit: Account<'info, SpendingLimit>,
-    #[account(
-        mut,
-        seeds = [b"squad", multisig.create_key.as_ref(), b"multisig"],
-        bump = multisig.bump,
-        signer
     )]
-    pub multisig: Account<'info, Ms>,
-    #[account(address = solana_program::system_program::ID)]
-    pub system_program: Program<'info, System>,
+    pub authority_policy: AccountInfo<'info>,
+    // pub system_program: Program<'info, System>,
 }
 
-/// The account context for pausing the spending limit
-/// 1. multisig account [signer]
-/// 2. spending_limit_disabler_authority account [signer]
-#[derive(Accounts)]
+/// The account context for submitting a vote to cancel a transaction
+/// The transaction must be in an ExecuteReady state, and the voter must be a member of the multisig with the vote permission
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. member account [signer]
+/// 4. authority policy account (may not exist)
+/// 
+#[derive(Accounts)]
+pub struct CancelTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = matches!(transaction.status, MsTransactionStatus::ExecuteReady { .. }) @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.has_permission(member.key(), PERMISSION_VOTE) @MsError::UnauthorizedMember,
+    )]
+    pub member: Signer<'info>,
+
+    /// CHECK: The authority policy PDA of the transaction authority, verified by the seeds. It may not exist.
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.authority_index.to_le_bytes(),
+            b"policy"
+        ],
+        bump,
+    )]
+    pub authority_policy: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for executing a transaction
+/// The transaction must be in an ExecuteReady state, and the member must be allowed to execute by the execution policy of the multisig
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. member account [signer]
+/// 
+#[derive(Accounts)]
+pub struct ExecuteTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = matches!(transaction.status, MsTransactionStatus::ExecuteReady { .. }) @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.transaction_index > multisig.ms_change_index @MsError::DeprecatedTransaction,
+        // if they've already started sequential execution, they must continue
+        constraint = transaction.executed_index < 1 @MsError::PartialExecution,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        constraint = multisig.can_execute(member.key()) @MsError::UnauthorizedExecutor,
+    )]
+    pub member: Signer<'info>,
+}
+
+/// The account context for executing a transaction instruction individually
+/// The transaction must be in an ExecuteReady state, and the member must be allowed to execute by the execution policy of the multisig, and the instruction must correlate to the next executed index
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. member account [signer]
+/// 
+#[derive(Accounts)]
+pub struct ExecuteInstruction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = matches!(transaction.status, MsTransactionStatus::ExecuteReady { .. }) @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.transaction_index > multisig.ms_change_index @MsError::DeprecatedTransaction,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            &transaction.executed_index.checked_add(1).unwrap().to_le_bytes(),
+            b"instruction"
+        ], bump = instruction.bump,
+        // it should be the next expected instruction account to be executed
+        constraint = instruction.instruction_index == transaction.executed_index.checked_add(1).unwrap() @MsError::InvalidInstructionAccount,
+    )]
+    pub instruction: Account<'info, MsInstruction>,
+
+    #[account(
+        mut,
+        constraint = multisig.can_execute(member.key()) @MsError::UnauthorizedExecutor,
+    )]
+    pub member: Signer<'info>,
+}
+
+/// The account context for marking a transaction past its deadline as expired
+/// The transaction must be in an Active or ExecuteReady state, and anyone can invoke it
+///
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+///
+#[derive(Accounts)]
+pub struct ExpireTransaction<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = matches!(transaction.status, MsTransactionStatus::Active { .. } | MsTransactionStatus::ExecuteReady { .. }) @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        // a transaction that started sequential execution must be allowed to complete
+        constraint = transaction.executed_index < 1 @MsError::PartialExecution,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+}
+
+/// The account context for the guardian to veto a transaction
+/// The transaction must be in an Active or ExecuteReady state, and the signer must be the multisig guardian.
+/// Internal transactions (authority index 0) can't be vetoed, as they may rotate or limit the guardian.
+///
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. guardian account [signer]
+///
+#[derive(Accounts)]
+pub struct VetoTransaction<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = matches!(transaction.status, MsTransactionStatus::Active { .. } | MsTransactionStatus::ExecuteReady { .. }) @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.authority_index >= 1 @MsError::InvalidAuthorityIndex,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        constraint = multisig.guardian == Some(guardian.key()) @MsError::UnauthorizedMember,
+    )]
+    pub guardian: Signer<'info>,
+}
+
+/// The account context for closing a transaction and reclaiming its rent
+/// The transaction must be Executed, Rejected or Cancelled, or deprecated by a multisig change,
+/// and all of its attached instructions must have been closed first
+///
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. rent collector account (multisig rent collector, or the transaction creator if unset)
+///
+#[derive(Accounts)]
+pub struct CloseTransaction<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.is_closable(multisig.ms_change_index) @MsError::InvalidTransactionState,
+        constraint = transaction.instruction_index == 0 @MsError::InstructionsNotClosed,
+        close = rent_collector
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    /// CHECK: only receives lamports, validated against the multisig settings
+    #[account(
+        mut,
+        constraint = rent_collector.key() == multisig.rent_collector.unwrap_or(transaction.creator) @MsError::InvalidRentCollector,
+    )]
+    pub rent_collector: AccountInfo<'info>,
+}
+
+/// The account context for closing an instruction attached to a closable transaction
+/// Instructions must be closed from the last one to the first one, so that the
+/// transaction can only be closed once none of its instructions are left
+///
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. instruction account
+/// 4. rent collector account (multisig rent collector, or the transaction creator if unset)
+///
+#[derive(Accounts)]
+pub struct CloseInstruction<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.is_closable(multisig.ms_change_index) @MsError::InvalidTransactionState,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            &instruction.instruction_index.to_le_bytes(),
+            b"instruction"
+        ], bump = instruction.bump,
+        // it should be the last instruction still attached to the transaction
+        constraint = instruction.instruction_index == transaction.instruction_index @MsError::InvalidInstructionAccount,
+        close = rent_collector
+    )]
+    pub instruction: Account<'info, MsInstruction>,
+
+    /// CHECK: only receives lamports, validated against the multisig settings
+    #[account(
+        mut,
+        constraint = rent_collector.key() == multisig.rent_collector.unwrap_or(transaction.creator) @MsError::InvalidRentCollector,
+    )]
+    pub rent_collector: AccountInfo<'info>,
+}
+
+/// The account context for executing an internal multisig transaction (which changes the multisig account)
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+#[derive(Accounts)]
+pub struct MsAuth<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveMemberWithGuardian<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        constraint = multisig.guardian == Some(remover.key()) @ MsError::UnauthorizedMember,
+    )]
+    pub remover: Signer<'info>,
+}
+
+/// The account context for finalizing a pending guardian change
+/// 1. multisig account
+#[derive(Accounts)]
+pub struct FinalizeGuardianChange<'info> {
+    #[account(
+        mut,
+        seeds = [b"squad", multisig.create_key.as_ref(), b"multisig"],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+}
+
+/// The account context for vetoing a pending guardian change
+/// 1. multisig account
+/// 2. guardian account [signer]
+#[derive(Accounts)]
+pub struct VetoGuardianChange<'info> {
+    #[account(
+        mut,
+        seeds = [b"squad", multisig.create_key.as_ref(), b"multisig"],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        constraint = multisig.guardian == Some(guardian.key()) @ MsError::UnauthorizedMember,
+    )]
+    pub guardian: Signer<'info>,
+}
+
+/// The account context for the guardian to freeze the multisig
+/// 1. multisig account
+/// 2. guardian account [signer]
+#[derive(Accounts)]
+pub struct FreezeMultisig<'info> {
+    #[account(
+        mut,
+        seeds = [b"squad", multisig.create_key.as_ref(), b"multisig"],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        constraint = multisig.guardian == Some(guardian.key()) @ MsError::UnauthorizedMember,
+    )]
+    pub guardian: Signer<'info>,
+}
+
+/// The account context for reallocating the multisig account (for add member, where the size may need to be adjusted)
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. rent sysvar
+/// 3. system program
+/// 
+/// 
+#[derive(Accounts)]
+pub struct MsAuthRealloc<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for creating an authority policy
+/// 1. authority policy account
+/// 2. multisig account [signer]
+/// 3. rent payer account [signer]
+/// 4. system program
+#[derive(Accounts)]
+#[instruction(authority_index: u32)]
+pub struct CreateAuthorityPolicy<'info> {
+    #[account(
+        init,
+        payer = rent_payer,
+        space = AuthorityPolicy::LEN,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &authority_index.to_le_bytes(),
+            b"policy",
+        ],
+        bump,
+    )]
+    pub authority_policy: Account<'info, AuthorityPolicy>,
+    #[account(
+        mut,
+        seeds = [b"squad", multisig.create_key.as_ref(), b"multisig"],
+        bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Account<'info, Ms>,
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for updating an authority policy
+/// 1. authority policy account
+/// 2. multisig account [signer]
+#[derive(Accounts)]
+pub struct UpdateAuthorityPolicy<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &authority_policy.authority_index.to_le_bytes(),
+            b"policy",
+        ],
+        bump = authority_policy.bump,
+    )]
+    pub authority_policy: Account<'info, AuthorityPolicy>,
+    #[account(
+        mut,
+        seeds = [b"squad", multisig.create_key.as_ref(), b"multisig"],
+        bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Account<'info, Ms>,
+}
+
+/// The account context for removing an authority policy
+/// 1. authority policy account
+/// 2. multisig account [signer]
+#[derive(Accounts)]
+pub struct RemoveAuthorityPolicy<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &authority_policy.authority_index.to_le_bytes(),
+            b"policy",
+        ],
+        bump = authority_policy.bump,
+        close = multisig
+    )]
+    pub authority_policy: Account<'info, AuthorityPolicy>,
+    #[account(
+        mut,
+        seeds = [b"squad", multisig.create_key.as_ref(), b"multisig"],
+        bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Account<'info, Ms>,
+}
+
+#[derive(Accounts)]
+#[instruction(create_key: Pubkey, mint: Pubkey, authority_index: u32 )]
+pub struct CreateSpendingLimit<'info> {
+    #[account(
+        init,
+        payer = rent_payer,
+        space = SpendingLimit::LEN,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            create_key.as_ref(),
+            b"spending_limit",
+        ],
+        bump,
+    )]
+    pub spending_limit: Account<'info, SpendingLimit>,
+    #[account(
+        mut,
+        seeds = [b"squad", multisig.create_key.as_ref(), b"multisig"],
+        bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Account<'info, Ms>,
+    /// This is usually the same as `config_authority`, but can be a different account if needed.
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveSpendingLimit<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &spending_limit.create_key.as_ref(),
+            b"spending_limit",
+        ],
+        bump,
+        close = multisig
+    )]
+    pub spending_limit: Account<'info, SpendingLimit>,
+    #[account(
+        mut,
+        seeds = [b"squad", multisig.create_key.as_ref(), b"multisig"],
+        bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Account<'info, Ms>,
+    #[account(address = solana_program::system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for pausing the spending limit
+/// 1. multisig account [signer]
+/// 2. spending_limit_disabler_authority account [signer]
+#[derive(Accounts)]
 pub struct PauseSpendingLimit<'info> {
     #[account(
         mut,
@@ -536,9 +1210,9 @@ pub struct SpendingLimitUse<'info> {
 
     #[account(
         mut,
-        constraint = spending_limit.member == member.key() @ MsError::UnauthorizedMember
+        constraint = spending_limit.is_member(member.key) @ MsError::UnauthorizedMember
     )]
-    pub member: Signer<'info>, // Primary member as signer
+    pub member: Signer<'info>, // One of the spending limit members as signer
 
     /// CHECK: Could be any account
     #[account(mut)]
@@ -573,3 +1247,321 @@ pub struct SpendingLimitUse<'info> {
     
 }
 
+
+/// The account context for creating a guardian set
+/// 1. guardian set account
+/// 2. multisig account [signer]
+/// 3. rent payer account [signer]
+/// 4. system program
+#[derive(Accounts)]
+pub struct CreateGuardianSet<'info> {
+    #[account(
+        init,
+        payer = rent_payer,
+        space = GuardianSet::LEN,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            b"guardian_set",
+        ],
+        bump,
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+    #[account(
+        mut,
+        seeds = [b"squad", multisig.create_key.as_ref(), b"multisig"],
+        bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Account<'info, Ms>,
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for updating the guardians of a guardian set
+/// 1. guardian set account
+/// 2. multisig account [signer]
+#[derive(Accounts)]
+pub struct UpdateGuardianSet<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            b"guardian_set",
+        ],
+        bump = guardian_set.bump,
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+    #[account(
+        mut,
+        seeds = [b"squad", multisig.create_key.as_ref(), b"multisig"],
+        bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Account<'info, Ms>,
+}
+
+/// The account context for proposing a guardian action
+/// The creator must be a guardian of the set, and approves the action
+///
+/// Expects the following accounts:
+/// 1. guardian set account
+/// 2. guardian action account
+/// 3. creator account [signer]
+/// 4. system program
+///
+/// Expects the following arguments:
+/// 1. instruction: IncomingInstruction (an instruction of this program)
+#[derive(Accounts)]
+#[instruction(instruction: IncomingInstruction)]
+pub struct CreateGuardianAction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            guardian_set.multisig.as_ref(),
+            b"guardian_set",
+        ],
+        bump = guardian_set.bump,
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = GuardianAction::space(&instruction),
+        seeds = [
+            b"squad",
+            guardian_set.key().as_ref(),
+            &guardian_set.action_index.checked_add(1).unwrap().to_le_bytes(),
+            b"guardian_action"
+        ], bump
+    )]
+    pub action: Account<'info, GuardianAction>,
+
+    #[account(
+        mut,
+        constraint = guardian_set.is_guardian(creator.key()).is_some() @MsError::UnauthorizedMember,
+    )]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for approving a guardian action
+/// 1. guardian set account
+/// 2. guardian action account
+/// 3. guardian account [signer]
+#[derive(Accounts)]
+pub struct ApproveGuardianAction<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            guardian_set.multisig.as_ref(),
+            b"guardian_set",
+        ],
+        bump = guardian_set.bump,
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            guardian_set.key().as_ref(),
+            &action.action_index.to_le_bytes(),
+            b"guardian_action"
+        ], bump = action.bump
+    )]
+    pub action: Account<'info, GuardianAction>,
+
+    #[account(
+        constraint = guardian_set.is_guardian(guardian.key()).is_some() @MsError::UnauthorizedMember,
+    )]
+    pub guardian: Signer<'info>,
+}
+
+/// The account context for executing an approved guardian action,
+/// the action account is closed and its rent sent back to its creator
+///
+/// Expects the following accounts:
+/// 1. guardian set account
+/// 2. guardian action account
+/// 3. creator account
+/// 4. guardian account [signer]
+///
+/// Followed by the accounts of the instruction as remaining accounts:
+/// `[squads program, instruction_key_1, instruction_key_2, ...]`
+#[derive(Accounts)]
+pub struct ExecuteGuardianAction<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            guardian_set.multisig.as_ref(),
+            b"guardian_set",
+        ],
+        bump = guardian_set.bump,
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            guardian_set.key().as_ref(),
+            &action.action_index.to_le_bytes(),
+            b"guardian_action"
+        ], bump = action.bump,
+        close = creator
+    )]
+    pub action: Account<'info, GuardianAction>,
+
+    /// CHECK: must match the creator of the action
+    #[account(mut, address = action.creator @MsError::InvalidRentCollector)]
+    pub creator: AccountInfo<'info>,
+
+    #[account(
+        constraint = guardian_set.is_guardian(guardian.key()).is_some() @MsError::UnauthorizedMember,
+    )]
+    pub guardian: Signer<'info>,
+}
+
+/// The account context for the guardian to propose a member recovery
+/// 1. multisig account
+/// 2. member recovery account
+/// 3. guardian account [signer]
+/// 4. rent payer account [signer]
+/// 5. system program
+#[derive(Accounts)]
+#[instruction(replacements: Vec<MemberReplacement>)]
+pub struct ProposeRecovery<'info> {
+    #[account(
+        seeds = [b"squad", multisig.create_key.as_ref(), b"multisig"],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = MemberRecovery::space(replacements.len()),
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            b"recovery",
+        ],
+        bump,
+    )]
+    pub recovery: Account<'info, MemberRecovery>,
+
+    #[account(
+        constraint = multisig.guardian == Some(guardian.key()) @ MsError::UnauthorizedMember,
+    )]
+    pub guardian: Signer<'info>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for a member to veto a member recovery
+/// Any current member can veto, except the members replaced by the recovery, whose keys may be lost or compromised
+/// 1. multisig account
+/// 2. member recovery account
+/// 3. rent payer account, receives the rent of the recovery
+/// 4. member account [signer]
+#[derive(Accounts)]
+pub struct VetoRecovery<'info> {
+    #[account(
+        seeds = [b"squad", multisig.create_key.as_ref(), b"multisig"],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            b"recovery",
+        ],
+        bump = recovery.bump,
+        close = rent_payer
+    )]
+    pub recovery: Account<'info, MemberRecovery>,
+
+    /// CHECK: must match the rent payer of the recovery
+    #[account(mut, address = recovery.rent_payer @ MsError::InvalidRentCollector)]
+    pub rent_payer: AccountInfo<'info>,
+
+    #[account(
+        constraint = multisig.is_member(member.key()).is_some() @ MsError::KeyNotInMultisig,
+        constraint = !recovery.is_replaced(member.key()) @ MsError::UnauthorizedMember,
+    )]
+    pub member: Signer<'info>,
+}
+
+/// The account context for finalizing a member recovery once its delay has passed, anyone can invoke it
+/// 1. multisig account
+/// 2. member recovery account
+/// 3. rent payer account, receives the rent of the recovery
+#[derive(Accounts)]
+pub struct FinalizeRecovery<'info> {
+    #[account(
+        mut,
+        seeds = [b"squad", multisig.create_key.as_ref(), b"multisig"],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            b"recovery",
+        ],
+        bump = recovery.bump,
+        close = rent_payer
+    )]
+    pub recovery: Account<'info, MemberRecovery>,
+
+    /// CHECK: must match the rent payer of the recovery
+    #[account(mut, address = recovery.rent_payer @ MsError::InvalidRentCollector)]
+    pub rent_payer: AccountInfo<'info>,
+}
+
+/// The account context for converting a multisig created with the legacy layout, anyone can invoke it
+/// 1. multisig account
+/// 2. rent payer account [signer], tops up the rent of the reallocated multisig
+/// 3. system program
+#[derive(Accounts)]
+pub struct MigrateMultisig<'info> {
+    /// CHECK: legacy layout, checked and deserialized by the instruction. Only the program can
+    /// create accounts with the multisig discriminator.
+    #[account(mut, owner = crate::ID @ MsError::InvalidInstructionAccount)]
+    pub multisig: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for converting a spending limit created with the legacy layout, anyone can invoke it
+/// 1. spending limit account
+/// 2. rent payer account [signer], tops up the rent of the reallocated spending limit
+/// 3. system program
+#[derive(Accounts)]
+pub struct MigrateSpendingLimit<'info> {
+    /// CHECK: legacy layout, checked and deserialized by the instruction. Only the program can
+    /// create accounts with the spending limit discriminator.
+    #[account(mut, owner = crate::ID @ MsError::InvalidInstructionAccount)]
+    pub spending_limit: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
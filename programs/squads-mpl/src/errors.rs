@@ -34,4 +34,37 @@ pub enum MsError {
     SpendingLimitDisabled, // Error for spending limit disabled
     DuplicateMembers, // Error for duplicate members
     ChangeIndexExceedsTransactionIndex, // Error for change index exceeding transaction index
+    InvalidRentCollector, // Error for rent collector not matching the multisig settings
+    InstructionsNotClosed, // Error for closing a transaction that still has attached instructions
+    TransactionExpired, // Error for transaction past its deadline
+    TransactionNotExpired, // Error for expiring a transaction still within its deadline
+    InvalidProposalLifetime, // Error for proposal lifetime out of the valid range
+    InvalidPermissions, // Error for unknown member permission bits
+    InvalidWeight, // Error for member without vote weight
+    InvalidGuardians, // Error for empty, oversized or duplicated guardian set
+    GuardianActionNotApproved, // Error for executing a guardian action below the guardian threshold
+    GuardianChangeRequiresDelay, // Error for replacing or removing the guardian without a guardian change
+    NoPendingGuardianChange, // Error for finalizing or vetoing without a pending guardian change
+    GuardianChangeNotReady, // Error for finalizing a guardian change before its delay
+    MultisigFrozen, // Error for executing or using a spending limit while the multisig is frozen
+    InvalidFreezeDuration, // Error for freeze duration out of the valid range
+    FreezeCooldown, // Error for freezing again before the end of the freeze cooldown
+    NotFrozen, // Error for unfreezing a multisig that is not frozen
+    InvalidRecovery, // Error for empty or conflicting member replacements, or replacing every voting member
+    RecoveryNotReady, // Error for finalizing a member recovery before its delay
+    InvalidDestinations, // Error for oversized or duplicated spending limit destinations
+    DestinationNotAllowed, // Error for a destination missing from the spending limit destinations
+    InvalidSpendingLimitMembers, // Error for empty, oversized or duplicated spending limit members
+    InvalidPeriod, // Error for custom spending limit period of 0 seconds
+    InvalidExpiration, // Error for a spending limit expiration in the past
+    SpendingLimitExpired, // Error for using a spending limit after its expiration
+    UnauthorizedExecutor, // Error for executing without the execute permission or being an allowed executor
+    InvalidExecutors, // Error for empty, oversized or duplicated executors
+    InvalidBufferSize, // Error for an empty or oversized instruction buffer, or a chunk past its size
+    InstructionBufferIncomplete, // Error for finalizing an instruction buffer before all its chunks are written
+    InvalidBufferHash, // Error for an instruction buffer not matching its hash
+    InvalidInstructionBuffer, // Error for an instruction buffer that is not a serialized instruction
+    AuthorityPolicyUnsatisfiable, // Error for approving with a policy no longer satisfiable by the multisig settings
+    AlreadyMigrated, // Error for migrating an account that already has the current layout
+    GuardianVetoLimitReached, // Error for vetoing more guardian changes in a row than allowed
 }
\ No newline at end of file
@@ -0,0 +1,385 @@
+/*
+    Squads Multisig Program - Events
+    https://github.com/squads-protocol/squads-mpl
+*/
+
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Emitted when a multisig is created
+#[event]
+#[derive(Clone, Debug)]
+pub struct MultisigCreated {
+    pub multisig: Pubkey,
+    pub create_key: Pubkey,
+    pub threshold: u16,
+    pub members: Vec<Member>,
+    pub time_lock: u32,
+}
+
+/// Emitted when a member is added to the multisig
+#[event]
+#[derive(Clone, Debug)]
+pub struct MemberAdded {
+    pub multisig: Pubkey,
+    pub member: Member,
+}
+
+/// Emitted when a member is removed from the multisig, either by an
+/// internal transaction or by the guardian
+#[event]
+#[derive(Clone, Debug)]
+pub struct MemberRemoved {
+    pub multisig: Pubkey,
+    pub member: Pubkey,
+    pub by_guardian: bool,
+}
+
+/// Emitted when the threshold of the multisig changes
+#[event]
+#[derive(Clone, Debug)]
+pub struct ThresholdChanged {
+    pub multisig: Pubkey,
+    pub threshold: u16,
+}
+
+/// Emitted when the authority index tracked by the multisig is bumped
+#[event]
+#[derive(Clone, Debug)]
+pub struct AuthorityAdded {
+    pub multisig: Pubkey,
+    pub authority_index: u16,
+}
+
+/// Emitted when any setting of the multisig changes, with the new settings
+#[event]
+#[derive(Clone, Debug)]
+pub struct MultisigSettingsChanged {
+    pub multisig: Pubkey,
+    pub time_lock: u32,
+    pub spending_limit_enabled: bool,
+    pub guardian: Option<Pubkey>,
+    pub rent_collector: Option<Pubkey>,
+    pub proposal_lifetime: u32,
+    pub time_lock_mode: TimeLockMode,
+    pub execution_policy: ExecutionPolicy,
+}
+
+impl MultisigSettingsChanged {
+    pub fn new(multisig: Pubkey, ms: &Ms) -> Self {
+        MultisigSettingsChanged {
+            multisig,
+            time_lock: ms.time_lock,
+            spending_limit_enabled: ms.spending_limit_enabled,
+            guardian: ms.guardian,
+            rent_collector: ms.rent_collector,
+            proposal_lifetime: ms.proposal_lifetime,
+            time_lock_mode: ms.time_lock_mode.clone(),
+            execution_policy: ms.execution_policy.clone(),
+        }
+    }
+}
+
+/// Emitted when a guardian rotation or removal is proposed
+#[event]
+#[derive(Clone, Debug)]
+pub struct GuardianChangeProposed {
+    pub multisig: Pubkey,
+    pub guardian: Option<Pubkey>,
+    pub ready_at: i64,
+}
+
+/// Emitted when the guardian vetoes a pending guardian change
+#[event]
+#[derive(Clone, Debug)]
+pub struct GuardianChangeVetoed {
+    pub multisig: Pubkey,
+    pub guardian: Pubkey,
+}
+
+/// Emitted when the guardian freezes the multisig
+#[event]
+#[derive(Clone, Debug)]
+pub struct MultisigFrozen {
+    pub multisig: Pubkey,
+    pub guardian: Pubkey,
+    pub frozen_until: i64,
+}
+
+/// Emitted when the members end a freeze early
+#[event]
+#[derive(Clone, Debug)]
+pub struct MultisigUnfrozen {
+    pub multisig: Pubkey,
+}
+
+/// Emitted when the guardian proposes to replace the keys of members
+#[event]
+#[derive(Clone, Debug)]
+pub struct RecoveryProposed {
+    pub multisig: Pubkey,
+    pub guardian: Pubkey,
+    pub replacements: Vec<MemberReplacement>,
+    pub ready_at: i64,
+}
+
+/// Emitted when a member vetoes a pending member recovery
+#[event]
+#[derive(Clone, Debug)]
+pub struct RecoveryVetoed {
+    pub multisig: Pubkey,
+    pub member: Pubkey,
+}
+
+/// Emitted when the keys of members are replaced by a member recovery
+#[event]
+#[derive(Clone, Debug)]
+pub struct RecoveryFinalized {
+    pub multisig: Pubkey,
+    pub replacements: Vec<MemberReplacement>,
+}
+
+/// Emitted when an authority policy is created or updated
+#[event]
+#[derive(Clone, Debug)]
+pub struct AuthorityPolicySet {
+    pub multisig: Pubkey,
+    pub authority_index: u32,
+    pub threshold: u16,
+    pub time_lock: u32,
+}
+
+/// Emitted when an authority policy is removed
+#[event]
+#[derive(Clone, Debug)]
+pub struct AuthorityPolicyRemoved {
+    pub multisig: Pubkey,
+    pub authority_index: u32,
+}
+
+/// Emitted when a transaction is created
+#[event]
+#[derive(Clone, Debug)]
+pub struct TransactionCreated {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub creator: Pubkey,
+    pub transaction_index: u32,
+    pub authority_index: u32,
+}
+
+/// Emitted when an instruction is attached to a transaction
+#[event]
+#[derive(Clone, Debug)]
+pub struct InstructionAdded {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub instruction: Pubkey,
+    pub instruction_index: u8,
+}
+
+/// Emitted when an instruction attached to a draft transaction is replaced
+#[event]
+#[derive(Clone, Debug)]
+pub struct InstructionUpdated {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub instruction: Pubkey,
+    pub instruction_index: u8,
+}
+
+/// Emitted when an instruction of a draft transaction is removed
+#[event]
+#[derive(Clone, Debug)]
+pub struct InstructionRemoved {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub instruction: Pubkey,
+    pub instruction_index: u8,
+}
+
+/// Emitted when a transaction is activated
+#[event]
+#[derive(Clone, Debug)]
+pub struct TransactionActivated {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+}
+
+/// Emitted when a member approves or rejects a transaction,
+/// with the resulting status of the transaction
+#[event]
+#[derive(Clone, Debug)]
+pub struct TransactionVoted {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub member: Pubkey,
+    pub approve: bool,
+    pub status: MsTransactionStatus,
+}
+
+/// Emitted when a member votes to cancel a transaction,
+/// with the resulting status of the transaction
+#[event]
+#[derive(Clone, Debug)]
+pub struct TransactionCancelVoted {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub member: Pubkey,
+    pub status: MsTransactionStatus,
+}
+
+/// Emitted when a transaction, or one of its instructions, is executed
+#[event]
+#[derive(Clone, Debug)]
+pub struct TransactionExecuted {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub executor: Pubkey,
+    pub executed_index: u8,
+    pub status: MsTransactionStatus,
+}
+
+/// Emitted when a transaction is marked as expired
+#[event]
+#[derive(Clone, Debug)]
+pub struct TransactionExpired {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+}
+
+/// Emitted when the guardian vetoes a transaction
+#[event]
+#[derive(Clone, Debug)]
+pub struct TransactionVetoed {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub guardian: Pubkey,
+}
+
+/// Emitted when a transaction is closed
+#[event]
+#[derive(Clone, Debug)]
+pub struct TransactionClosed {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub rent_collector: Pubkey,
+}
+
+/// Emitted when an instruction is closed
+#[event]
+#[derive(Clone, Debug)]
+pub struct InstructionClosed {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub instruction: Pubkey,
+    pub rent_collector: Pubkey,
+}
+
+/// Emitted when a spending limit is created
+#[event]
+#[derive(Clone, Debug)]
+pub struct SpendingLimitCreated {
+    pub multisig: Pubkey,
+    pub spending_limit: Pubkey,
+    pub create_key: Pubkey,
+    pub mint: Pubkey,
+    pub authority_index: u32,
+    pub amount: u64,
+    pub members: Vec<Pubkey>,
+    pub destinations: Vec<Pubkey>,
+    pub period: Period,
+    pub mode: SpendingLimitMode,
+    pub expiration: Option<i64>,
+}
+
+/// Emitted when a spending limit is removed
+#[event]
+#[derive(Clone, Debug)]
+pub struct SpendingLimitRemoved {
+    pub multisig: Pubkey,
+    pub spending_limit: Pubkey,
+}
+
+/// Emitted when the guardian pauses the spending limits of the multisig
+#[event]
+#[derive(Clone, Debug)]
+pub struct SpendingLimitPaused {
+    pub multisig: Pubkey,
+    pub guardian: Pubkey,
+}
+
+/// Emitted when a spending limit is used
+#[event]
+#[derive(Clone, Debug)]
+pub struct SpendingLimitUsed {
+    pub multisig: Pubkey,
+    pub spending_limit: Pubkey,
+    pub member: Pubkey,
+    pub destination: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub remaining_amount: u64,
+    pub total_spent: u64,
+    pub use_count: u64,
+}
+
+/// Emitted when the guardians of a guardian set are created or replaced
+#[event]
+#[derive(Clone, Debug)]
+pub struct GuardianSetChanged {
+    pub multisig: Pubkey,
+    pub guardian_set: Pubkey,
+    pub guardians: Vec<Pubkey>,
+    pub threshold: u16,
+}
+
+/// Emitted when a guardian proposes a guardian action
+#[event]
+#[derive(Clone, Debug)]
+pub struct GuardianActionCreated {
+    pub multisig: Pubkey,
+    pub guardian_set: Pubkey,
+    pub action: Pubkey,
+    pub action_index: u32,
+    pub creator: Pubkey,
+}
+
+/// Emitted when a guardian approves a guardian action,
+/// with the number of approvals of current guardians
+#[event]
+#[derive(Clone, Debug)]
+pub struct GuardianActionApproved {
+    pub multisig: Pubkey,
+    pub guardian_set: Pubkey,
+    pub action: Pubkey,
+    pub guardian: Pubkey,
+    pub approvals: u16,
+}
+
+/// Emitted when a guardian action is executed
+#[event]
+#[derive(Clone, Debug)]
+pub struct GuardianActionExecuted {
+    pub multisig: Pubkey,
+    pub guardian_set: Pubkey,
+    pub action: Pubkey,
+    pub executor: Pubkey,
+}
+
+/// Emitted when a legacy multisig is converted to the current layout
+#[event]
+#[derive(Clone, Debug)]
+pub struct MultisigMigrated {
+    pub multisig: Pubkey,
+    pub members: Vec<Member>,
+}
+
+/// Emitted when a legacy spending limit is converted to the current layout
+#[event]
+#[derive(Clone, Debug)]
+pub struct SpendingLimitMigrated {
+    pub multisig: Pubkey,
+    pub spending_limit: Pubkey,
+    pub members: Vec<Pubkey>,
+}
@@ -4,6 +4,7 @@
 */
 
 #![allow(clippy::result_large_err)]
+#![allow(clippy::too_many_arguments)]
 #![deny(arithmetic_overflow)]
 #![deny(unused_must_use)]
 // #![deny(clippy::arithmetic_side_effects)]
@@ -11,6 +12,7 @@
 
 use anchor_lang::{
     prelude::*,
+    Discriminator,
     solana_program::{
         instruction::Instruction,
         program::invoke_signed
@@ -22,10 +24,12 @@ use hex::FromHex;
 use state::*;
 use errors::*;
 use account::*;
+use events::*;
 
 pub mod state;
 pub mod account;
 pub mod errors;
+pub mod events;
 
 #[cfg(not(feature = "no-entrypoint"))]
 use {default_env::default_env, solana_security_txt::security_txt};
@@ -80,6 +84,14 @@ pub mod squads_mpl {
         // Check the invariants after initialization
         multisig.check_invariants()?;
 
+        emit!(MultisigCreated {
+            multisig: multisig.key(),
+            create_key,
+            threshold,
+            members: multisig.keys.clone(),
+            time_lock,
+        });
+
         Ok(())
     }
 
@@ -95,8 +107,11 @@ pub mod squads_mpl {
             return err!(MsError::InvalidInstructionAccount);
         }
         let curr_data_size = multisig_account_info.data.borrow().len();
-        let spots_left =
-            ((curr_data_size - Ms::SIZE_WITHOUT_MEMBERS) /  Member::INIT_SPACE) - ctx.accounts.multisig.keys.len();
+        let spots_left = curr_data_size
+            .checked_sub(Ms::SIZE_WITHOUT_MEMBERS)
+            .map(|members_size| members_size / Member::INIT_SPACE)
+            .and_then(|spots| spots.checked_sub(ctx.accounts.multisig.keys.len()))
+            .ok_or(MsError::InvalidInstructionAccount)?;
 
         // if not enough, add (10 * Member::INIT_SPACE) to size - bump it up by 10 accounts
         if spots_left < 1 {
@@ -113,7 +128,7 @@ pub mod squads_mpl {
             }
         }
         ctx.accounts.multisig.reload()?;
-        ctx.accounts.multisig.add_member(new_member)?;
+        ctx.accounts.multisig.add_member(new_member.clone())?;
         let new_index = ctx.accounts.multisig.transaction_index;
         // set the change index, which will deprecate any active transactions
         ctx.accounts.multisig.set_change_index(new_index)?;
@@ -121,6 +136,11 @@ pub mod squads_mpl {
         // Check the invariants after adding a member
         ctx.accounts.multisig.check_invariants()?;
 
+        emit!(MemberAdded {
+            multisig: ctx.accounts.multisig.key(),
+            member: new_member,
+        });
+
         Ok(())
     }
 
@@ -139,14 +159,21 @@ pub mod squads_mpl {
         // Check the invariants after removing a member
         ctx.accounts.multisig.check_invariants()?;
 
+        emit!(MemberRemoved {
+            multisig: ctx.accounts.multisig.key(),
+            member: old_member,
+            by_guardian: false,
+        });
+
         Ok(())
     }
 
     /// The instruction to change the threshold of the multisig
     pub fn change_threshold(ctx: Context<MsAuth>, new_threshold: u16) -> Result<()> {
-        // if the new threshold value is valid
-        if ctx.accounts.multisig.keys.len() < usize::from(new_threshold) {
-            let new_threshold: u16 = ctx.accounts.multisig.keys.len().try_into().unwrap();
+        // if the new threshold value is valid, it can't exceed the total weight of the members allowed to vote
+        let total_voting_weight = ctx.accounts.multisig.total_voting_weight();
+        if total_voting_weight < u32::from(new_threshold) {
+            let new_threshold: u16 = total_voting_weight.try_into().unwrap();
             ctx.accounts.multisig.change_threshold(new_threshold)?;
         } else if new_threshold < 1 {
             return err!(MsError::InvalidThreshold);
@@ -160,6 +187,11 @@ pub mod squads_mpl {
         // Check the invariants after changing the threshold
         ctx.accounts.multisig.check_invariants()?;
 
+        emit!(ThresholdChanged {
+            multisig: ctx.accounts.multisig.key(),
+            threshold: ctx.accounts.multisig.threshold,
+        });
+
         Ok(())
     }
 
@@ -169,7 +201,65 @@ pub mod squads_mpl {
     /// This has no functional impact on the multisig or its functionality, but
     /// can be used to track commonly used authorities (ie, vault 1, vault 2, etc.)
     pub fn add_authority(ctx: Context<MsAuth>) -> Result<()> {
-        ctx.accounts.multisig.add_authority()
+        ctx.accounts.multisig.add_authority()?;
+
+        emit!(AuthorityAdded {
+            multisig: ctx.accounts.multisig.key(),
+            authority_index: ctx.accounts.multisig.authority_index,
+        });
+
+        Ok(())
+    }
+
+    /// The instruction to set the account receiving the rent of closed
+    /// transactions and instructions. If set to None, the rent goes back
+    /// to the creator of each transaction.
+    pub fn set_rent_collector(ctx: Context<MsAuth>, rent_collector: Option<Pubkey>) -> Result<()> {
+        ctx.accounts.multisig.set_rent_collector(rent_collector)?;
+
+        emit!(MultisigSettingsChanged::new(ctx.accounts.multisig.key(), &ctx.accounts.multisig));
+
+        Ok(())
+    }
+
+    /// The instruction to change the lifetime of the multisig transactions.
+    /// Active and ExecuteReady transactions activated more than the lifetime ago can no
+    /// longer be approved or executed. A lifetime of 0 disables the expiry.
+    pub fn set_proposal_lifetime(ctx: Context<MsAuth>, proposal_lifetime: u32) -> Result<()> {
+        ctx.accounts.multisig.set_proposal_lifetime(proposal_lifetime)?;
+
+        // Check the invariants after changing the proposal lifetime
+        ctx.accounts.multisig.check_invariants()?;
+
+        emit!(MultisigSettingsChanged::new(ctx.accounts.multisig.key(), &ctx.accounts.multisig));
+
+        Ok(())
+    }
+
+    /// The instruction to choose when the time lock applies. In the voting mode
+    /// (the default) members wait the time lock after activation before approving,
+    /// in the execution mode they wait it after approval before executing, during
+    /// which the transaction can still be cancelled.
+    pub fn set_time_lock_mode(ctx: Context<MsAuth>, time_lock_mode: TimeLockMode) -> Result<()> {
+        ctx.accounts.multisig.set_time_lock_mode(time_lock_mode)?;
+
+        emit!(MultisigSettingsChanged::new(ctx.accounts.multisig.key(), &ctx.accounts.multisig));
+
+        Ok(())
+    }
+
+    /// The instruction to let designated executors, or anyone, execute the
+    /// ExecuteReady transactions, ie. keeper bots that are not members.
+    /// Members with the execute permission can always execute them.
+    pub fn set_execution_policy(ctx: Context<MsAuth>, execution_policy: ExecutionPolicy) -> Result<()> {
+        ctx.accounts.multisig.set_execution_policy(execution_policy)?;
+
+        // Check the invariants after changing the execution policy
+        ctx.accounts.multisig.check_invariants()?;
+
+        emit!(MultisigSettingsChanged::new(ctx.accounts.multisig.key(), &ctx.accounts.multisig));
+
+        Ok(())
     }
 
     /// Instruction to create a multisig transaction.
@@ -203,13 +293,30 @@ pub mod squads_mpl {
             ctx.bumps.transaction,
             authority_index,
             authority_bump,
-        )
+        )?;
+
+        emit!(TransactionCreated {
+            multisig: ms.key(),
+            transaction: ctx.accounts.transaction.key(),
+            creator: ctx.accounts.creator.key(),
+            transaction_index: ms.transaction_index,
+            authority_index,
+        });
+
+        Ok(())
     }
 
     /// Instruction to set the state of a transaction "active".
     /// "active" transactions can then be signed off by multisig members
     pub fn activate_transaction(ctx: Context<ActivateTransaction>) -> Result<()> {
-        ctx.accounts.transaction.activate()
+        ctx.accounts.transaction.activate()?;
+
+        emit!(TransactionActivated {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+        });
+
+        Ok(())
     }
 
     /// Instruction to attach an instruction to a transaction.
@@ -230,12 +337,189 @@ pub mod squads_mpl {
             tx.instruction_index,
             incoming_instruction,
             ctx.bumps.instruction,
-        )
+        )?;
+
+        emit!(InstructionAdded {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: tx.key(),
+            instruction: ctx.accounts.instruction.key(),
+            instruction_index: tx.instruction_index,
+        });
+
+        Ok(())
+    }
+
+    /// Instruction to replace an instruction attached to a draft transaction,
+    /// ie. to fix a mistake without abandoning the transaction.
+    pub fn update_instruction(
+        ctx: Context<UpdateInstruction>,
+        incoming_instruction: IncomingInstruction,
+    ) -> Result<()> {
+        // make sure internal transactions have a matching program id for attached instructions
+        if ctx.accounts.transaction.authority_index == 0 && &incoming_instruction.program_id != ctx.program_id {
+            return err!(MsError::InvalidAuthorityIndex);
+        }
+        let instruction_index = ctx.accounts.instruction.instruction_index;
+        let bump = ctx.accounts.instruction.bump;
+        ctx.accounts.instruction.init(instruction_index, incoming_instruction, bump)?;
+
+        emit!(InstructionUpdated {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            instruction: ctx.accounts.instruction.key(),
+            instruction_index,
+        });
+
+        Ok(())
+    }
+
+    /// Instruction to remove an instruction attached to a draft transaction and reclaim
+    /// its rent. Every following instruction is moved to the previous index, so that the
+    /// instruction indexes stay contiguous, and the last instruction account is closed.
+    /// The instruction accounts from the removed one to the one before the last are
+    /// expected in the remaining accounts.
+    pub fn remove_instruction<'info>(
+        ctx: Context<'_, '_, '_, 'info, RemoveInstruction<'info>>,
+        instruction_index: u8,
+    ) -> Result<()> {
+        let transaction_key = ctx.accounts.transaction.key();
+        let last_index = ctx.accounts.transaction.instruction_index;
+        if instruction_index < 1 || instruction_index > last_index {
+            return err!(MsError::InvalidInstructionAccount);
+        }
+        if ctx.remaining_accounts.len() != usize::from(last_index - instruction_index) {
+            return err!(MsError::InvalidNumberOfAccounts);
+        }
+
+        let mut instructions = Vec::with_capacity(ctx.remaining_accounts.len());
+        for (index, ix_account_info) in (instruction_index..last_index).zip(ctx.remaining_accounts) {
+            let ix: MsInstruction = MsInstruction::try_deserialize(&mut &ix_account_info.try_borrow_data()?[..])?;
+            // check the instruction account key maches the pda of its index
+            let ix_pda = Pubkey::create_program_address(
+                &[
+                    b"squad",
+                    transaction_key.as_ref(),
+                    &index.to_le_bytes(),
+                    b"instruction",
+                    &[ix.bump],
+                ],
+                ctx.program_id,
+            ).map_err(|_| error!(MsError::InvalidInstructionAccount))?;
+            if &ix_pda != ix_account_info.key || ix_account_info.owner != ctx.program_id || !ix_account_info.is_writable {
+                return err!(MsError::InvalidInstructionAccount);
+            }
+            instructions.push(ix);
+        }
+
+        // move the instructions from the end, the rent of a grown account is taken from the
+        // last instruction account, which always holds at least the rent of the next one to move
+        let last_account_info = ctx.accounts.instruction.to_account_info();
+        let mut moved = IncomingInstruction::from(ctx.accounts.instruction.clone().into_inner());
+        let rent = Rent::get()?;
+        for (ix_account_info, mut ix) in ctx.remaining_accounts.iter().zip(instructions).rev() {
+            let next = IncomingInstruction::from(ix.clone());
+            let needed_len = 8 + moved.get_max_size();
+            let rent_exempt_lamports = rent.minimum_balance(needed_len).max(1);
+            let lamports = ix_account_info.lamports();
+            let (from, to, amount) = if rent_exempt_lamports > lamports {
+                (&last_account_info, ix_account_info, rent_exempt_lamports - lamports)
+            } else {
+                (ix_account_info, &last_account_info, lamports - rent_exempt_lamports)
+            };
+            let from_lamports = from.lamports().checked_sub(amount).ok_or(ProgramError::InsufficientFunds)?;
+            let to_lamports = to.lamports().checked_add(amount).unwrap();
+            **from.try_borrow_mut_lamports()? = from_lamports;
+            **to.try_borrow_mut_lamports()? = to_lamports;
+            ix_account_info.realloc(needed_len, false)?;
+
+            ix.init(ix.instruction_index, moved, ix.bump)?;
+            ix.try_serialize(&mut &mut ix_account_info.try_borrow_mut_data()?[..])?;
+            moved = next;
+        }
+
+        let tx = &mut ctx.accounts.transaction;
+        tx.instruction_index = tx.instruction_index.checked_sub(1).unwrap();
+
+        emit!(InstructionRemoved {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: tx.key(),
+            instruction: ctx.remaining_accounts.first().map_or(last_account_info.key(), |info| info.key()),
+            instruction_index,
+        });
+
+        Ok(())
+    }
+
+    /// Instruction to create the buffer of an instruction too large to be attached with
+    /// add_instruction, with the sha256 hash and size of the serialized IncomingInstruction.
+    /// The first chunk is written right away, the others with write_instruction_buffer.
+    pub fn create_instruction_buffer(
+        ctx: Context<CreateInstructionBuffer>,
+        final_hash: [u8; 32],
+        final_size: u32,
+        chunk: Vec<u8>,
+    ) -> Result<()> {
+        let buffer = &mut ctx.accounts.instruction_buffer;
+        buffer.init(
+            ctx.accounts.multisig.key(),
+            ctx.accounts.transaction.key(),
+            ctx.accounts.creator.key(),
+            final_hash,
+            final_size,
+            ctx.bumps.instruction_buffer,
+        )?;
+        buffer.write(&chunk)
+    }
+
+    /// Instruction to append a chunk to the instruction buffer of a draft transaction.
+    pub fn write_instruction_buffer(ctx: Context<WriteInstructionBuffer>, chunk: Vec<u8>) -> Result<()> {
+        ctx.accounts.instruction_buffer.write(&chunk)
+    }
+
+    /// Instruction to attach the instruction of a complete buffer to a draft transaction,
+    /// once validated against its hash. The buffer is closed.
+    pub fn add_instruction_from_buffer(ctx: Context<AddInstructionFromBuffer>) -> Result<()> {
+        let incoming_instruction = ctx.accounts.instruction_buffer.incoming_instruction()?;
+        let tx = &mut ctx.accounts.transaction;
+        // make sure internal transactions have a matching program id for attached instructions
+        if tx.authority_index == 0 && &incoming_instruction.program_id != ctx.program_id {
+            return err!(MsError::InvalidAuthorityIndex);
+        }
+        tx.instruction_index = tx.instruction_index.checked_add(1).unwrap();
+        ctx.accounts.instruction.init(
+            tx.instruction_index,
+            incoming_instruction,
+            ctx.bumps.instruction,
+        )?;
+
+        emit!(InstructionAdded {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: tx.key(),
+            instruction: ctx.accounts.instruction.key(),
+            instruction_index: tx.instruction_index,
+        });
+
+        Ok(())
+    }
+
+    /// Instruction to close an instruction buffer that won't be attached and reclaim its rent,
+    /// including once its transaction is closed.
+    pub fn close_instruction_buffer(_ctx: Context<CloseInstructionBuffer>) -> Result<()> {
+        Ok(())
     }
 
     /// Instruction to approve a transaction on behalf of a member.
     /// The transaction must have an "active" status
     pub fn approve_transaction(ctx: Context<VoteTransaction>) -> Result<()> {
+        // the authority policy overrides the multisig threshold and time lock if it exists
+        let policy = AuthorityPolicy::try_load(&ctx.accounts.authority_policy)?;
+        let (threshold, time_lock) = ctx.accounts.multisig.get_policy(policy.as_ref());
+
+        // the members, weights or proposal lifetime may have changed since the policy was set
+        if let Some(policy) = policy.as_ref() {
+            policy.check_satisfiable(&ctx.accounts.multisig)?;
+        }
+
         // if they have previously voted to reject, remove that item (change vote check)
         if let Some(ind) = ctx
             .accounts
@@ -255,25 +539,49 @@ pub mod squads_mpl {
             ctx.accounts.transaction.sign(ctx.accounts.member.key())?;
         }
 
-        // verifying if the time lock duration has been satisfied
-        match ctx.accounts.transaction.status {
-            MsTransactionStatus::Active { timestamp } => {
-                require!(Clock::get()?.unix_timestamp - timestamp >= i64::from(ctx.accounts.multisig.time_lock), MsError::TimeLockNotSatisfied);
+        // verifying if the time lock duration has been satisfied, in the execution
+        // time lock mode it is instead enforced once the transaction is approved
+        let execution_delay = match ctx.accounts.multisig.time_lock_mode {
+            TimeLockMode::Voting => {
+                match ctx.accounts.transaction.status {
+                    MsTransactionStatus::Active { timestamp } => {
+                        require!(Clock::get()?.unix_timestamp - timestamp >= i64::from(time_lock), MsError::TimeLockNotSatisfied);
+                    }
+                    _ => return err!(MsError::InvalidTransactionState),
+                };
+                0
             }
-            _ => return err!(MsError::InvalidTransactionState),
+            TimeLockMode::Execution => time_lock,
         };
 
-        // if current number of signers reaches threshold, mark the transaction as execute ready
-        if ctx.accounts.transaction.approved.len() >= usize::from(ctx.accounts.multisig.threshold) {
-            ctx.accounts.transaction.ready_to_execute()?;
+        // verifying the transaction has not passed its deadline
+        if ctx.accounts.transaction.is_expired(ctx.accounts.multisig.proposal_lifetime, Clock::get()?.unix_timestamp) {
+            return err!(MsError::TransactionExpired);
+        }
+
+        // if current weight of signers reaches threshold, mark the transaction as execute ready
+        if ctx.accounts.multisig.vote_weight(&ctx.accounts.transaction.approved) >= u32::from(threshold) {
+            ctx.accounts.transaction.ready_to_execute(execution_delay)?;
         }
 
+        emit!(TransactionVoted {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            member: ctx.accounts.member.key(),
+            approve: true,
+            status: ctx.accounts.transaction.status.clone(),
+        });
+
         Ok(())
     }
 
     /// Instruction to reject a transaction.
     /// The transaction must have an "active" status.
     pub fn reject_transaction(ctx: Context<VoteTransaction>) -> Result<()> {
+        // the authority policy overrides the multisig threshold if it exists
+        let policy = AuthorityPolicy::try_load(&ctx.accounts.authority_policy)?;
+        let (threshold, _) = ctx.accounts.multisig.get_policy(policy.as_ref());
+
         // if they have previously voted to approve, remove that item (change vote check)
         if let Some(ind) = ctx
             .accounts
@@ -293,18 +601,26 @@ pub mod squads_mpl {
             ctx.accounts.transaction.reject(ctx.accounts.member.key())?;
         }
 
-        // ie total members 7, threshold 3, cutoff = 4
-        // ie total member 8, threshold 6, cutoff = 2
+        // only the weight of members allowed to vote is counted
+        // ie total voting weight 7, threshold 3, cutoff = 4
+        // ie total voting weight 8, threshold 6, cutoff = 2
         let cutoff = ctx
             .accounts
             .multisig
-            .keys
-            .len()
-            .checked_sub(usize::from(ctx.accounts.multisig.threshold))
-            .unwrap();
-        if ctx.accounts.transaction.rejected.len() > cutoff {
+            .total_voting_weight()
+            .saturating_sub(u32::from(threshold));
+        if ctx.accounts.multisig.vote_weight(&ctx.accounts.transaction.rejected) > cutoff {
             ctx.accounts.transaction.set_rejected()?;
         }
+
+        emit!(TransactionVoted {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            member: ctx.accounts.member.key(),
+            approve: false,
+            status: ctx.accounts.transaction.status.clone(),
+        });
+
         Ok(())
     }
 
@@ -314,6 +630,10 @@ pub mod squads_mpl {
     /// cancellations reaches the threshold. A cancelled
     /// transaction will no longer be able to be executed.
     pub fn cancel_transaction(ctx: Context<CancelTransaction>) -> Result<()> {
+        // the authority policy overrides the multisig threshold if it exists
+        let policy = AuthorityPolicy::try_load(&ctx.accounts.authority_policy)?;
+        let (threshold, _) = ctx.accounts.multisig.get_policy(policy.as_ref());
+
         // check if they haven't cancelled yet
         if ctx
             .accounts
@@ -324,11 +644,19 @@ pub mod squads_mpl {
             ctx.accounts.transaction.cancel(ctx.accounts.member.key())?
         }
 
-        // if the current number of signers reaches threshold, mark the transaction as "cancelled"
-        if ctx.accounts.transaction.cancelled.len() >= usize::from(ctx.accounts.multisig.threshold)
+        // if the current weight of signers reaches threshold, mark the transaction as "cancelled"
+        if ctx.accounts.multisig.vote_weight(&ctx.accounts.transaction.cancelled) >= u32::from(threshold)
         {
             ctx.accounts.transaction.set_cancelled()?;
         }
+
+        emit!(TransactionCancelVoted {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            member: ctx.accounts.member.key(),
+            status: ctx.accounts.transaction.status.clone(),
+        });
+
         Ok(())
     }
 
@@ -342,10 +670,35 @@ pub mod squads_mpl {
         ctx: Context<'_, '_, '_, 'info, ExecuteTransaction<'info>>,
         account_list: Vec<u8>,
     ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        // check that the transaction has not passed its deadline
+        if ctx.accounts.transaction.is_expired(ctx.accounts.multisig.proposal_lifetime, now) {
+            return err!(MsError::TransactionExpired);
+        }
+
+        // check that the execution delay of the time lock has passed
+        if ctx.accounts.transaction.is_execution_delayed(now) {
+            return err!(MsError::TimeLockNotSatisfied);
+        }
+
+        // only internal transactions can be executed while the multisig is frozen,
+        // so that the members can unfreeze it or change the guardian
+        if ctx.accounts.transaction.authority_index > 0 && ctx.accounts.multisig.is_frozen(now) {
+            return err!(MsError::MultisigFrozen);
+        }
+
         // check that we are provided at least one instruction
         if ctx.accounts.transaction.instruction_index < 1 {
             // if no instructions were found, mark it as executed and move on
             ctx.accounts.transaction.set_executed()?;
+            emit!(TransactionExecuted {
+                multisig: ctx.accounts.multisig.key(),
+                transaction: ctx.accounts.transaction.key(),
+                executor: ctx.accounts.member.key(),
+                executed_index: 0,
+                status: ctx.accounts.transaction.status.clone(),
+            });
             return Ok(());
         }
 
@@ -421,11 +774,11 @@ pub mod squads_mpl {
             let mut ix_account_infos: Vec<AccountInfo> = vec![ix_program_info.clone()];
 
             // loop through the provided remaining accounts
-            for account_index in 0..ix_keys.len() {
+            for ix_key in ix_keys.iter() {
                 let ix_account_info = next_account_info(ix_iter)?.clone();
 
                 // check that the ix account keys match the submitted account keys
-                if *ix_account_info.key != ix_keys[account_index].pubkey {
+                if *ix_account_info.key != ix_key.pubkey {
                     return err!(MsError::InvalidInstructionAccount);
                 }
 
@@ -462,6 +815,15 @@ pub mod squads_mpl {
         ctx.accounts.transaction.set_executed()?;
         // reload any multisig changes
         ctx.accounts.multisig.reload()?;
+
+        emit!(TransactionExecuted {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            executor: ctx.accounts.member.key(),
+            executed_index: ctx.accounts.transaction.executed_index,
+            status: ctx.accounts.transaction.status.clone(),
+        });
+
         Ok(())
     }
 
@@ -490,6 +852,24 @@ pub mod squads_mpl {
             return err!(MsError::InvalidAuthorityIndex);
         }
 
+        let now = Clock::get()?.unix_timestamp;
+
+        // check that the transaction has not passed its deadline, once sequential
+        // execution has started it must be allowed to complete
+        if tx.executed_index < 1 && tx.is_expired(ctx.accounts.multisig.proposal_lifetime, now) {
+            return err!(MsError::TransactionExpired);
+        }
+
+        // check that the execution delay of the time lock has passed
+        if tx.is_execution_delayed(now) {
+            return err!(MsError::TimeLockNotSatisfied);
+        }
+
+        // the multisig authorities can't sign while the multisig is frozen
+        if ctx.accounts.multisig.is_frozen(now) {
+            return err!(MsError::MultisigFrozen);
+        }
+
         // setup the authority seeds
         let authority_seeds = [
             b"squad",
@@ -547,6 +927,76 @@ pub mod squads_mpl {
         {
             ctx.accounts.transaction.set_executed()?;
         }
+
+        emit!(TransactionExecuted {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            executor: ctx.accounts.member.key(),
+            executed_index: ctx.accounts.transaction.executed_index,
+            status: ctx.accounts.transaction.status.clone(),
+        });
+
+        Ok(())
+    }
+
+    /// Instruction to mark a transaction past its deadline as "expired".
+    /// The transaction must be "active" or "executeReady", and can be
+    /// expired by anyone once the multisig proposal lifetime has elapsed.
+    pub fn expire_transaction(ctx: Context<ExpireTransaction>) -> Result<()> {
+        if !ctx.accounts.transaction.is_expired(ctx.accounts.multisig.proposal_lifetime, Clock::get()?.unix_timestamp) {
+            return err!(MsError::TransactionNotExpired);
+        }
+        ctx.accounts.transaction.set_expired()?;
+
+        emit!(TransactionExpired {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+        });
+
+        Ok(())
+    }
+
+    /// Instruction for the guardian to veto an active or approved transaction of a vault
+    /// (authority index 1 and above), setting it to Cancelled without a vote of the members
+    pub fn veto_transaction(ctx: Context<VetoTransaction>) -> Result<()> {
+        ctx.accounts.transaction.set_cancelled()?;
+
+        emit!(TransactionVetoed {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            guardian: ctx.accounts.guardian.key(),
+        });
+        Ok(())
+    }
+
+    /// Instruction to close a transaction and reclaim its rent.
+    /// The transaction must be "executed", "rejected", "cancelled" or "expired", or be
+    /// deprecated by a change of the multisig. All attached instructions
+    /// must be closed beforehand with close_instruction.
+    pub fn close_transaction(ctx: Context<CloseTransaction>) -> Result<()> {
+        emit!(TransactionClosed {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            rent_collector: ctx.accounts.rent_collector.key(),
+        });
+
+        Ok(())
+    }
+
+    /// Instruction to close the last instruction attached to a closable transaction
+    /// and reclaim its rent. Instructions are closed from the last one to the first
+    /// one, decrementing the transaction instruction index each time.
+    pub fn close_instruction(ctx: Context<CloseInstruction>) -> Result<()> {
+        let tx = &mut ctx.accounts.transaction;
+        tx.instruction_index = tx.instruction_index.checked_sub(1).unwrap();
+
+        emit!(InstructionClosed {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: tx.key(),
+            instruction: ctx.accounts.instruction.key(),
+            rent_collector: ctx.accounts.rent_collector.key(),
+        });
+
         Ok(())
     }
 
@@ -565,12 +1015,9 @@ pub mod squads_mpl {
         // Update the spending limit enabled flag
         multisig.spending_limit_enabled = spending_limit_enabled;
 
-        // Update the guardian if provided
-        if let Some(new_guardian) = guardian {
-            multisig.guardian = Some(new_guardian);
-        } else {
-            multisig.guardian = None;
-        }
+        // Set the guardian if none is set yet, rotating or removing
+        // the guardian must go through propose_guardian_change
+        multisig.set_guardian(guardian)?;
 
         // Mark the change by updating the change index to deprecate any active transactions
         let new_index = multisig.transaction_index;
@@ -579,6 +1026,8 @@ pub mod squads_mpl {
         // Check the invariants after updating multisig settings
         multisig.check_invariants()?;
 
+        emit!(MultisigSettingsChanged::new(multisig.key(), multisig));
+
         Ok(())
     }
 
@@ -604,14 +1053,86 @@ pub mod squads_mpl {
         // Check the invariants after removing a member
         ctx.accounts.multisig.check_invariants()?;
 
+        emit!(MemberRemoved {
+            multisig: ctx.accounts.multisig.key(),
+            member: old_member,
+            by_guardian: true,
+        });
+
         Ok(())
     }
 
-    pub fn add_spending_limit(ctx: Context<CreateSpendingLimit>, create_key: Pubkey, mint: Pubkey, authority_index: u32, amount: u64, member: Pubkey, period: Period) -> Result<()> {
+    /// Instruction to create a policy overriding the multisig threshold and time lock
+    /// for the transactions of a given authority. Authority 0 (internal transactions)
+    /// always uses the multisig threshold and time lock.
+    pub fn add_authority_policy(ctx: Context<CreateAuthorityPolicy>, authority_index: u32, threshold: u16, time_lock: u32) -> Result<()> {
+        if authority_index < 1 {
+            return err!(MsError::InvalidAuthorityIndex);
+        }
+
+        ctx.accounts.authority_policy.init(
+            ctx.accounts.multisig.key(),
+            authority_index,
+            threshold,
+            time_lock,
+            ctx.bumps.authority_policy,
+        )?;
+        ctx.accounts.authority_policy.check_invariants(&ctx.accounts.multisig)?;
+
+        emit!(AuthorityPolicySet {
+            multisig: ctx.accounts.multisig.key(),
+            authority_index: ctx.accounts.authority_policy.authority_index,
+            threshold,
+            time_lock,
+        });
+
+        // update the change index to deprecate any active transactions
+        let new_index = ctx.accounts.multisig.transaction_index;
+        ctx.accounts.multisig.set_change_index(new_index)
+    }
+
+    /// Instruction to update the threshold and time lock of an authority policy
+    pub fn update_authority_policy(ctx: Context<UpdateAuthorityPolicy>, threshold: u16, time_lock: u32) -> Result<()> {
+        ctx.accounts.authority_policy.threshold = threshold;
+        ctx.accounts.authority_policy.time_lock = time_lock;
+        ctx.accounts.authority_policy.check_invariants(&ctx.accounts.multisig)?;
+
+        emit!(AuthorityPolicySet {
+            multisig: ctx.accounts.multisig.key(),
+            authority_index: ctx.accounts.authority_policy.authority_index,
+            threshold,
+            time_lock,
+        });
+
+        // update the change index to deprecate any active transactions
+        let new_index = ctx.accounts.multisig.transaction_index;
+        ctx.accounts.multisig.set_change_index(new_index)
+    }
+
+    /// Instruction to remove an authority policy, the authority
+    /// then falls back to the multisig threshold and time lock
+    pub fn remove_authority_policy(ctx: Context<RemoveAuthorityPolicy>) -> Result<()> {
+        emit!(AuthorityPolicyRemoved {
+            multisig: ctx.accounts.multisig.key(),
+            authority_index: ctx.accounts.authority_policy.authority_index,
+        });
+
+        let new_index = ctx.accounts.multisig.transaction_index;
+        ctx.accounts.multisig.set_change_index(new_index)
+    }
+
+    pub fn add_spending_limit(ctx: Context<CreateSpendingLimit>, create_key: Pubkey, mint: Pubkey, authority_index: u32, amount: u64, members: Vec<Pubkey>, destinations: Vec<Pubkey>, period: Period, mode: SpendingLimitMode, expiration: Option<i64>) -> Result<()> {
         // Ensure amount is strictly positive
         if amount == 0 {
             return err!(MsError::InvalidAmount);
         }
+
+        // Ensure the expiration is in the future
+        if let Some(expiration) = expiration {
+            if expiration <= Clock::get()?.unix_timestamp {
+                return err!(MsError::InvalidExpiration);
+            }
+        }
         
         let spending_limit = &mut ctx.accounts.spending_limit;        
         
@@ -622,10 +1143,28 @@ pub mod squads_mpl {
             mint,
             authority_index,
             amount,
-            member,
-            period,
+            members.clone(),
+            destinations.clone(),
+            period.clone(),
+            mode.clone(),
+            expiration,
             ctx.bumps.spending_limit,
         )?;
+        spending_limit.check_invariants()?;
+
+        emit!(SpendingLimitCreated {
+            multisig: ctx.accounts.multisig.key(),
+            spending_limit: ctx.accounts.spending_limit.key(),
+            create_key,
+            mint,
+            authority_index,
+            amount,
+            members,
+            destinations,
+            period,
+            mode,
+            expiration,
+        });
 
         let new_index = ctx.accounts.multisig.transaction_index;
         ctx.accounts.multisig.set_change_index(new_index)
@@ -633,6 +1172,11 @@ pub mod squads_mpl {
 
     /// Method to remove a spending limit
     pub fn remove_spending_limit(ctx: Context<RemoveSpendingLimit>, ) -> Result<()> {
+        emit!(SpendingLimitRemoved {
+            multisig: ctx.accounts.multisig.key(),
+            spending_limit: ctx.accounts.spending_limit.key(),
+        });
+
         let new_index = ctx.accounts.multisig.transaction_index;
         ctx.accounts.multisig.set_change_index(new_index)
     }
@@ -641,6 +1185,11 @@ pub mod squads_mpl {
         // Disable spending limit
         ctx.accounts.multisig.spending_limit_enabled = false;
 
+        emit!(SpendingLimitPaused {
+            multisig: ctx.accounts.multisig.key(),
+            guardian: ctx.accounts.disabler.key(),
+        });
+
         // Mark the change by updating the change index to deprecate any active transactions
         let new_index = ctx.accounts.multisig.transaction_index;
         ctx.accounts.multisig.set_change_index(new_index)
@@ -655,36 +1204,25 @@ pub mod squads_mpl {
 
         let now = Clock::get()?.unix_timestamp;
 
+        // Ensure the multisig is not frozen by the guardian
+        if ctx.accounts.multisig.is_frozen(now) {
+            return err!(MsError::MultisigFrozen);
+        }
+
         // Get a mutable reference to `spending_limit` account.
         let spending_limit = &mut ctx.accounts.spending_limit;
 
-        // Calculate the reset period in seconds.
-        let reset_period = spending_limit.period.to_seconds().unwrap();
-
-        // Calculate the timestamp difference between now and last reset.
-        let time_since_last_reset = now.checked_sub(spending_limit.last_reset).unwrap();
-
+        // Ensure the spending limit can send to the destination
+        if !spending_limit.is_destination_allowed(ctx.accounts.destination.key) {
+            return err!(MsError::DestinationNotAllowed);
+        }
 
         let multisig_key = ctx.accounts.multisig.key();
         let destination = ctx.accounts.destination.as_ref();
         let vault_bump = ctx.bumps.vault;
 
-        // Check if the reset period has passed.
-        if time_since_last_reset > reset_period {
-            // Reset remaining amount and update the last reset timestamp.
-            spending_limit.remaining_amount = spending_limit.amount;
-            let periods_passed = time_since_last_reset.checked_div(reset_period).unwrap();
-            spending_limit.last_reset = spending_limit
-                .last_reset
-                .checked_add(periods_passed.checked_mul(reset_period).unwrap())
-                .unwrap();
-        }
-
         // Subtract the amount from the remaining limit, ensuring it does not exceed.
-        spending_limit.remaining_amount = spending_limit
-            .remaining_amount
-            .checked_sub(amount)
-            .ok_or(MsError::SpendingLimitExceeded)?;
+        spending_limit.spend(amount, now)?;
 
         // Determine transfer type based on mint
         if spending_limit.mint == Pubkey::default() {
@@ -761,6 +1299,382 @@ pub mod squads_mpl {
             )?;
         }
 
+        emit!(SpendingLimitUsed {
+            multisig: multisig_key,
+            spending_limit: ctx.accounts.spending_limit.key(),
+            member: ctx.accounts.member.key(),
+            destination: destination.key(),
+            mint: ctx.accounts.spending_limit.mint,
+            amount,
+            remaining_amount: ctx.accounts.spending_limit.remaining_amount,
+            total_spent: ctx.accounts.spending_limit.total_spent,
+            use_count: ctx.accounts.spending_limit.use_count,
+        });
+
+        Ok(())
+    }
+
+    /// Instruction to rotate (Some) or remove (None) the guardian of the multisig.
+    /// The change can be finalized once the multisig time lock and the guardian
+    /// change delay have passed, and can be vetoed by the current guardian until then,
+    /// up to MAX_GUARDIAN_VETOES changes in a row.
+    pub fn propose_guardian_change(ctx: Context<MsAuth>, guardian: Option<Pubkey>) -> Result<()> {
+        let multisig = &mut ctx.accounts.multisig;
+        multisig.propose_guardian_change(guardian, Clock::get()?.unix_timestamp)?;
+
+        emit!(GuardianChangeProposed {
+            multisig: multisig.key(),
+            guardian,
+            ready_at: multisig.pending_guardian_change.as_ref().unwrap().ready_at,
+        });
+        Ok(())
+    }
+
+    /// Instruction applying a pending guardian change once its delay has passed, can be called by anyone
+    pub fn finalize_guardian_change(ctx: Context<FinalizeGuardianChange>) -> Result<()> {
+        let multisig = &mut ctx.accounts.multisig;
+        multisig.finalize_guardian_change(Clock::get()?.unix_timestamp)?;
+
+        emit!(MultisigSettingsChanged::new(multisig.key(), multisig));
+        Ok(())
+    }
+
+    /// Instruction for the current guardian to veto a pending guardian change.
+    /// The guardian can't veto more than MAX_GUARDIAN_VETOES changes in a row,
+    /// the count is reset once a change is finalized.
+    pub fn veto_guardian_change(ctx: Context<VetoGuardianChange>) -> Result<()> {
+        ctx.accounts.multisig.veto_guardian_change()?;
+
+        emit!(GuardianChangeVetoed {
+            multisig: ctx.accounts.multisig.key(),
+            guardian: ctx.accounts.guardian.key(),
+        });
+        Ok(())
+    }
+
+    /// Instruction for the guardian to freeze the multisig for `duration` seconds, blocking
+    /// the execution of non internal transactions and the use of spending limits.
+    /// Members can still vote on and cancel transactions.
+    pub fn freeze_multisig(ctx: Context<FreezeMultisig>, duration: u32) -> Result<()> {
+        let multisig = &mut ctx.accounts.multisig;
+        multisig.freeze(duration, Clock::get()?.unix_timestamp)?;
+
+        emit!(MultisigFrozen {
+            multisig: multisig.key(),
+            guardian: ctx.accounts.guardian.key(),
+            frozen_until: multisig.frozen_until,
+        });
+        Ok(())
+    }
+
+    /// Instruction to end a freeze early, needs to be executed as an internal transaction
+    pub fn unfreeze_multisig(ctx: Context<MsAuth>) -> Result<()> {
+        let multisig = &mut ctx.accounts.multisig;
+        multisig.unfreeze(Clock::get()?.unix_timestamp)?;
+
+        emit!(MultisigUnfrozen {
+            multisig: multisig.key(),
+        });
+        Ok(())
+    }
+
+    /// Instruction for the guardian to propose replacing the keys of members, ie. when members lost their keys.
+    /// The recovery can be finalized once the multisig time lock and the recovery delay have passed,
+    /// and can be vetoed until then by any member whose key is not replaced.
+    pub fn propose_recovery(ctx: Context<ProposeRecovery>, replacements: Vec<MemberReplacement>) -> Result<()> {
+        let delay = ctx.accounts.multisig.time_lock.checked_add(RECOVERY_DELAY).unwrap();
+        let ready_at = Clock::get()?.unix_timestamp.checked_add(i64::from(delay)).unwrap();
+
+        ctx.accounts.recovery.init(
+            ctx.accounts.multisig.key(),
+            replacements,
+            ready_at,
+            ctx.accounts.rent_payer.key(),
+            ctx.bumps.recovery,
+        )?;
+        ctx.accounts.recovery.check_invariants(&ctx.accounts.multisig)?;
+
+        emit!(RecoveryProposed {
+            multisig: ctx.accounts.multisig.key(),
+            guardian: ctx.accounts.guardian.key(),
+            replacements: ctx.accounts.recovery.replacements.clone(),
+            ready_at,
+        });
+        Ok(())
+    }
+
+    /// Instruction for any member not replaced by a pending member recovery to veto it
+    pub fn veto_recovery(ctx: Context<VetoRecovery>) -> Result<()> {
+        emit!(RecoveryVetoed {
+            multisig: ctx.accounts.multisig.key(),
+            member: ctx.accounts.member.key(),
+        });
+        Ok(())
+    }
+
+    /// Instruction applying a member recovery once its delay has passed, can be called by anyone.
+    /// The replaced members keep their permissions and weight.
+    pub fn finalize_recovery(ctx: Context<FinalizeRecovery>) -> Result<()> {
+        if Clock::get()?.unix_timestamp < ctx.accounts.recovery.ready_at {
+            return err!(MsError::RecoveryNotReady);
+        }
+
+        // the members may have changed since the proposal
+        let recovery = &ctx.accounts.recovery;
+        recovery.check_invariants(&ctx.accounts.multisig)?;
+
+        let multisig = &mut ctx.accounts.multisig;
+        multisig.replace_members(&recovery.replacements)?;
+
+        // update the change index to deprecate any active transactions
+        let new_index = multisig.transaction_index;
+        multisig.set_change_index(new_index)?;
+
+        // Check the invariants after replacing the members
+        multisig.check_invariants()?;
+
+        emit!(RecoveryFinalized {
+            multisig: multisig.key(),
+            replacements: recovery.replacements.clone(),
+        });
+        Ok(())
+    }
+
+    /// Instruction to create a M-of-N guardian set for the multisig. The set becomes
+    /// the guardian of the multisig once its address is set as the multisig guardian.
+    pub fn create_guardian_set(ctx: Context<CreateGuardianSet>, guardians: Vec<Pubkey>, threshold: u16) -> Result<()> {
+        ctx.accounts.guardian_set.init(
+            ctx.accounts.multisig.key(),
+            guardians,
+            threshold,
+            ctx.bumps.guardian_set,
+        )?;
+        ctx.accounts.guardian_set.check_invariants()?;
+
+        emit!(GuardianSetChanged {
+            multisig: ctx.accounts.multisig.key(),
+            guardian_set: ctx.accounts.guardian_set.key(),
+            guardians: ctx.accounts.guardian_set.guardians.clone(),
+            threshold,
+        });
+        Ok(())
+    }
+
+    /// Instruction to replace the guardians and the threshold of the guardian set.
+    /// Pending actions only count the approvals of the new guardians.
+    pub fn update_guardian_set(ctx: Context<UpdateGuardianSet>, guardians: Vec<Pubkey>, threshold: u16) -> Result<()> {
+        ctx.accounts.guardian_set.set_guardians(guardians, threshold)?;
+        ctx.accounts.guardian_set.check_invariants()?;
+
+        emit!(GuardianSetChanged {
+            multisig: ctx.accounts.multisig.key(),
+            guardian_set: ctx.accounts.guardian_set.key(),
+            guardians: ctx.accounts.guardian_set.guardians.clone(),
+            threshold,
+        });
+        Ok(())
+    }
+
+    /// Instruction for a guardian to propose an action of the guardian set, an instruction
+    /// of this program signed by the guardian set (ie, pause_spending_limit, remove_member_with_guardian).
+    /// The proposing guardian approves the action.
+    pub fn create_guardian_action(ctx: Context<CreateGuardianAction>, instruction: IncomingInstruction) -> Result<()> {
+        // the guardian set can only sign instructions of this program
+        if instruction.program_id != *ctx.program_id {
+            return err!(MsError::InvalidInstructionAccount);
+        }
+
+        let guardian_set = &mut ctx.accounts.guardian_set;
+        guardian_set.action_index = guardian_set.action_index.checked_add(1).unwrap();
+
+        let action = &mut ctx.accounts.action;
+        action.init(
+            guardian_set.key(),
+            guardian_set.action_index,
+            ctx.accounts.creator.key(),
+            instruction,
+            ctx.bumps.action,
+        )?;
+        action.approve(ctx.accounts.creator.key(), guardian_set)?;
+
+        emit!(GuardianActionCreated {
+            multisig: guardian_set.multisig,
+            guardian_set: guardian_set.key(),
+            action: action.key(),
+            action_index: action.action_index,
+            creator: action.creator,
+        });
+        Ok(())
+    }
+
+    /// Instruction for a guardian to approve a guardian action
+    pub fn approve_guardian_action(ctx: Context<ApproveGuardianAction>) -> Result<()> {
+        let guardian_set = &ctx.accounts.guardian_set;
+        let action = &mut ctx.accounts.action;
+        action.approve(ctx.accounts.guardian.key(), guardian_set)?;
+
+        emit!(GuardianActionApproved {
+            multisig: guardian_set.multisig,
+            guardian_set: guardian_set.key(),
+            action: action.key(),
+            guardian: ctx.accounts.guardian.key(),
+            approvals: guardian_set.approvals(&action.approved) as u16,
+        });
+        Ok(())
+    }
+
+    /// Instruction for a guardian to execute a guardian action approved by the guardian set threshold.
+    /// The instruction is signed by the guardian set, and the action account is closed.
+    pub fn execute_guardian_action<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteGuardianAction<'info>>,
+    ) -> Result<()> {
+        let guardian_set = &ctx.accounts.guardian_set;
+        let action = &ctx.accounts.action;
+
+        // only count the approvals of the current guardians
+        if guardian_set.approvals(&action.approved) < usize::from(guardian_set.threshold) {
+            return err!(MsError::GuardianActionNotApproved);
+        }
+
+        let guardian_set_seeds = [
+            b"squad",
+            guardian_set.multisig.as_ref(),
+            b"guardian_set",
+            &[guardian_set.bump],
+        ];
+
+        // map the saved action account data to the instruction to be invoked
+        let ix: Instruction = Instruction {
+            accounts: action
+                .keys
+                .iter()
+                .map(|k| AccountMeta {
+                    pubkey: k.pubkey,
+                    is_signer: k.is_signer,
+                    is_writable: k.is_writable,
+                })
+                .collect(),
+            data: action.data.clone(),
+            program_id: *ctx.program_id,
+        };
+
+        // collect the accounts needed from remaining accounts (order matters)
+        let mut ix_account_infos: Vec<AccountInfo> = Vec::<AccountInfo>::new();
+        let ix_account_iter = &mut ctx.remaining_accounts.iter();
+        // the first account in the submitted list should be the program
+        let ix_program_account = next_account_info(ix_account_iter)?;
+        if ix_program_account.key != &ix.program_id {
+            return err!(MsError::InvalidInstructionAccount);
+        }
+
+        // check the provided remaining accounts match the saved instruction accounts
+        for key in action.keys.iter() {
+            let ix_account_info = next_account_info(ix_account_iter)?;
+            if ix_account_info.key != &key.pubkey {
+                return err!(MsError::InvalidInstructionAccount);
+            }
+            ix_account_infos.push(ix_account_info.clone());
+        }
+
+        invoke_signed(&ix, &ix_account_infos, &[&guardian_set_seeds])?;
+
+        emit!(GuardianActionExecuted {
+            multisig: guardian_set.multisig,
+            guardian_set: guardian_set.key(),
+            action: action.key(),
+            executor: ctx.accounts.guardian.key(),
+        });
+        Ok(())
+    }
+
+    /// Instruction to convert a multisig created with the legacy layout, before members
+    /// had permissions and weights, to the current layout. Legacy members get every
+    /// permission and a weight of 1. The account is reallocated, the rent payer tops
+    /// up its rent. Can be called by anyone as the conversion is deterministic.
+    pub fn migrate_multisig(ctx: Context<MigrateMultisig>) -> Result<()> {
+        let multisig_account_info = ctx.accounts.multisig.to_account_info();
+        let legacy = {
+            let data = multisig_account_info.try_borrow_data()?;
+            if data.len() < 8 || data[..8] != Ms::DISCRIMINATOR {
+                return err!(MsError::InvalidInstructionAccount);
+            }
+            // the layout is given by the size of the account, see LegacyMs::is_legacy_len
+            if !LegacyMs::is_legacy_len(data.len()) {
+                return err!(MsError::AlreadyMigrated);
+            }
+            LegacyMs::deserialize(&mut &data[8..]).map_err(|_| error!(MsError::InvalidInstructionAccount))?
+        };
+
+        // keep the free member spots of the legacy account
+        let spots = (multisig_account_info.data_len().saturating_sub(LegacyMs::SIZE_WITHOUT_MEMBERS)
+            / LegacyMember::INIT_SPACE)
+            .max(legacy.keys.len());
+        let needed_len = Ms::SIZE_WITHOUT_MEMBERS + (spots * Member::INIT_SPACE);
+
+        // if more lamports are needed, transfer them from the rent payer
+        let rent_exempt_lamports = Rent::get()?.minimum_balance(needed_len).max(1);
+        let top_up_lamports = rent_exempt_lamports.saturating_sub(multisig_account_info.lamports());
+        if top_up_lamports > 0 {
+            anchor_lang::system_program::transfer(CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.rent_payer.to_account_info(),
+                    to: multisig_account_info.clone(),
+                },
+            ), top_up_lamports)?;
+        }
+        AccountInfo::realloc(&multisig_account_info, needed_len, false)?;
+
+        let multisig = legacy.migrate();
+        multisig.check_invariants()?;
+        multisig.try_serialize(&mut &mut multisig_account_info.try_borrow_mut_data()?[..])?;
+
+        emit!(MultisigMigrated {
+            multisig: multisig_account_info.key(),
+            members: multisig.keys,
+        });
+        Ok(())
+    }
+
+    /// Instruction to convert a spending limit created with the legacy layout, for a single
+    /// member, to the current layout. The account is reallocated, the rent payer tops up its rent.
+    /// Can be called by anyone as the conversion is deterministic.
+    pub fn migrate_spending_limit(ctx: Context<MigrateSpendingLimit>) -> Result<()> {
+        let spending_limit_account_info = ctx.accounts.spending_limit.to_account_info();
+        let legacy = {
+            let data = spending_limit_account_info.try_borrow_data()?;
+            if data.len() < 8 || data[..8] != SpendingLimit::DISCRIMINATOR {
+                return err!(MsError::InvalidInstructionAccount);
+            }
+            // legacy spending limits have a fixed size, smaller than the current one
+            if data.len() != LegacySpendingLimit::LEN {
+                return err!(MsError::AlreadyMigrated);
+            }
+            LegacySpendingLimit::deserialize(&mut &data[8..]).map_err(|_| error!(MsError::InvalidInstructionAccount))?
+        };
+
+        // if more lamports are needed, transfer them from the rent payer
+        let rent_exempt_lamports = Rent::get()?.minimum_balance(SpendingLimit::LEN).max(1);
+        let top_up_lamports = rent_exempt_lamports.saturating_sub(spending_limit_account_info.lamports());
+        if top_up_lamports > 0 {
+            anchor_lang::system_program::transfer(CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.rent_payer.to_account_info(),
+                    to: spending_limit_account_info.clone(),
+                },
+            ), top_up_lamports)?;
+        }
+        AccountInfo::realloc(&spending_limit_account_info, SpendingLimit::LEN, false)?;
+
+        let spending_limit = legacy.migrate();
+        spending_limit.try_serialize(&mut &mut spending_limit_account_info.try_borrow_mut_data()?[..])?;
+
+        emit!(SpendingLimitMigrated {
+            multisig: spending_limit.multisig,
+            spending_limit: spending_limit_account_info.key(),
+            members: spending_limit.members,
+        });
         Ok(())
     }
 
@@ -4,16 +4,35 @@
 */
 
 
-use anchor_lang::{prelude::*, solana_program::instruction::Instruction};
+use anchor_lang::{prelude::*, solana_program::{hash::hash, instruction::Instruction}};
+#[allow(deprecated)]
 use anchor_lang::solana_program::borsh0_10::get_instance_packed_len;
 use crate::errors::*;
 
 pub const MAX_TIME_LOCK: u32 = 3 * 30 * 24 * 60 * 60; // 3 months
+pub const MAX_PROPOSAL_LIFETIME: u32 = 12 * 30 * 24 * 60 * 60; // 1 year
+pub const MAX_GUARDIANS: usize = 10;
+pub const GUARDIAN_CHANGE_DELAY: u32 = 3 * 24 * 60 * 60; // 3 days, on top of the multisig time lock
+pub const MAX_GUARDIAN_VETOES: u16 = 3; // guardian changes the guardian can veto in a row
+pub const MAX_FREEZE_DURATION: u32 = 7 * 24 * 60 * 60; // 1 week
+pub const FREEZE_COOLDOWN: u32 = 7 * 24 * 60 * 60; // 1 week between the end of a freeze and the next one
+pub const RECOVERY_DELAY: u32 = 14 * 24 * 60 * 60; // 2 weeks, on top of the multisig time lock
+pub const MAX_SPENDING_LIMIT_DESTINATIONS: usize = 10;
+pub const MAX_SPENDING_LIMIT_MEMBERS: usize = 10;
+pub const MAX_EXECUTORS: usize = 10;
+pub const MAX_INSTRUCTION_BUFFER_SIZE: u32 = 10_000; // accounts created by the program can't exceed 10KiB
+pub const MAX_ROLLING_SPENDS: usize = 10;
+
+// Member permission bits
+pub const PERMISSION_INITIATE: u8 = 1 << 0; // create, add instructions to and activate transactions
+pub const PERMISSION_VOTE: u8 = 1 << 1;     // approve, reject and cancel transactions
+pub const PERMISSION_EXECUTE: u8 = 1 << 2;  // execute approved transactions
+pub const PERMISSION_ALL: u8 = PERMISSION_INITIATE | PERMISSION_VOTE | PERMISSION_EXECUTE;
 
 /// Ms is the basic state account for a multisig.
 #[account]
 pub struct Ms {
-    pub threshold: u16,                 // threshold for signatures to execute.
+    pub threshold: u16,                 // threshold of vote weight (sum of member weights) to execute.
 
     pub authority_index: u16,           // luxury field to help track how many authorities are currently used.
 
@@ -33,6 +52,13 @@ pub struct Ms {
     pub time_lock: u32,                 // time lock duration in seconds before a transaction can be executed
     pub spending_limit_enabled: bool,   // Spending limit enabled
     pub guardian: Option<Pubkey>,       // Guardian authority can disable spending limit and remove some members without a multisig vote 
+    pub rent_collector: Option<Pubkey>, // receives the rent of closed transactions/instructions, defaults to the transaction creator
+    pub proposal_lifetime: u32,         // duration in seconds a transaction stays valid after its activation, 0 means no expiry
+    pub pending_guardian_change: Option<PendingGuardianChange>, // guardian rotation/removal waiting for its delay, can be vetoed by the guardian
+    pub frozen_until: i64,              // end of the guardian freeze, executions and spending limits are blocked until then
+    pub time_lock_mode: TimeLockMode,   // whether the time lock delays the votes or the execution of transactions
+    pub execution_policy: ExecutionPolicy, // who can execute ExecuteReady transactions besides members with the execute permission
+    pub guardian_vetoes: u16,           // guardian changes vetoed by the current guardian, so that it can't block its rotation forever
 }
 
 impl Ms {
@@ -46,7 +72,14 @@ impl Ms {
     4 +          // for vec length
     4 +         // time lock
     1 +         // spending limit enabled
-    33;         // guardian authority (one byte for option + 32 for Pubkey)
+    33 +        // guardian authority (one byte for option + 32 for Pubkey)
+    33 +        // rent collector (one byte for option + 32 for Pubkey)
+    4 +         // proposal lifetime
+    1 + 33 + 8 + // pending guardian change (one byte for option + new guardian + ready timestamp)
+    8 +         // frozen until
+    1 +         // time lock mode
+    1 + 4 + (MAX_EXECUTORS * 32) + // execution policy (one byte for the variant + executors)
+    2;          // guardian vetoes
 
 
     /// Initializes the new multisig account
@@ -61,6 +94,13 @@ impl Ms {
         self.time_lock = time_lock; // Initialize with the time_lock
         self.spending_limit_enabled = true;
         self.guardian = None;
+        self.rent_collector = None;
+        self.proposal_lifetime = 0;
+        self.pending_guardian_change = None;
+        self.frozen_until = 0;
+        self.time_lock_mode = TimeLockMode::Voting;
+        self.execution_policy = ExecutionPolicy::Members;
+        self.guardian_vetoes = 0;
         Ok(())
     }
 
@@ -76,16 +116,42 @@ impl Ms {
             return err!(MsError::MaxMembersReached);
         }
 
-        // Ensure the threshold is within the valid range
-        if usize::from(self.threshold) < 1 || usize::from(self.threshold) > self.keys.len() {
+        // Ensure every member has a vote weight
+        if self.keys.iter().any(|m| m.weight < 1) {
+            return err!(MsError::InvalidWeight);
+        }
+
+        // Ensure the threshold is within the valid range,
+        // the members allowed to vote must be able to reach it
+        if self.threshold < 1 || u32::from(self.threshold) > self.total_voting_weight() {
             return err!(MsError::InvalidThreshold);
         }
 
+        // Ensure members only have known permissions
+        if self.keys.iter().any(|m| m.permissions & !PERMISSION_ALL != 0) {
+            return err!(MsError::InvalidPermissions);
+        }
+
         // Ensure the time lock duration is within the maximum allowable duration
         if self.time_lock > MAX_TIME_LOCK {
             return err!(MsError::TimeLockExceedsMaximum);
         }
 
+        // Ensure the executors fit in the account and are not duplicated
+        if let ExecutionPolicy::Executors { keys } = &self.execution_policy {
+            let mut keys = keys.clone();
+            keys.sort();
+            if keys.is_empty() || keys.len() > MAX_EXECUTORS || keys.windows(2).any(|w| w[0] == w[1]) {
+                return err!(MsError::InvalidExecutors);
+            }
+        }
+
+        // Ensure the proposal lifetime leaves time to vote once the time lock is satisfied
+        if self.proposal_lifetime > MAX_PROPOSAL_LIFETIME ||
+            (self.proposal_lifetime > 0 && self.proposal_lifetime <= self.time_lock) {
+            return err!(MsError::InvalidProposalLifetime);
+        }
+
         // Ensure there are no duplicate members
         let mut keys = self.keys.iter().map(|m| m.key).collect::<Vec<_>>();
         keys.sort();
@@ -108,6 +174,47 @@ impl Ms {
         self.keys.binary_search_by_key(&member_key, |m| m.key).ok()
     }
 
+    /// Checks to see if the key is a member of the multisig with the given permission
+    pub fn has_permission(&self, member_key: Pubkey, permission: u8) -> bool {
+        match self.is_member(member_key) {
+            Some(index) => self.keys[index].has_permission(permission),
+            None => false,
+        }
+    }
+
+    /// checks if the key can execute transactions, according to the execution policy
+    pub fn can_execute(&self, key: Pubkey) -> bool {
+        if self.has_permission(key, PERMISSION_EXECUTE) {
+            return true;
+        }
+        match &self.execution_policy {
+            ExecutionPolicy::Members => false,
+            ExecutionPolicy::Executors { keys } => keys.contains(&key),
+            ExecutionPolicy::Permissionless => true,
+        }
+    }
+
+    /// Sum of the weights of the members allowed to vote on transactions
+    pub fn total_voting_weight(&self) -> u32 {
+        self.keys
+            .iter()
+            .filter(|m| m.has_permission(PERMISSION_VOTE))
+            .map(|m| u32::from(m.weight))
+            .sum()
+    }
+
+    /// Sum of the weights of the given keys, only counting
+    /// the members currently allowed to vote
+    pub fn vote_weight(&self, voters: &[Pubkey]) -> u32 {
+        voters
+            .iter()
+            .filter_map(|key| self.is_member(*key))
+            .map(|index| &self.keys[index])
+            .filter(|m| m.has_permission(PERMISSION_VOTE))
+            .map(|m| u32::from(m.weight))
+            .sum()
+    }
+
     /// Updates the change index, deprecating any active/draft transactions
     /// that have an index lower than the change index
     pub fn set_change_index(&mut self, index: u32) -> Result<()>{
@@ -141,6 +248,130 @@ impl Ms {
         Ok(())
     }
 
+    /// sets the rent collector for closed transactions and instructions.
+    pub fn set_rent_collector(&mut self, rent_collector: Option<Pubkey>) -> Result<()>{
+        self.rent_collector = rent_collector;
+        Ok(())
+    }
+
+    /// sets the proposal lifetime for the multisig.
+    pub fn set_proposal_lifetime(&mut self, proposal_lifetime: u32) -> Result<()>{
+        self.proposal_lifetime = proposal_lifetime;
+        Ok(())
+    }
+
+    /// sets whether the time lock applies before voting or before execution.
+    pub fn set_time_lock_mode(&mut self, time_lock_mode: TimeLockMode) -> Result<()>{
+        self.time_lock_mode = time_lock_mode;
+        Ok(())
+    }
+
+    /// sets who can execute transactions besides the members with the execute permission.
+    pub fn set_execution_policy(&mut self, execution_policy: ExecutionPolicy) -> Result<()>{
+        self.execution_policy = execution_policy;
+        Ok(())
+    }
+
+    /// sets the guardian of the multisig, only allowed directly if no guardian is set.
+    /// Rotating or removing the current guardian must go through a guardian change.
+    pub fn set_guardian(&mut self, guardian: Option<Pubkey>) -> Result<()>{
+        if self.guardian.is_some() && self.guardian != guardian {
+            return err!(MsError::GuardianChangeRequiresDelay);
+        }
+        self.guardian = guardian;
+        Ok(())
+    }
+
+    /// starts a guardian change, replacing any pending one. The change can be finalized
+    /// once the multisig time lock and the guardian change delay have passed.
+    pub fn propose_guardian_change(&mut self, guardian: Option<Pubkey>, now: i64) -> Result<()>{
+        let delay = self.time_lock.checked_add(GUARDIAN_CHANGE_DELAY).unwrap();
+        self.pending_guardian_change = Some(PendingGuardianChange {
+            guardian,
+            ready_at: now.checked_add(i64::from(delay)).unwrap(),
+        });
+        Ok(())
+    }
+
+    /// applies the pending guardian change once its delay has passed
+    pub fn finalize_guardian_change(&mut self, now: i64) -> Result<()>{
+        let change = match &self.pending_guardian_change {
+            Some(change) => change,
+            None => return err!(MsError::NoPendingGuardianChange),
+        };
+        if now < change.ready_at {
+            return err!(MsError::GuardianChangeNotReady);
+        }
+        self.guardian = change.guardian;
+        self.pending_guardian_change = None;
+        self.guardian_vetoes = 0;
+        Ok(())
+    }
+
+    /// drops the pending guardian change on a veto of the guardian. The guardian can only veto
+    /// MAX_GUARDIAN_VETOES changes in a row, so that the members can still rotate or remove it.
+    pub fn veto_guardian_change(&mut self) -> Result<()>{
+        if self.pending_guardian_change.is_none() {
+            return err!(MsError::NoPendingGuardianChange);
+        }
+        if self.guardian_vetoes >= MAX_GUARDIAN_VETOES {
+            return err!(MsError::GuardianVetoLimitReached);
+        }
+        self.pending_guardian_change = None;
+        self.guardian_vetoes = self.guardian_vetoes.checked_add(1).unwrap();
+        Ok(())
+    }
+
+    /// checks if the multisig is frozen by the guardian
+    pub fn is_frozen(&self, now: i64) -> bool {
+        now < self.frozen_until
+    }
+
+    /// freezes the multisig for `duration` seconds. A new freeze can only start
+    /// once the cooldown following the end of the previous one has passed.
+    pub fn freeze(&mut self, duration: u32, now: i64) -> Result<()>{
+        if !(1..=MAX_FREEZE_DURATION).contains(&duration) {
+            return err!(MsError::InvalidFreezeDuration);
+        }
+        if now < self.frozen_until.checked_add(i64::from(FREEZE_COOLDOWN)).unwrap() {
+            return err!(MsError::FreezeCooldown);
+        }
+        self.frozen_until = now.checked_add(i64::from(duration)).unwrap();
+        Ok(())
+    }
+
+    /// ends the current freeze early, the cooldown starts now
+    pub fn unfreeze(&mut self, now: i64) -> Result<()>{
+        if !self.is_frozen(now) {
+            return err!(MsError::NotFrozen);
+        }
+        self.frozen_until = now;
+        Ok(())
+    }
+
+    /// replaces the keys of members, keeping their permissions and weight
+    pub fn replace_members(&mut self, replacements: &[MemberReplacement]) -> Result<()>{
+        for replacement in replacements {
+            let index = match self.is_member(replacement.old_key) {
+                Some(index) => index,
+                None => return err!(MsError::MemberNotFound),
+            };
+            self.keys[index].key = replacement.new_key;
+            // keep the keys sorted for the lookups of the next replacements
+            self.keys.sort_by_key(|m| m.key);
+        }
+        Ok(())
+    }
+
+    /// returns the threshold and time lock applying to a transaction,
+    /// taking the policy of its authority into account if there is one
+    pub fn get_policy(&self, policy: Option<&AuthorityPolicy>) -> (u16, u32) {
+        match policy {
+            Some(policy) => (policy.threshold, policy.time_lock),
+            None => (self.threshold, self.time_lock),
+        }
+    }
+
     /// sets the threshold for the multisig.
     pub fn change_threshold(&mut self, threshold: u16) -> Result<()>{
         self.threshold = threshold;
@@ -149,14 +380,46 @@ impl Ms {
 
 }
 
-#[derive(AnchorDeserialize, AnchorSerialize, InitSpace, Eq, PartialEq, Clone)]
+#[derive(AnchorDeserialize, AnchorSerialize, InitSpace, Eq, PartialEq, Clone, Debug)]
 pub struct Member {
     pub key: Pubkey,
     pub guardian_can_remove: bool,
+    pub permissions: u8,    // bitmask of PERMISSION_INITIATE, PERMISSION_VOTE and PERMISSION_EXECUTE
+    pub weight: u16,        // weight of the member votes towards the threshold
+}
+
+impl Member {
+    /// Checks if the member has been granted the given permission(s)
+    pub fn has_permission(&self, permission: u8) -> bool {
+        self.permissions & permission == permission
+    }
+}
+
+/// Guardian rotation or removal waiting for its delay
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct PendingGuardianChange {
+    pub guardian: Option<Pubkey>,       // the new guardian, None removes the guardian
+    pub ready_at: i64,                  // timestamp after which the change can be finalized
+}
+
+/// TimeLockMode enum of when the time lock of the multisig applies.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum TimeLockMode {
+    Voting,     // members wait the time lock after activation before approving
+    Execution,  // members wait the time lock after approval before executing, the transaction can still be cancelled
+}
+
+/// ExecutionPolicy enum of who can execute the ExecuteReady transactions,
+/// members with the execute permission can always execute them.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum ExecutionPolicy {
+    Members,                        // only members with the execute permission
+    Executors { keys: Vec<Pubkey> },// designated executor keys, ie. keeper bots
+    Permissionless,                 // anyone
 }
 
 /// MsTransactionStatus enum of the current status of the Multisig Transaction.
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
 pub enum MsTransactionStatus {
     Draft { timestamp: i64 },          // Transaction default state
     Active { timestamp: i64 },         // Transaction is live and ready
@@ -164,6 +427,7 @@ pub enum MsTransactionStatus {
     Executed { timestamp: i64 },       // Transaction has been executed
     Rejected { timestamp: i64 },       // Transaction has been rejected
     Cancelled { timestamp: i64 },      // Transaction has been cancelled
+    Expired { timestamp: i64 },        // Transaction has passed its deadline without being executed
 }
 
 /// The MsTransaction is the state account for a multisig transaction
@@ -180,8 +444,11 @@ pub struct MsTransaction {
     pub approved: Vec<Pubkey>,          // keys that have approved/signed
     pub rejected: Vec<Pubkey>,          // keys that have rejected
     pub cancelled: Vec<Pubkey>,         // keys that have cancelled (ExecuteReady only)
-    pub executed_index: u8              // if Tx is executed sequentially, tracks which ix
+    pub executed_index: u8,             // if Tx is executed sequentially, tracks which ix
                                         // has been executed so far.
+    pub execution_delay: u32,           // seconds to wait after ExecuteReady before executing,
+                                        // the time lock in the execution time lock mode.
+    pub activated_at: i64,              // activation timestamp, the start of the proposal lifetime
 }
 
 impl MsTransaction {
@@ -191,10 +458,12 @@ impl MsTransaction {
         4 +                                 // the transaction index
         4 +                                 // the authority index (for this proposal)
         1 +                                 // the authority bump
-        (8 * 6) +                           // the timestamp for each status variant
+        (8 * 7) +                           // the timestamp for each status variant
         1 +                                 // the number of instructions (attached)
         1 +                                 // space for tx bump
-        1;                                  // track index if executed sequentially
+        1 +                                 // track index if executed sequentially
+        4 +                                 // the execution delay
+        8;                                  // the activation timestamp
 
     pub fn initial_size_with_members(members_len: usize) -> usize {
         MsTransaction::MINIMUM_SIZE + (3 * (4 + (members_len * 32) ) )
@@ -214,21 +483,36 @@ impl MsTransaction {
         self.cancelled = Vec::new();
         self.bump = bump;
         self.executed_index = 0;
+        self.execution_delay = 0;
+        self.activated_at = 0;
         Ok(())
     }
 
     /// change status to Active
     pub fn activate(&mut self)-> Result<()>{
-        self.status = MsTransactionStatus::Active { timestamp: Clock::get()?.unix_timestamp };
+        let now = Clock::get()?.unix_timestamp;
+        self.status = MsTransactionStatus::Active { timestamp: now };
+        self.activated_at = now;
         Ok(())
     }
 
     /// change status to ExecuteReady
-    pub fn ready_to_execute(&mut self)-> Result<()>{
+    pub fn ready_to_execute(&mut self, execution_delay: u32)-> Result<()>{
         self.status = MsTransactionStatus::ExecuteReady { timestamp: Clock::get()?.unix_timestamp };
+        self.execution_delay = execution_delay;
         Ok(())
     }
 
+    /// check if an ExecuteReady transaction is still waiting for its execution delay
+    pub fn is_execution_delayed(&self, now: i64) -> bool {
+        match self.status {
+            MsTransactionStatus::ExecuteReady { timestamp } => {
+                now.saturating_sub(timestamp) < i64::from(self.execution_delay)
+            }
+            _ => false,
+        }
+    }
+
     /// set status to Rejected
     pub fn set_rejected(&mut self) -> Result<()>{
         self.status = MsTransactionStatus::Rejected { timestamp: Clock::get()?.unix_timestamp };
@@ -241,6 +525,12 @@ impl MsTransaction {
         Ok(())
     }
 
+    /// set status to Expired
+    pub fn set_expired(&mut self) -> Result<()>{
+        self.status = MsTransactionStatus::Expired { timestamp: Clock::get()?.unix_timestamp };
+        Ok(())
+    }
+
     /// set status to executed
     pub fn set_executed(&mut self) -> Result<()>{
         self.status = MsTransactionStatus::Executed { timestamp: Clock::get()?.unix_timestamp };
@@ -269,6 +559,38 @@ impl MsTransaction {
     }
 
 
+    /// check if the transaction can be closed and its rent reclaimed,
+    /// either because it reached a final status or because it was deprecated
+    /// by a change of the multisig (ms_change_index)
+    pub fn is_closable(&self, ms_change_index: u32) -> bool {
+        match self.status {
+            MsTransactionStatus::Executed { .. } |
+            MsTransactionStatus::Rejected { .. } |
+            MsTransactionStatus::Cancelled { .. } |
+            MsTransactionStatus::Expired { .. } => true,
+            _ => self.transaction_index <= ms_change_index,
+        }
+    }
+
+    /// check if the transaction has passed its deadline. Active and ExecuteReady transactions
+    /// expire `proposal_lifetime` seconds after activation, reaching the threshold doesn't
+    /// extend it, and neither does a freeze of the multisig.
+    /// A lifetime of 0 means transactions never expire.
+    pub fn is_expired(&self, proposal_lifetime: u32, now: i64) -> bool {
+        if proposal_lifetime == 0 {
+            return false;
+        }
+        match self.status {
+            MsTransactionStatus::Active { timestamp } |
+            MsTransactionStatus::ExecuteReady { timestamp } => {
+                // transactions activated before the upgrade only have their status timestamp
+                let activated_at = if self.activated_at > 0 { self.activated_at } else { timestamp };
+                now.saturating_sub(activated_at) > i64::from(proposal_lifetime)
+            }
+            _ => false,
+        }
+    }
+
     /// check if a user has voted already
     pub fn has_voted(&self, member: Pubkey) -> bool {
         let approved = self.approved.binary_search(&member).is_ok();
@@ -351,6 +673,17 @@ impl From<MsInstruction> for Instruction {
     }
 }
 
+impl From<MsInstruction> for IncomingInstruction {
+    /// Converts the MsInstruction back to the instruction it was attached with
+    fn from(instruction: MsInstruction) -> Self {
+        IncomingInstruction {
+            program_id: instruction.program_id,
+            keys: instruction.keys,
+            data: instruction.data,
+        }
+    }
+}
+
 /// Wrapper for our internal MsInstruction key serialization schema
 /// MsAccount meta is identical to the AccountMeta struct, but defined
 /// here for serialization purposes.
@@ -379,10 +712,405 @@ impl IncomingInstruction {
         // there are 3 extra bytes in a saved instruction account: index, bump, executed
         // this is used to determine how much space the incoming instruction
         // will used when saved
+        #[allow(deprecated)]
         get_instance_packed_len(&self).unwrap_or_default().checked_add(2).unwrap_or_default()
     }
 }
 
+/// Instruction buffer account, holding a serialized IncomingInstruction written in chunks
+/// by the creator of a draft transaction, for instructions too large to be attached
+/// with a single add_instruction (ie. program buffer writes).
+#[account]
+pub struct InstructionBuffer {
+    /// The multisig this belongs to.
+    pub multisig: Pubkey,
+
+    /// The draft transaction the instruction will be attached to.
+    pub transaction: Pubkey,
+
+    /// The creator of the transaction, writing the buffer.
+    pub creator: Pubkey,
+
+    /// The sha256 hash of the complete serialized IncomingInstruction.
+    pub final_hash: [u8; 32],
+
+    /// The size of the complete serialized IncomingInstruction.
+    pub final_size: u32,
+
+    /// The chunks written so far.
+    pub buffer: Vec<u8>,
+
+    /// PDA bump.
+    pub bump: u8,
+}
+
+impl InstructionBuffer {
+    pub fn space(final_size: u32) -> usize {
+        8 + 32 + 32 + 32 + 32 + 4 + (4 + final_size as usize) + 1
+    }
+
+    pub fn init(
+        &mut self,
+        multisig: Pubkey,
+        transaction: Pubkey,
+        creator: Pubkey,
+        final_hash: [u8; 32],
+        final_size: u32,
+        bump: u8,
+    ) -> Result<()> {
+        if final_size == 0 || final_size > MAX_INSTRUCTION_BUFFER_SIZE {
+            return err!(MsError::InvalidBufferSize);
+        }
+        self.multisig = multisig;
+        self.transaction = transaction;
+        self.creator = creator;
+        self.final_hash = final_hash;
+        self.final_size = final_size;
+        self.buffer = Vec::new();
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Appends a chunk to the buffer, which can't grow past its final size
+    pub fn write(&mut self, chunk: &[u8]) -> Result<()> {
+        if self.buffer.len().checked_add(chunk.len()).unwrap() > self.final_size as usize {
+            return err!(MsError::InvalidBufferSize);
+        }
+        self.buffer.extend_from_slice(chunk);
+        Ok(())
+    }
+
+    /// Returns the instruction of a complete buffer, validated against its hash
+    pub fn incoming_instruction(&self) -> Result<IncomingInstruction> {
+        if self.buffer.len() != self.final_size as usize {
+            return err!(MsError::InstructionBufferIncomplete);
+        }
+        if hash(&self.buffer).to_bytes() != self.final_hash {
+            return err!(MsError::InvalidBufferHash);
+        }
+        IncomingInstruction::try_from_slice(&self.buffer).map_err(|_| error!(MsError::InvalidInstructionBuffer))
+    }
+
+    /// Space needed by the MsInstruction account of the buffered instruction,
+    /// 0 if the buffer doesn't hold a valid instruction
+    pub fn instruction_size(&self) -> usize {
+        IncomingInstruction::try_from_slice(&self.buffer)
+            .map(|instruction| instruction.get_max_size())
+            .unwrap_or_default()
+    }
+}
+
+/// Authority policy account, overriding the multisig threshold and time lock
+/// for the transactions using a specific authority (vault, upgrade authority, etc.)
+#[account]
+pub struct AuthorityPolicy {
+    /// The multisig this belongs to.
+    pub multisig: Pubkey,
+
+    /// The index of the authority the policy applies to.
+    pub authority_index: u32,
+
+    /// The vote weight threshold for the transactions of this authority.
+    pub threshold: u16,
+
+    /// The time lock duration in seconds for the transactions of this authority.
+    pub time_lock: u32,
+
+    /// PDA bump.
+    pub bump: u8,
+}
+
+impl AuthorityPolicy {
+    pub const LEN: usize = 8 + 32 + 4 + 2 + 4 + 1;
+
+    pub fn init(
+        &mut self,
+        multisig: Pubkey,
+        authority_index: u32,
+        threshold: u16,
+        time_lock: u32,
+        bump: u8,
+    ) -> Result<()> {
+        self.multisig = multisig;
+        self.authority_index = authority_index;
+        self.threshold = threshold;
+        self.time_lock = time_lock;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Checks the policy can be satisfied by the multisig members
+    pub fn check_invariants(&self, multisig: &Ms) -> Result<()> {
+        if self.threshold < 1 || u32::from(self.threshold) > multisig.total_voting_weight() {
+            return err!(MsError::InvalidThreshold);
+        }
+
+        if self.time_lock > MAX_TIME_LOCK {
+            return err!(MsError::TimeLockExceedsMaximum);
+        }
+
+        if multisig.proposal_lifetime > 0 && self.time_lock >= multisig.proposal_lifetime {
+            return err!(MsError::InvalidProposalLifetime);
+        }
+        Ok(())
+    }
+
+    /// Checks the policy is still satisfiable by the current multisig settings,
+    /// which can change after the policy is set
+    pub fn check_satisfiable(&self, multisig: &Ms) -> Result<()> {
+        if self.check_invariants(multisig).is_err() {
+            return err!(MsError::AuthorityPolicyUnsatisfiable);
+        }
+        Ok(())
+    }
+
+    /// Loads the policy from its PDA account, returns None if no policy was created
+    /// for the authority. The PDA address must be verified by the caller.
+    pub fn try_load(account: &AccountInfo) -> Result<Option<AuthorityPolicy>> {
+        if account.owner != &crate::ID || account.data_is_empty() {
+            return Ok(None);
+        }
+        let mut data: &[u8] = &account.try_borrow_data()?;
+        Ok(Some(AuthorityPolicy::try_deserialize(&mut data)?))
+    }
+}
+
+/// Guardian set account, a M-of-N set of guardian keys for a multisig.
+/// When the guardian of the multisig is set to the address of this account,
+/// guardian actions must be approved by `threshold` guardians of the set,
+/// and are then signed by this account.
+#[account]
+pub struct GuardianSet {
+    /// The multisig this belongs to.
+    pub multisig: Pubkey,
+
+    /// The keys of the guardians, sorted.
+    pub guardians: Vec<Pubkey>,
+
+    /// The number of guardian approvals needed to execute a guardian action.
+    pub threshold: u16,
+
+    /// Look up and seed reference for guardian actions.
+    pub action_index: u32,
+
+    /// PDA bump.
+    pub bump: u8,
+}
+
+impl GuardianSet {
+    pub const LEN: usize = 8 + 32 + 4 + (MAX_GUARDIANS * 32) + 2 + 4 + 1;
+
+    pub fn init(
+        &mut self,
+        multisig: Pubkey,
+        guardians: Vec<Pubkey>,
+        threshold: u16,
+        bump: u8,
+    ) -> Result<()> {
+        self.multisig = multisig;
+        self.action_index = 0;
+        self.bump = bump;
+        self.set_guardians(guardians, threshold)
+    }
+
+    /// Replaces the guardians and the threshold of the set
+    pub fn set_guardians(&mut self, mut guardians: Vec<Pubkey>, threshold: u16) -> Result<()> {
+        guardians.sort();
+        self.guardians = guardians;
+        self.threshold = threshold;
+        Ok(())
+    }
+
+    /// Checks the threshold can be reached by the guardians of the set
+    pub fn check_invariants(&self) -> Result<()> {
+        if self.guardians.is_empty() || self.guardians.len() > MAX_GUARDIANS {
+            return err!(MsError::InvalidGuardians);
+        }
+
+        if self.guardians.windows(2).any(|w| w[0] == w[1]) {
+            return err!(MsError::InvalidGuardians);
+        }
+
+        if self.threshold < 1 || usize::from(self.threshold) > self.guardians.len() {
+            return err!(MsError::InvalidThreshold);
+        }
+        Ok(())
+    }
+
+    /// Checks to see if the key is a guardian of the set
+    pub fn is_guardian(&self, key: Pubkey) -> Option<usize> {
+        self.guardians.binary_search(&key).ok()
+    }
+
+    /// Number of the given keys that are currently guardians of the set
+    pub fn approvals(&self, approved: &[Pubkey]) -> usize {
+        approved.iter().filter(|key| self.is_guardian(**key).is_some()).count()
+    }
+}
+
+/// Guardian action account, an instruction of the squads program to be signed
+/// by a guardian set once approved by enough guardians.
+#[account]
+pub struct GuardianAction {
+    /// The guardian set this belongs to.
+    pub guardian_set: Pubkey,
+
+    /// The index of the action in the guardian set.
+    pub action_index: u32,
+
+    /// The guardian who created the action, receives the rent once executed.
+    pub creator: Pubkey,
+
+    /// The accounts of the instruction to sign.
+    pub keys: Vec<MsAccountMeta>,
+
+    /// The data of the instruction to sign.
+    pub data: Vec<u8>,
+
+    /// The guardians who approved the action.
+    pub approved: Vec<Pubkey>,
+
+    /// PDA bump.
+    pub bump: u8,
+}
+
+impl GuardianAction {
+    /// Space needed to store an action for the given instruction
+    pub fn space(instruction: &IncomingInstruction) -> usize {
+        8 +                                 // Anchor discriminator
+        32 +                                // guardian set
+        4 +                                 // action index
+        32 +                                // creator
+        4 + (instruction.keys.len() * (32 + 1 + 1)) + // keys
+        4 + instruction.data.len() +        // data
+        4 + (MAX_GUARDIANS * 32) +          // approvals
+        1                                   // PDA bump
+    }
+
+    pub fn init(
+        &mut self,
+        guardian_set: Pubkey,
+        action_index: u32,
+        creator: Pubkey,
+        instruction: IncomingInstruction,
+        bump: u8,
+    ) -> Result<()> {
+        self.guardian_set = guardian_set;
+        self.action_index = action_index;
+        self.creator = creator;
+        self.keys = instruction.keys;
+        self.data = instruction.data;
+        self.approved = Vec::new();
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Approves the action, is a no-op if the guardian already approved it.
+    /// Approvals of keys no longer in the guardian set are dropped, so that
+    /// the approvals always fit in the account.
+    pub fn approve(&mut self, guardian: Pubkey, guardian_set: &GuardianSet) -> Result<()> {
+        self.approved.retain(|key| guardian_set.is_guardian(*key).is_some());
+        if let Err(pos) = self.approved.binary_search(&guardian) {
+            self.approved.insert(pos, guardian);
+        }
+        Ok(())
+    }
+}
+
+/// Replacement of the key of a member, used by member recoveries
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct MemberReplacement {
+    pub old_key: Pubkey,
+    pub new_key: Pubkey,
+}
+
+/// Member recovery account, a replacement of member keys proposed by the guardian.
+/// It can be finalized once its delay has passed, and vetoed until then by any
+/// member whose key is not replaced.
+#[account]
+pub struct MemberRecovery {
+    /// The multisig this belongs to.
+    pub multisig: Pubkey,
+
+    /// The member keys to replace.
+    pub replacements: Vec<MemberReplacement>,
+
+    /// Timestamp after which the recovery can be finalized.
+    pub ready_at: i64,
+
+    /// Receives the rent once the recovery is finalized or vetoed.
+    pub rent_payer: Pubkey,
+
+    /// PDA bump.
+    pub bump: u8,
+}
+
+impl MemberRecovery {
+    /// Space needed to store a recovery with the given number of replacements
+    pub fn space(replacements: usize) -> usize {
+        8 +                             // Anchor discriminator
+        32 +                            // multisig
+        4 + (replacements * 64) +       // replacements
+        8 +                             // ready at
+        32 +                            // rent payer
+        1                               // PDA bump
+    }
+
+    pub fn init(
+        &mut self,
+        multisig: Pubkey,
+        replacements: Vec<MemberReplacement>,
+        ready_at: i64,
+        rent_payer: Pubkey,
+        bump: u8,
+    ) -> Result<()> {
+        self.multisig = multisig;
+        self.replacements = replacements;
+        self.ready_at = ready_at;
+        self.rent_payer = rent_payer;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Checks the replacements apply to current members, with keys not in the multisig yet,
+    /// leaving at least one voting member able to veto the recovery
+    pub fn check_invariants(&self, multisig: &Ms) -> Result<()> {
+        if self.replacements.is_empty() {
+            return err!(MsError::InvalidRecovery);
+        }
+
+        for (i, replacement) in self.replacements.iter().enumerate() {
+            if multisig.is_member(replacement.old_key).is_none() {
+                return err!(MsError::MemberNotFound);
+            }
+            if multisig.is_member(replacement.new_key).is_some() {
+                return err!(MsError::DuplicateMembers);
+            }
+            // a key can only be replaced once, and only be used once as a new key
+            if self.replacements[..i].iter().any(|r| r.old_key == replacement.old_key || r.new_key == replacement.new_key) {
+                return err!(MsError::InvalidRecovery);
+            }
+        }
+
+        // a guardian replacing every voting member would take over the multisig
+        // once the delay has passed, without anyone left to notice and veto
+        let unreplaced_weight: u32 = multisig.keys
+            .iter()
+            .filter(|m| m.has_permission(PERMISSION_VOTE) && !self.is_replaced(m.key))
+            .map(|m| u32::from(m.weight))
+            .sum();
+        if unreplaced_weight == 0 {
+            return err!(MsError::InvalidRecovery);
+        }
+        Ok(())
+    }
+
+    /// Checks if the key is replaced by the recovery
+    pub fn is_replaced(&self, key: Pubkey) -> bool {
+        self.replacements.iter().any(|r| r.old_key == key)
+    }
+}
+
 /// Spending Limit struct
 #[account]
 pub struct SpendingLimit {
@@ -406,27 +1134,51 @@ pub struct SpendingLimit {
     /// so 1 SOL would be `1_000_000_000` and 1 USDC would be `1_000_000`.
     pub amount: u64,
 
-    // The member that can use the spending limit
-    pub member: Pubkey,
+    /// The members that can use the spending limit, sharing the same remaining amount.
+    pub members: Vec<Pubkey>,
+
+    /// The destinations the spending limit can send to, any destination if empty.
+    /// These are the owners of the token accounts for SPL tokens.
+    pub destinations: Vec<Pubkey>,
 
     /// The reset period of the spending limit.
     /// When it passes, the remaining amount is reset, unless it's `Period::OneTime`.
     pub period: Period,
 
+    /// How the amount spent in a period is accounted.
+    pub mode: SpendingLimitMode,
+
+    /// Optional unix timestamp after which the spending limit can't be used anymore.
+    pub expiration: Option<i64>,
+
     /// The remaining amount of tokens that can be spent in the current period.
     /// When reaches 0, the spending limit cannot be used anymore until the period reset.
+    /// For rolling limits, the remaining amount in the window as of the last use.
     pub remaining_amount: u64,
 
     /// Unix timestamp marking the last time the spending limit was reset (or created).
     pub last_reset: i64,
 
+    /// The spends of the rolling window, oldest first. Only used by rolling limits.
+    pub spends: Vec<Spend>,
+
+    /// The total amount spent since the creation of the spending limit.
+    pub total_spent: u64,
+
+    /// The number of times the spending limit was used.
+    pub use_count: u64,
+
+    /// Unix timestamp of the last use of the spending limit, 0 if never used.
+    pub last_used: i64,
+
     /// PDA bump.
     pub bump: u8,
 }
 
 impl SpendingLimit {
-    pub const LEN: usize = 8 + 32 + 32 + 4 + 32 + 8 + 32 + 1 + 8 + 8 + 1;
+    pub const LEN: usize = 8 + 32 + 32 + 4 + 32 + 8 + (4 + MAX_SPENDING_LIMIT_MEMBERS * 32) + (4 + MAX_SPENDING_LIMIT_DESTINATIONS * 32) + (1 + 4) + 1 + (1 + 8) + 8 + 8 + (4 + MAX_ROLLING_SPENDS * 16) + 8 + 8 + 8 + 1;
 
+    #[allow(clippy::too_many_arguments)]
     pub fn init(
         &mut self,
         multisig: Pubkey,
@@ -434,8 +1186,11 @@ impl SpendingLimit {
         mint: Pubkey,
         authority_index: u32,
         amount: u64,
-        member: Pubkey,
+        members: Vec<Pubkey>,
+        destinations: Vec<Pubkey>,
         period: Period,
+        mode: SpendingLimitMode,
+        expiration: Option<i64>,
         bump: u8,
     ) -> Result<()> {
         self.multisig = multisig;
@@ -443,31 +1198,519 @@ impl SpendingLimit {
         self.mint = mint;
         self.authority_index = authority_index;
         self.amount = amount;
-        self.member = member;
+        self.members = members;
+        self.destinations = destinations;
         self.period = period;
+        self.mode = mode;
+        self.expiration = expiration;
         self.remaining_amount = amount;
         self.last_reset = Clock::get()?.unix_timestamp;
+        self.spends = Vec::new();
+        self.total_spent = 0;
+        self.use_count = 0;
+        self.last_used = 0;
         self.bump = bump;
         Ok(())
     }
 
+    /// Checks the settings of the spending limit
+    pub fn check_invariants(&self) -> Result<()> {
+        if let Period::Custom { seconds: 0 } = self.period {
+            return err!(MsError::InvalidPeriod);
+        }
+
+        // a rolling window needs a period
+        if self.mode == SpendingLimitMode::Rolling && self.period.to_seconds().is_none() {
+            return err!(MsError::InvalidPeriod);
+        }
+
+        if self.members.is_empty() || self.members.len() > MAX_SPENDING_LIMIT_MEMBERS {
+            return err!(MsError::InvalidSpendingLimitMembers);
+        }
+
+        let mut members = self.members.clone();
+        members.sort();
+        if members.windows(2).any(|w| w[0] == w[1]) {
+            return err!(MsError::InvalidSpendingLimitMembers);
+        }
+
+        if self.destinations.len() > MAX_SPENDING_LIMIT_DESTINATIONS {
+            return err!(MsError::InvalidDestinations);
+        }
+
+        let mut destinations = self.destinations.clone();
+        destinations.sort();
+        if destinations.windows(2).any(|w| w[0] == w[1]) {
+            return err!(MsError::InvalidDestinations);
+        }
+        Ok(())
+    }
+
+    /// Spends `amount` from the spending limit, failing if it exceeds the remaining amount
+    pub fn spend(&mut self, amount: u64, now: i64) -> Result<()> {
+        if self.is_expired(now) {
+            return err!(MsError::SpendingLimitExpired);
+        }
+
+        match self.mode {
+            SpendingLimitMode::Fixed => {
+                // one time spending limits never reset
+                if let Some(reset_period) = self.period.to_seconds() {
+                    // Calculate the timestamp difference between now and last reset.
+                    let time_since_last_reset = now.checked_sub(self.last_reset).unwrap();
+
+                    // Check if the reset period has passed.
+                    if time_since_last_reset > reset_period {
+                        // Reset remaining amount and update the last reset timestamp.
+                        self.remaining_amount = self.amount;
+                        let periods_passed = time_since_last_reset.checked_div(reset_period).unwrap();
+                        self.last_reset = self
+                            .last_reset
+                            .checked_add(periods_passed.checked_mul(reset_period).unwrap())
+                            .unwrap();
+                    }
+                }
+
+                self.remaining_amount = self
+                    .remaining_amount
+                    .checked_sub(amount)
+                    .ok_or(MsError::SpendingLimitExceeded)?;
+            }
+            SpendingLimitMode::Rolling => {
+                let window = self.period.to_seconds().unwrap();
+
+                // drop the spends that left the window
+                let window_start = now.checked_sub(window).unwrap();
+                self.spends.retain(|spend| spend.timestamp > window_start);
+
+                let spent = self
+                    .spends
+                    .iter()
+                    .try_fold(0u64, |total, spend| total.checked_add(spend.amount))
+                    .unwrap();
+                self.remaining_amount = self
+                    .amount
+                    .saturating_sub(spent)
+                    .checked_sub(amount)
+                    .ok_or(MsError::SpendingLimitExceeded)?;
+
+                // when full, merge the oldest spend into the second oldest one, keeping
+                // the later timestamp of the two: the merged amount stays in the window
+                // longer than the oldest spend would have, never less
+                if self.spends.len() >= MAX_ROLLING_SPENDS {
+                    let oldest = self.spends.remove(0);
+                    self.spends[0].amount = self.spends[0].amount.checked_add(oldest.amount).unwrap();
+                }
+                self.spends.push(Spend { amount, timestamp: now });
+            }
+        }
+
+        // lifetime counters, for audits
+        self.total_spent = self.total_spent.saturating_add(amount);
+        self.use_count = self.use_count.saturating_add(1);
+        self.last_used = now;
+        Ok(())
+    }
+
+    /// Checks whether the spending limit expired
+    pub fn is_expired(&self, now: i64) -> bool {
+        matches!(self.expiration, Some(expiration) if now >= expiration)
+    }
+
+    /// Checks the key is allowed to use the spending limit
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.members.contains(key)
+    }
+
+    /// Checks the spending limit can send to the destination
+    pub fn is_destination_allowed(&self, destination: &Pubkey) -> bool {
+        self.destinations.is_empty() || self.destinations.contains(destination)
+    }
+}
+
+
+/// How the amount spent in a period is accounted
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum SpendingLimitMode {
+    Fixed,                  // the remaining amount is reset at the start of every period
+    Rolling,                // the spends of the last period can't exceed the amount
 }
 
+/// A spend of a rolling spending limit
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct Spend {
+    pub amount: u64,
+    pub timestamp: i64,
+}
 
 /// Period enum
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub enum Period {
     Daily,
     Weekly,
     Monthly,
+    OneTime,                // never resets
+    Custom { seconds: u32 }, // resets every `seconds`
 }
 
 impl Period {
+    /// Returns the reset period in seconds, None if the spending limit never resets
     pub fn to_seconds(&self) -> Option<i64> {
         match self {
             Period::Daily => Some(24 * 60 * 60),     // 1 day in seconds
             Period::Weekly => Some(7 * 24 * 60 * 60), // 1 week in seconds
             Period::Monthly => Some(30 * 24 * 60 * 60), // 1 month (assuming 30 days) in seconds
+            Period::OneTime => None,
+            Period::Custom { seconds } => Some(i64::from(*seconds)),
+        }
+    }
+}
+
+/// Layout of the Ms accounts created before members had permissions and weights,
+/// only deserialized by `migrate_multisig` to convert them to the current layout.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LegacyMs {
+    pub threshold: u16,
+    pub authority_index: u16,
+    pub transaction_index: u32,
+    pub ms_change_index: u32,
+    pub bump: u8,
+    pub create_key: Pubkey,
+    pub keys: Vec<LegacyMember>,
+    pub time_lock: u32,
+    pub spending_limit_enabled: bool,
+    pub guardian: Option<Pubkey>,
+}
+
+// the legacy and current sizes are respectively 2 and 0 modulo 3 whatever the member spots
+const _: () = assert!(
+    LegacyMember::INIT_SPACE % 3 == 0
+        && Member::INIT_SPACE % 3 == 0
+        && LegacyMs::SIZE_WITHOUT_MEMBERS % 3 != Ms::SIZE_WITHOUT_MEMBERS % 3
+);
+
+/// Layout of the members of a LegacyMs
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone)]
+pub struct LegacyMember {
+    pub key: Pubkey,
+    pub guardian_can_remove: bool,
+}
+
+impl LegacyMs {
+    pub const SIZE_WITHOUT_MEMBERS: usize = 8 + 2 + 2 + 4 + 4 + 1 + 32 + 4 + 4 + 1 + 33;
+
+    /// Whether an Ms account of `len` bytes has the legacy layout. Ms accounts are always
+    /// sized for a whole number of member spots, and the legacy and current sizes never match.
+    pub fn is_legacy_len(len: usize) -> bool {
+        matches!(
+            len.checked_sub(Self::SIZE_WITHOUT_MEMBERS),
+            Some(members_size) if members_size % LegacyMember::INIT_SPACE == 0
+        )
+    }
+
+    /// Converts the legacy multisig to the current layout, legacy members
+    /// get every permission and a weight of 1, which keeps their threshold.
+    pub fn migrate(self) -> Ms {
+        Ms {
+            threshold: self.threshold,
+            authority_index: self.authority_index,
+            transaction_index: self.transaction_index,
+            ms_change_index: self.ms_change_index,
+            bump: self.bump,
+            create_key: self.create_key,
+            keys: self.keys
+                .into_iter()
+                .map(|m| Member {
+                    key: m.key,
+                    guardian_can_remove: m.guardian_can_remove,
+                    permissions: PERMISSION_ALL,
+                    weight: 1,
+                })
+                .collect(),
+            time_lock: self.time_lock,
+            spending_limit_enabled: self.spending_limit_enabled,
+            guardian: self.guardian,
+            rent_collector: None,
+            proposal_lifetime: 0,
+            pending_guardian_change: None,
+            frozen_until: 0,
+            time_lock_mode: TimeLockMode::Voting,
+            execution_policy: ExecutionPolicy::Members,
+            guardian_vetoes: 0,
+        }
+    }
+}
+
+/// Layout of the SpendingLimit accounts created before they could be shared by several members,
+/// only deserialized by `migrate_spending_limit` to convert them to the current layout.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LegacySpendingLimit {
+    pub create_key: Pubkey,
+    pub multisig: Pubkey,
+    pub authority_index: u32,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub member: Pubkey,
+    pub period: Period,
+    pub remaining_amount: u64,
+    pub last_reset: i64,
+    pub bump: u8,
+}
+
+impl LegacySpendingLimit {
+    pub const LEN: usize = 8 + 32 + 32 + 4 + 32 + 8 + 32 + 1 + 8 + 8 + 1;
+
+    /// Converts the legacy spending limit to the current layout,
+    /// keeping its member and the remaining amount of the current period.
+    pub fn migrate(self) -> SpendingLimit {
+        SpendingLimit {
+            create_key: self.create_key,
+            multisig: self.multisig,
+            authority_index: self.authority_index,
+            mint: self.mint,
+            amount: self.amount,
+            members: vec![self.member],
+            destinations: Vec::new(),
+            period: self.period,
+            mode: SpendingLimitMode::Fixed,
+            expiration: None,
+            remaining_amount: self.remaining_amount,
+            last_reset: self.last_reset,
+            spends: Vec::new(),
+            total_spent: 0,
+            use_count: 0,
+            last_used: 0,
+            bump: self.bump,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    fn legacy_ms(keys: &[u8]) -> LegacyMs {
+        LegacyMs {
+            threshold: 2,
+            authority_index: 1,
+            transaction_index: 4,
+            ms_change_index: 3,
+            bump: 255,
+            create_key: Pubkey::new_from_array([7; 32]),
+            keys: keys
+                .iter()
+                .map(|k| LegacyMember { key: Pubkey::new_from_array([*k; 32]), guardian_can_remove: false })
+                .collect(),
+            time_lock: 60,
+            spending_limit_enabled: true,
+            guardian: None,
+        }
+    }
+
+    /// Serializes the legacy multisig as created by the previous program, with free member spots
+    fn legacy_ms_data(legacy: &LegacyMs, spots: usize) -> Vec<u8> {
+        let mut data = Ms::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        data.resize(LegacyMs::SIZE_WITHOUT_MEMBERS + spots * LegacyMember::INIT_SPACE, 0);
+        data
+    }
+
+    #[test]
+    fn legacy_ms_is_detected_from_its_size() {
+        let data = legacy_ms_data(&legacy_ms(&[0xaa, 0xbb, 0xcc]), 3);
+        assert!(LegacyMs::is_legacy_len(data.len()));
+        assert!(LegacyMs::deserialize(&mut &data[8..]).is_ok());
+
+        for spots in 0..=100 {
+            assert!(LegacyMs::is_legacy_len(LegacyMs::SIZE_WITHOUT_MEMBERS + spots * LegacyMember::INIT_SPACE));
+            assert!(!LegacyMs::is_legacy_len(Ms::SIZE_WITHOUT_MEMBERS + spots * Member::INIT_SPACE));
+        }
+    }
+
+    #[test]
+    fn legacy_ms_migrates_to_current_layout() {
+        let data = legacy_ms_data(&legacy_ms(&[0xaa, 0xbb, 0xcc]), 10);
+        let ms = LegacyMs::deserialize(&mut &data[8..]).unwrap().migrate();
+        ms.check_invariants().unwrap();
+        assert_eq!(ms.threshold, 2);
+        assert_eq!(ms.total_voting_weight(), 3);
+        assert!(ms.keys.iter().all(|m| m.permissions == PERMISSION_ALL && m.weight == 1));
+        assert_eq!(ms.ms_change_index, 3);
+
+        // the migrated multisig fits in the reallocated account
+        let mut migrated = vec![0; Ms::SIZE_WITHOUT_MEMBERS + 10 * Member::INIT_SPACE];
+        ms.try_serialize(&mut &mut migrated[..]).unwrap();
+        let ms = Ms::try_deserialize(&mut &migrated[..]).unwrap();
+        assert_eq!(ms.keys.len(), 3);
+        assert_eq!(ms.execution_policy, ExecutionPolicy::Members);
+    }
+
+    #[test]
+    fn guardian_vetoes_are_limited_until_a_change_is_finalized() {
+        let mut ms = legacy_ms(&[0xaa, 0xbb, 0xcc]).migrate();
+        ms.guardian = Some(Pubkey::new_unique());
+        for _ in 0..MAX_GUARDIAN_VETOES {
+            ms.propose_guardian_change(None, 0).unwrap();
+            ms.veto_guardian_change().unwrap();
         }
+
+        // the guardian can no longer veto its removal
+        ms.propose_guardian_change(None, 0).unwrap();
+        assert!(ms.veto_guardian_change().is_err());
+        ms.finalize_guardian_change(i64::from(ms.time_lock + GUARDIAN_CHANGE_DELAY)).unwrap();
+        assert_eq!(ms.guardian, None);
+        assert_eq!(ms.guardian_vetoes, 0);
+    }
+
+    #[test]
+    fn approved_transactions_expire_a_lifetime_after_activation() {
+        let mut tx = MsTransaction {
+            creator: Pubkey::new_unique(),
+            ms: Pubkey::new_unique(),
+            transaction_index: 1,
+            authority_index: 1,
+            authority_bump: 255,
+            status: MsTransactionStatus::ExecuteReady { timestamp: 900 },
+            instruction_index: 1,
+            bump: 255,
+            approved: Vec::new(),
+            rejected: Vec::new(),
+            cancelled: Vec::new(),
+            executed_index: 0,
+            execution_delay: 0,
+            activated_at: 100,
+        };
+        assert!(!tx.is_expired(1_000, 1_100));
+        assert!(tx.is_expired(1_000, 1_101));
+        assert!(!tx.is_expired(0, 1_000_000));
+
+        // transactions activated before the upgrade expire from their status timestamp
+        tx.activated_at = 0;
+        assert!(!tx.is_expired(1_000, 1_900));
+        assert!(tx.is_expired(1_000, 1_901));
+    }
+
+    #[test]
+    fn legacy_spending_limit_migrates_to_current_layout() {
+        let legacy = LegacySpendingLimit {
+            create_key: Pubkey::new_unique(),
+            multisig: Pubkey::new_unique(),
+            authority_index: 1,
+            mint: Pubkey::default(),
+            amount: 100,
+            member: Pubkey::new_unique(),
+            period: Period::Weekly,
+            remaining_amount: 40,
+            last_reset: 1_000,
+            bump: 254,
+        };
+        let mut data = SpendingLimit::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        assert_eq!(data.len(), LegacySpendingLimit::LEN);
+
+        let spending_limit = LegacySpendingLimit::deserialize(&mut &data[8..]).unwrap().migrate();
+        assert_eq!(spending_limit.members, vec![legacy.member]);
+        assert_eq!(spending_limit.remaining_amount, 40);
+        assert_eq!(spending_limit.mode, SpendingLimitMode::Fixed);
+
+        let mut migrated = vec![0; SpendingLimit::LEN];
+        spending_limit.try_serialize(&mut &mut migrated[..]).unwrap();
+        assert!(SpendingLimit::try_deserialize(&mut &migrated[..]).is_ok());
+    }
+
+    fn rolling_limit(amount: u64, window: u32) -> SpendingLimit {
+        SpendingLimit {
+            create_key: Pubkey::new_unique(),
+            multisig: Pubkey::new_unique(),
+            authority_index: 1,
+            mint: Pubkey::default(),
+            amount,
+            members: vec![Pubkey::new_unique()],
+            destinations: Vec::new(),
+            period: Period::Custom { seconds: window },
+            mode: SpendingLimitMode::Rolling,
+            expiration: None,
+            remaining_amount: amount,
+            last_reset: 0,
+            spends: Vec::new(),
+            total_spent: 0,
+            use_count: 0,
+            last_used: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn rolling_spends_leave_the_window_after_one_period() {
+        let mut limit = rolling_limit(100, 1_000);
+        limit.spend(100, 5_000).unwrap();
+
+        // still in the window one second before the end of the period
+        assert!(limit.spend(1, 5_999).is_err());
+        // released exactly one period after the spend
+        limit.spend(100, 6_000).unwrap();
+        assert_eq!(limit.remaining_amount, 0);
+        assert_eq!(limit.spends, vec![Spend { amount: 100, timestamp: 6_000 }]);
+    }
+
+    #[test]
+    fn rolling_limit_bounds_any_window() {
+        let mut limit = rolling_limit(100, 1_000);
+        limit.spend(60, 0).unwrap();
+        limit.spend(40, 500).unwrap();
+        assert!(limit.spend(1, 999).is_err());
+
+        // only the first spend left the window
+        limit.spend(60, 1_000).unwrap();
+        assert!(limit.spend(1, 1_499).is_err());
+        assert_eq!(limit.remaining_amount, 0);
+    }
+
+    #[test]
+    fn rolling_spends_are_pruned() {
+        let mut limit = rolling_limit(100, 1_000);
+        for i in 0..5 {
+            limit.spend(10, i * 100).unwrap();
+        }
+        assert_eq!(limit.spends.len(), 5);
+
+        // the spends at 0, 100 and 200 left the window
+        limit.spend(10, 1_250).unwrap();
+        let timestamps: Vec<_> = limit.spends.iter().map(|s| s.timestamp).collect();
+        assert_eq!(timestamps, vec![300, 400, 1_250]);
+        assert_eq!(limit.remaining_amount, 70);
+    }
+
+    #[test]
+    fn full_rolling_spends_are_merged() {
+        let mut limit = rolling_limit(1_000, 1_000);
+        for i in 0..MAX_ROLLING_SPENDS as i64 {
+            limit.spend(i as u64 + 1, i * 10).unwrap();
+        }
+        assert_eq!(limit.spends.len(), MAX_ROLLING_SPENDS);
+
+        // the oldest spend is merged into the second oldest one, at its timestamp
+        limit.spend(100, 200).unwrap();
+        assert_eq!(limit.spends.len(), MAX_ROLLING_SPENDS);
+        assert_eq!(limit.spends[0], Spend { amount: 3, timestamp: 10 });
+        assert_eq!(limit.spends.last(), Some(&Spend { amount: 100, timestamp: 200 }));
+
+        // the merged amount leaves the window with the second oldest spend
+        let spent: u64 = limit.spends.iter().map(|s| s.amount).sum();
+        assert_eq!(limit.remaining_amount, 1_000 - spent);
+        limit.spend(0, 1_010).unwrap();
+        assert_eq!(limit.spends[0], Spend { amount: 3, timestamp: 20 });
+        assert_eq!(limit.remaining_amount, 1_000 - (spent - 3));
+    }
+
+    #[test]
+    fn expired_limits_cannot_be_spent() {
+        let mut limit = rolling_limit(100, 1_000);
+        limit.expiration = Some(2_000);
+        limit.spend(10, 1_999).unwrap();
+        assert!(limit.spend(10, 2_000).is_err());
+        assert_eq!(limit.use_count, 1);
+        assert_eq!(limit.total_spent, 10);
     }
 }
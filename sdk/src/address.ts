@@ -112,3 +112,63 @@ export const getSpendingLimitPDA = (
     ],
     programId
   );
+
+export const getAuthorityPolicyPDA = (
+  msPDA: PublicKey,
+  authorityIndexBN: BN,
+  programId: PublicKey
+) =>
+  PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode("squad"),
+      msPDA.toBuffer(),
+      authorityIndexBN.toArrayLike(Buffer, "le", 4), // note authority index is an u32 (4 byte)
+      utils.bytes.utf8.encode("policy"),
+    ],
+    programId
+  );
+
+export const getInstructionBufferPDA = (txPDA: PublicKey, programId: PublicKey) =>
+  PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode("squad"),
+      txPDA.toBuffer(),
+      utils.bytes.utf8.encode("instruction_buffer"),
+    ],
+    programId
+  );
+
+export const getGuardianSetPDA = (msPDA: PublicKey, programId: PublicKey) =>
+  PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode("squad"),
+      msPDA.toBuffer(),
+      utils.bytes.utf8.encode("guardian_set"),
+    ],
+    programId
+  );
+
+export const getGuardianActionPDA = (
+  guardianSetPDA: PublicKey,
+  actionIndexBN: BN,
+  programId: PublicKey
+) =>
+  PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode("squad"),
+      guardianSetPDA.toBuffer(),
+      actionIndexBN.toArrayLike(Buffer, "le", 4), // note action index is an u32 (4 byte)
+      utils.bytes.utf8.encode("guardian_action"),
+    ],
+    programId
+  );
+
+export const getRecoveryPDA = (msPDA: PublicKey, programId: PublicKey) =>
+  PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode("squad"),
+      msPDA.toBuffer(),
+      utils.bytes.utf8.encode("recovery"),
+    ],
+    programId
+  );
@@ -3,6 +3,32 @@ import { PublicKey } from "@solana/web3.js";
 export const DEFAULT_MULTISIG_PROGRAM_ID = new PublicKey(
   "SMPLecH534NA9acpos4G6x7uf3LWbCAwZQE9e8ZekMu"
 );
+export const PERMISSION_INITIATE = 1 << 0;
+export const PERMISSION_VOTE = 1 << 1;
+export const PERMISSION_EXECUTE = 1 << 2;
+export const PERMISSION_ALL =
+  PERMISSION_INITIATE | PERMISSION_VOTE | PERMISSION_EXECUTE;
+// size of a member in the Ms account (key, guardianCanRemove, permissions, weight)
+export const MEMBER_SIZE = 32 + 1 + 1 + 2;
+// size of the Ms account without its members, cf. Ms::SIZE_WITHOUT_MEMBERS
+export const MS_SIZE_WITHOUT_MEMBERS =
+  8 + // Anchor discriminator
+  2 + // threshold value
+  2 + // authority index
+  4 + // transaction index
+  4 + // processed internal transaction index
+  1 + // PDA bump
+  32 + // creator
+  4 + // for vec length
+  4 + // time lock
+  1 + // spending limit enabled
+  33 + // guardian (one byte for option + 32 for Pubkey)
+  33 + // rent collector (one byte for option + 32 for Pubkey)
+  4 + // proposal lifetime
+  1 + 33 + 8 + // pending guardian change
+  8 + // frozen until
+  1 + // time lock mode
+  1 + 4 + 10 * 32; // execution policy (variant + up to 10 executors)
 export const DEFAULT_PROGRAM_MANAGER_PROGRAM_ID = new PublicKey(
   "SMPLKTQhrgo22hFCVq2VGX1KAktTWjeizkhrdB1eauK"
 );
@@ -5,12 +5,18 @@ import {
   ConnectionConfig,
   TransactionInstruction,
   Signer,
+  AddressLookupTableProgram,
+  TransactionMessage,
+  VersionedTransaction,
 } from "@solana/web3.js";
 
 import { TOKEN_PROGRAM_ID } from "@solana/spl-token"; // Ensure you have the correct import for TOKEN_PROGRAM_ID
 import {
   DEFAULT_MULTISIG_PROGRAM_ID,
   DEFAULT_PROGRAM_MANAGER_PROGRAM_ID,
+  PERMISSION_ALL,
+  MEMBER_SIZE,
+  MS_SIZE_WITHOUT_MEMBERS,
 } from "./constants";
 import squadsMplJSON from "../../target/idl/squads_mpl.json";
 import {SquadsMpl} from "../../idl/squads_mpl";
@@ -29,7 +35,8 @@ import {
   getIxPDA,
   getMsPDA,
   getTxPDA,
-  getSpendingLimitPDA
+  getSpendingLimitPDA,
+  getAuthorityPolicyPDA
 } from "./address";
 import BN from "bn.js";
 import * as anchor from "@coral-xyz/anchor";
@@ -262,7 +269,7 @@ class Squads {
     if (
         !initialMembers.find((member) => member.key.equals(this.wallet.publicKey))
     ) {
-      initialMembers.push({ key: this.wallet.publicKey, guardianCanRemove: false });
+      initialMembers.push({ key: this.wallet.publicKey, guardianCanRemove: false, permissions: PERMISSION_ALL, weight: 1 });
     }
     const [multisigPDA] = getMsPDA(createKey, this.multisigProgramId);
     return [
@@ -415,6 +422,53 @@ class Squads {
     return await methods.instruction();
   }
 
+  private async _removeInstruction(
+      transactionPDA: PublicKey,
+      instructionIndex: number
+  ): Promise<SquadsMethods> {
+    const transaction = await this.getTransaction(transactionPDA);
+    const [lastInstructionPDA] = getIxPDA(
+        transactionPDA,
+        new BN(transaction.instructionIndex, 10),
+        this.multisigProgramId
+    );
+    // the removed instruction and the following ones, except the last, are moved to the previous index
+    const movedInstructions = [...new Array(Math.max(transaction.instructionIndex - instructionIndex, 0))]
+        .map((a, i) => {
+          const [ixPDA] = getIxPDA(
+              transactionPDA,
+              new BN(instructionIndex + i, 10),
+              this.multisigProgramId
+          );
+          return {pubkey: ixPDA, isSigner: false, isWritable: true};
+        });
+    return this.multisig.methods.removeInstruction(instructionIndex).accounts({
+      multisig: transaction.ms,
+      transaction: transactionPDA,
+      instruction: lastInstructionPDA,
+      creator: this.wallet.publicKey,
+    }).remainingAccounts(movedInstructions);
+  }
+
+  // removes the instruction at instructionIndex of a draft transaction, the following ones
+  // are moved to the previous index in the same instruction
+  async removeInstruction(
+      transactionPDA: PublicKey,
+      instructionIndex: number
+  ): Promise<TransactionAccount> {
+    const methods = await this._removeInstruction(transactionPDA, instructionIndex);
+    await methods.rpc();
+    return await this.getTransaction(transactionPDA);
+  }
+
+  async buildRemoveInstruction(
+      transactionPDA: PublicKey,
+      instructionIndex: number
+  ): Promise<TransactionInstruction> {
+    const methods = await this._removeInstruction(transactionPDA, instructionIndex);
+    return await methods.instruction();
+  }
+
   private async _activateTransaction(
       multisigPDA: PublicKey,
       transactionPDA: PublicKey
@@ -453,10 +507,17 @@ class Squads {
       multisigPDA: PublicKey,
       transactionPDA: PublicKey
   ): Promise<SquadsMethods> {
+    const transaction = await this.getTransaction(transactionPDA);
+    const [authorityPolicyPDA] = getAuthorityPolicyPDA(
+        multisigPDA,
+        new BN(transaction.authorityIndex, 10),
+        this.multisigProgramId
+    );
     return this.multisig.methods.approveTransaction().accounts({
       multisig: multisigPDA,
       transaction: transactionPDA,
       member: this.wallet.publicKey,
+      authorityPolicy: authorityPolicyPDA,
     });
   }
 
@@ -484,10 +545,17 @@ class Squads {
       multisigPDA: PublicKey,
       transactionPDA: PublicKey
   ): Promise<SquadsMethods> {
+    const transaction = await this.getTransaction(transactionPDA);
+    const [authorityPolicyPDA] = getAuthorityPolicyPDA(
+        multisigPDA,
+        new BN(transaction.authorityIndex, 10),
+        this.multisigProgramId
+    );
     return this.multisig.methods.rejectTransaction().accounts({
       multisig: multisigPDA,
       transaction: transactionPDA,
       member: this.wallet.publicKey,
+      authorityPolicy: authorityPolicyPDA,
     });
   }
 
@@ -515,10 +583,17 @@ class Squads {
       multisigPDA: PublicKey,
       transactionPDA: PublicKey
   ): Promise<SquadsMethods> {
+    const transaction = await this.getTransaction(transactionPDA);
+    const [authorityPolicyPDA] = getAuthorityPolicyPDA(
+        multisigPDA,
+        new BN(transaction.authorityIndex, 10),
+        this.multisigProgramId
+    );
     return this.multisig.methods.cancelTransaction().accounts({
       multisig: multisigPDA,
       transaction: transactionPDA,
       member: this.wallet.publicKey,
+      authorityPolicy: authorityPolicyPDA,
     });
   }
 
@@ -650,6 +725,80 @@ class Squads {
     return await this._executeTransaction(transactionPDA, payer);
   }
 
+  // creates an address lookup table holding the accounts needed to execute a transaction,
+  // so that it can be executed in a v0 transaction with executeTransactionV0
+  async createTransactionLookupTable(
+      transactionPDA: PublicKey,
+      feePayer?: PublicKey,
+      signers?: Signer[]
+  ): Promise<PublicKey> {
+    const payer = feePayer ?? this.wallet.publicKey;
+    const executeIx = await this._executeTransaction(transactionPDA, payer);
+    // signers can't be loaded from a lookup table
+    const addresses = [
+      ...new Set(executeIx.keys.filter((k) => !k.isSigner).map((k) => k.pubkey.toBase58())),
+    ].map((k) => new PublicKey(k));
+
+    const recentSlot = await this.connection.getSlot("finalized");
+    const [createIx, lookupTable] = AddressLookupTableProgram.createLookupTable({
+      authority: payer,
+      payer,
+      recentSlot,
+    });
+    const instructions = [createIx];
+    // extend in chunks so that each transaction fits the size limit
+    for (let i = 0; i < addresses.length; i += 20) {
+      instructions.push(
+        AddressLookupTableProgram.extendLookupTable({
+          lookupTable,
+          authority: payer,
+          payer,
+          addresses: addresses.slice(i, i + 20),
+        })
+      );
+    }
+    for (const ix of instructions) {
+      const {blockhash} = await this.connection.getLatestBlockhash();
+      const lastValidBlockHeight = await this.connection.getBlockHeight();
+      const tx = new anchor.web3.Transaction({
+        blockhash,
+        lastValidBlockHeight,
+        feePayer: payer,
+      });
+      tx.add(ix);
+      await this.provider.sendAndConfirm(tx, signers);
+    }
+    return lookupTable;
+  }
+
+  // executes a transaction in a v0 transaction, loading its accounts from the lookup table
+  // created by createTransactionLookupTable. The table is usable one slot after its last extension.
+  // The accounts of the table are resolved before the program runs, so the usual account list is used.
+  // preInstructions (ie. a compute unit limit) are added before the execution.
+  async executeTransactionV0(
+      transactionPDA: PublicKey,
+      lookupTable: PublicKey,
+      feePayer?: PublicKey,
+      signers?: Signer[],
+      preInstructions: TransactionInstruction[] = []
+  ): Promise<TransactionAccount> {
+    const payer = feePayer ?? this.wallet.publicKey;
+    const executeIx = await this._executeTransaction(transactionPDA, payer);
+    const lookupTableAccount = (await this.connection.getAddressLookupTable(lookupTable)).value;
+    if (!lookupTableAccount) {
+      throw new Error(`Lookup table ${lookupTable.toBase58()} not found`);
+    }
+
+    const {blockhash} = await this.connection.getLatestBlockhash();
+    const message = new TransactionMessage({
+      payerKey: payer,
+      recentBlockhash: blockhash,
+      instructions: [...preInstructions, executeIx],
+    }).compileToV0Message([lookupTableAccount]);
+    await this.provider.sendAndConfirm(new VersionedTransaction(message), signers);
+    return await this.getTransaction(transactionPDA);
+  }
+
   private async _executeInstruction(
       transactionPDA: PublicKey,
       instructionPDA: PublicKey
@@ -795,6 +944,35 @@ class Squads {
     await methods.rpc();
   }
 
+  // converts a multisig created with the legacy layout, the wallet tops up its rent
+  async migrateMultisig(multisigPDA: PublicKey): Promise<MultisigAccount> {
+    await this.multisig.methods.migrateMultisig().accounts({
+      multisig: multisigPDA,
+      rentPayer: this.wallet.publicKey,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    }).rpc();
+    return await this.getMultisig(multisigPDA);
+  }
+
+  async buildMigrateMultisig(multisigPDA: PublicKey): Promise<TransactionInstruction> {
+    return await this.multisig.methods.migrateMultisig().accounts({
+      multisig: multisigPDA,
+      rentPayer: this.wallet.publicKey,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    }).instruction();
+  }
+
+  // converts a spending limit created with the legacy layout, the wallet tops up its rent
+  async migrateSpendingLimit(multisigPDA: PublicKey, createKey: PublicKey): Promise<SpendingLimitAccount> {
+    const spendingLimitPDA = this.getSpendingLimitPDA(multisigPDA, createKey);
+    await this.multisig.methods.migrateSpendingLimit().accounts({
+      spendingLimit: spendingLimitPDA,
+      rentPayer: this.wallet.publicKey,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    }).rpc();
+    return await this.getSpendingLimit(multisigPDA, createKey);
+  }
+
   // this will check to see if the multisig needs to be reallocated for
   // more members, and return the instruction if necessary (or null)
   async checkGetTopUpInstruction(publicKey: PublicKey): Promise<TransactionInstruction | null> {
@@ -802,23 +980,10 @@ class Squads {
     const ms = await this.getMultisig(publicKey);
     const currDataSize = msAccount.value.data.length;
     const currNumKeys = ms.keys.length;
-    const SIZE_WITHOUT_MEMBERS = 8 + // Anchor discriminator
-        2 +         // threshold value
-        2 +         // authority index
-        4 +         // transaction index
-        4 +         // processed internal transaction index
-        1 +         // PDA bump
-        32 +        // creator
-        4 +         // for vec length
-        33 +        // primary member (one byte for option + 32 for Pubkey)
-        4 +         // time lock
-        4 +         // for guardians vec length
-        (5 * 32); // each guardian is a public key (32 bytes) and there are 5 guardians cf. MAX_GUARDIANS
-
-    const spotsLeft = ((currDataSize - SIZE_WITHOUT_MEMBERS) / 32) - currNumKeys;
+    const spotsLeft = Math.floor((currDataSize - MS_SIZE_WITHOUT_MEMBERS) / MEMBER_SIZE) - currNumKeys;
 
     if(spotsLeft < 1){
-      const neededLen = currDataSize + (10 * 32);
+      const neededLen = currDataSize + (10 * MEMBER_SIZE);
       const rentExemptLamports = await this.provider.connection.getMinimumBalanceForRentExemption(neededLen);
       const topUpLamports = rentExemptLamports - msAccount.value.lamports;
       if(topUpLamports > 0){
@@ -2,7 +2,8 @@ import {
   MultisigAccount,
   SquadsMethodsNamespace,
   Member,
-  Period
+  Period,
+  SpendingLimitMode
 } from "./types";
 import { PublicKey, TransactionInstruction } from "@solana/web3.js";
 import { getAuthorityPDA, getIxPDA, getTxPDA, getSpendingLimitPDA } from "./address";
@@ -132,18 +133,33 @@ export class TransactionBuilder {
     return this.withInstruction(instruction);
   }
 
+  async withProposeGuardianChange(
+    guardian: PublicKey | null,
+  ): Promise<TransactionBuilder> {
+    const instruction = await this.methods
+      .proposeGuardianChange(guardian)
+      .accounts({
+        multisig: this.multisig.publicKey,
+      })
+      .instruction();
+    return this.withInstruction(instruction);
+  }
+
   // Add this after other methods inside the TransactionBuilder class
   async withAddSpendingLimit(
     createKey: PublicKey,
     mint: PublicKey,
     vaultIndex: number,
     amount: number,
-    member: PublicKey,
-    period: Period
+    members: PublicKey[],
+    period: Period,
+    destinations: PublicKey[] = [],
+    mode: SpendingLimitMode = { fixed: {} },
+    expiration: number | null = null,
   ): Promise<TransactionBuilder> {
     const [spendingLimitPDA] = await getSpendingLimitPDA(this.multisig.publicKey, createKey, this.programId);
     const instruction = await this.methods
-      .addSpendingLimit(createKey, mint, vaultIndex, new BN(amount),member,period)
+      .addSpendingLimit(createKey, mint, vaultIndex, new BN(amount), members, destinations, period, mode, expiration === null ? null : new BN(expiration))
       .accounts({
         multisig: this.multisig.publicKey,
         spendingLimit: spendingLimitPDA,
@@ -40,4 +40,6 @@ export type Member = IdlTypes<SquadsMpl>["Member"];
 
 export type Period = IdlTypes<SquadsMpl>["Period"];
 
+export type SpendingLimitMode = IdlTypes<SquadsMpl>["SpendingLimitMode"];
+
 export type SpendingLimitAccount = AccountDefDictionary<SquadsMpl>["spendingLimit"];
@@ -20,14 +20,27 @@ import {
   createTestTransferTransaction,
 } from "../helpers/transactions";
 import { execSync } from "child_process";
-import { LAMPORTS_PER_SOL, ParsedAccountData, SystemProgram } from "@solana/web3.js";
+import { createHash } from "crypto";
+import { ComputeBudgetProgram, LAMPORTS_PER_SOL, ParsedAccountData, SystemProgram } from "@solana/web3.js";
 import Squads, {
   getMsPDA,
   getIxPDA,
   getAuthorityPDA,
   getTxPDA,
   getSpendingLimitPDA,
+  getAuthorityPolicyPDA,
+  getGuardianSetPDA,
+  getGuardianActionPDA,
+  getRecoveryPDA,
+  getInstructionBufferPDA,
+  PERMISSION_ALL,
+  PERMISSION_INITIATE,
+  PERMISSION_VOTE,
+  MS_SIZE_WITHOUT_MEMBERS,
+  MEMBER_SIZE,
+  Member,
 } from "../sdk/src/index";
+import { TransactionBuilder } from "../sdk/src/tx_builder";
 import BN from "bn.js";
 import { agnosticExecute } from "../helpers/sdkExecute";
 
@@ -112,6 +125,119 @@ describe("Programs", function(){
     let timeLock = 0; // Set the time lock to 0 for no delay
     const initialGuardiansKeys =  anchor.web3.Keypair.generate();
 
+    const testMember = (key: anchor.web3.PublicKey, permissions = PERMISSION_ALL, weight = 1): Member => {
+      return { key, guardianCanRemove: false, permissions, weight };
+    };
+
+    // creates a multisig with a fresh create key, the wallet is added with every permission if missing
+    const createTestMultisig = async (members: Member[], threshold = 1, timeLock = 0) => {
+      const createKey = anchor.web3.Keypair.generate().publicKey;
+      await squads.createMultisig(threshold, createKey, members, "", "", "", null, timeLock);
+      const [multisigPDA] = getMsPDA(createKey, squads.multisigProgramId);
+      return multisigPDA;
+    };
+
+    // transfers lamports from the wallet, ie. to fund a vault or the fees of a test signer
+    const fund = async (to: anchor.web3.PublicKey, lamports = 0.1 * LAMPORTS_PER_SOL) => {
+      const fundingTx = await createBlankTransaction(squads.connection, creator.publicKey);
+      fundingTx.add(await createTestTransferTransaction(creator.publicKey, to, lamports));
+      await provider.sendAndConfirm(fundingTx, undefined, { commitment: "confirmed" });
+    };
+
+    // creates and activates a transaction of the wallet with the instructions added by `build`
+    const createActiveTransaction = async (
+      multisigPDA: anchor.web3.PublicKey,
+      authorityIndex: number,
+      build: (txBuilder: TransactionBuilder) => TransactionBuilder | Promise<TransactionBuilder>
+    ) => {
+      const txBuilder = await build(await squads.getTransactionBuilder(multisigPDA, authorityIndex));
+      const [txInstructions, txPDA] = await txBuilder.getInstructions();
+      const activateIx = await squads.buildActivateTransaction(multisigPDA, txPDA);
+      const tx = new anchor.web3.Transaction().add(...txInstructions).add(activateIx);
+      await provider.sendAndConfirm(tx, undefined, { commitment: "confirmed" });
+      return txPDA;
+    };
+
+    // approves a transaction on behalf of another member than the wallet
+    const approveAs = async (transactionPDA: anchor.web3.PublicKey, member: anchor.web3.Keypair) => {
+      const txState = await squads.getTransaction(transactionPDA);
+      const [authorityPolicyPDA] = getAuthorityPolicyPDA(txState.ms, new BN(txState.authorityIndex, 10), squads.multisigProgramId);
+      await program.methods
+        .approveTransaction()
+        .accounts({
+          multisig: txState.ms,
+          transaction: transactionPDA,
+          member: member.publicKey,
+          authorityPolicy: authorityPolicyPDA,
+        })
+        .signers([member])
+        .rpc();
+    };
+
+    // executes a transaction on behalf of another key than the wallet, which pays the fees,
+    // the provider can't send it as the wallet would have to sign it too
+    const executeAs = async (transactionPDA: anchor.web3.PublicKey, executor: anchor.web3.Keypair) => {
+      const executeIx = await squads.buildExecuteTransaction(transactionPDA, executor.publicKey);
+      const executeTx = new anchor.web3.Transaction().add(executeIx);
+      await anchor.web3.sendAndConfirmTransaction(squads.connection, executeTx, [executor], { commitment: "confirmed" });
+      return await squads.getTransaction(transactionPDA, "confirmed");
+    };
+
+    // runs instructions of the program as an internal transaction, approved by the wallet
+    // and the given members, and executed by the wallet
+    const executeInternalTransaction = async (
+      multisigPDA: anchor.web3.PublicKey,
+      build: (txBuilder: TransactionBuilder) => TransactionBuilder | Promise<TransactionBuilder>,
+      approvers: anchor.web3.Keypair[] = []
+    ) => {
+      const txPDA = await createActiveTransaction(multisigPDA, 0, build);
+      await squads.approveTransaction(txPDA);
+      for (const approver of approvers) {
+        await approveAs(txPDA, approver);
+      }
+      await squads.executeTransaction(txPDA);
+      return txPDA;
+    };
+
+    // uses a SOL spending limit on behalf of the given member, the wallet by default
+    const useSolSpendingLimit = (
+      multisigPDA: anchor.web3.PublicKey,
+      createKey: anchor.web3.PublicKey,
+      amount: number,
+      destination: anchor.web3.PublicKey,
+      member?: anchor.web3.Keypair
+    ) => {
+      const [spendingLimitPDA] = getSpendingLimitPDA(multisigPDA, createKey, squads.multisigProgramId);
+      return program.methods
+        .spendingLimitUse(new BN(amount), 9)
+        .accounts({
+          multisig: multisigPDA,
+          spendingLimit: spendingLimitPDA,
+          vault: squads.getAuthorityPDA(multisigPDA, 1),
+          member: member?.publicKey ?? creator.publicKey,
+          destination,
+          systemProgram: SystemProgram.programId,
+          mint: null,
+          vaultTokenAccount: null,
+          destinationTokenAccount: null,
+          tokenProgram: null,
+        })
+        .signers(member ? [member] : [])
+        .rpc({ commitment: "confirmed" });
+    };
+
+    // expects the promise to fail with the given program error, the transactions sent
+    // through the provider are not translated to anchor errors so their logs are checked too
+    const expectProgramError = async (promise: Promise<any>, errorName: string) => {
+      try {
+        await promise;
+      } catch (e) {
+        expect([e.message, ...(e.logs ?? [])].join("\n")).to.include(errorName);
+        return;
+      }
+      throw new Error(`Expected the transaction to fail with ${errorName}`);
+    };
+
     // test suite 
     describe("SMPL Basic functionality", function(){
       this.beforeAll(async function(){
@@ -143,7 +269,7 @@ describe("Programs", function(){
           await squads.createMultisig(
             threshold,
             randomCreateKey,
-            memberList.map((m) => ({ key: m.publicKey, guardianCanRemove: false })),
+            memberList.map((m) => ({ key: m.publicKey, guardianCanRemove: false, permissions: PERMISSION_ALL, weight: 1 })),
             "Test Multisig",
             "Description for testing",
             "https://example.com/image.png",
@@ -412,24 +538,12 @@ describe("Programs", function(){
         // get the current number of keys
         const currNumKeys = msStateCheck.keys.length;
         // get the number of spots left
-        const SIZE_WITHOUT_MEMBERS = 8 +  // Anchor discriminator
-        2 +   // threshold value
-        2 +   // authority index
-        4 +   // transaction index
-        4 +   // processed internal transaction index
-        1 +   // PDA bump
-        32 +  // creator
-        4 +   // for vec length
-        4 +   // time lock
-        1 +   // spending limit enabled
-        33;   // spending limit disabler authority (one byte for option + 32 for Pubkey)
-        
-        const spotsLeft = ((currDataSize - SIZE_WITHOUT_MEMBERS) / 33) - currNumKeys;
+        const spotsLeft = Math.floor((currDataSize - MS_SIZE_WITHOUT_MEMBERS) / MEMBER_SIZE) - currNumKeys;
         // if there is less than 1 spot left, calculate rent needed for realloc of 10 more keys
         if(spotsLeft < 1){
           console.log("            MS needs more space")
           // add space for 10 more keys
-          const neededLen = currDataSize + (10 * 33);
+          const neededLen = currDataSize + (10 * MEMBER_SIZE);
           // rent exempt lamports
           const rentExemptLamports = await squads.connection.getMinimumBalanceForRentExemption(neededLen);
           // top up lamports
@@ -449,7 +563,7 @@ describe("Programs", function(){
         // use 0 as authority index
         const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
         const [txInstructions, txPDA] = await (
-          await txBuilder.withAddMember({key: member2.publicKey, removerAuthority: null})
+          await txBuilder.withAddMember({ key: member2.publicKey, guardianCanRemove: false, permissions: PERMISSION_ALL, weight: 1 })
         ).getInstructions();
         const activateIx = await squads.buildActivateTransaction(msPDA, txPDA);
 
@@ -501,7 +615,7 @@ describe("Programs", function(){
         let msState = await squads.getMultisig(msPDA);
         const startKeys = msState.keys.length;
         const [txInstructions, txPDA] = await (
-          await txBuilder.withAddMember({key: newMember, removerAuthority: null})
+          await txBuilder.withAddMember({ key: newMember, guardianCanRemove: false, permissions: PERMISSION_ALL, weight: 1 })
         ).getInstructions();
         const activateIx = await squads.buildActivateTransaction(msPDA, txPDA);
 
@@ -704,6 +818,11 @@ describe("Programs", function(){
         expect(startTxIndex + 1).to.equal(msState.transactionIndex);
         // get necessary signers
         // if the threshold has changed, use the other members to approve as well
+        const [authorityPolicyPDA] = getAuthorityPolicyPDA(
+          msPDA,
+          new BN(0, 10),
+          squads.multisigProgramId
+        );
         for (let i = 0; i < memberList.length; i++) {
           // check to see if we need more signers
           const approvalState = await squads.getTransaction(txPDA);
@@ -730,6 +849,7 @@ describe("Programs", function(){
                 multisig: msPDA,
                 transaction: txPDA,
                 member: memberList[i].publicKey,
+                authorityPolicy: authorityPolicyPDA,
               })
               .signers([memberList[i]])
               .transaction();
@@ -942,7 +1062,7 @@ describe("Programs", function(){
         
         // Step 2: Add instruction to add the spending limit
         let [txInstructions, txPDA] = await (
-          await txBuilder.withAddSpendingLimit(randomCreateKeySpendingLimit, mint, vaultIndex, amount, creator.publicKey, period)
+          await txBuilder.withAddSpendingLimit(randomCreateKeySpendingLimit, mint, vaultIndex, amount, [creator.publicKey], period)
         ).getInstructions();
 
         // Step 3: Add activation instruction
@@ -994,7 +1114,7 @@ describe("Programs", function(){
 
         let txBuilder = await squads.getTransactionBuilder(msPDA, 0);
         let [txInstructions, txPDA] = await (
-          await txBuilder.withAddSpendingLimit(randomCreateKeySpendingLimitSOL, mint, vaultIndex, amount, creator.publicKey, period)
+          await txBuilder.withAddSpendingLimit(randomCreateKeySpendingLimitSOL, mint, vaultIndex, amount, [creator.publicKey], period)
         ).getInstructions();
         
         let activateIx = await squads.buildActivateTransaction(msPDA, txPDA);
@@ -1086,7 +1206,7 @@ describe("Programs", function(){
         const period = { daily: {} }; // Daily reset period
         let txBuilder = await squads.getTransactionBuilder(msPDA, 0);
         let [txInstructions, txPDA] = await (
-          await txBuilder.withAddSpendingLimit(randomCreateKeySpendingLimitSPL, mint, vaultIndex, limitAmount, creator.publicKey, period)
+          await txBuilder.withAddSpendingLimit(randomCreateKeySpendingLimitSPL, mint, vaultIndex, limitAmount, [creator.publicKey], period)
         ).getInstructions();
         let activateIx = await squads.buildActivateTransaction(msPDA, txPDA);
         const addSpendingLimitTx = new anchor.web3.Transaction().add(...txInstructions).add(activateIx);
@@ -1140,7 +1260,7 @@ describe("Programs", function(){
 
         let txBuilder = await squads.getTransactionBuilder(msPDA, 0);
         let [txInstructions, txPDA] = await (
-          await txBuilder.withAddMember({ key: newMember, guardianCanRemove: true })
+          await txBuilder.withAddMember({ key: newMember, guardianCanRemove: true, permissions: PERMISSION_ALL, weight: 1 })
         ).getInstructions();
         let activateIx = await squads.buildActivateTransaction(msPDA, txPDA);
         
@@ -1218,9 +1338,1252 @@ describe("Programs", function(){
           expect(e.message).to.include("UnauthorizedMember");
         }
       });
+
+      it(`Batch transfer Tx execute with a lookup table`, async function(){
+        // create authority to use (Vault, index 1)
+        const authorityPDA = squads.getAuthorityPDA(msPDA, 1);
+
+        // 30 transfers to new accounts, too many accounts for a legacy transaction
+        const payees = [...new Array(30)].map(() => anchor.web3.Keypair.generate().publicKey);
+        let txState = await squads.createTransaction(msPDA, 1);
+        for (const payee of payees) {
+          const testIx = await createTestTransferTransaction(authorityPDA, payee);
+          await squads.addInstruction(txState.publicKey, testIx);
+        }
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
+        txState = await squads.getTransaction(txState.publicKey);
+        expect(txState.status).to.have.property("executeReady");
+
+        // move funds to auth/vault
+        const moveFundsToMsPDATx = await createBlankTransaction(
+          squads.connection,
+          creator.publicKey
+        );
+        moveFundsToMsPDATx.add(await createTestTransferTransaction(
+          creator.publicKey,
+          authorityPDA,
+          payees.length * 1000000
+        ));
+        await provider.sendAndConfirm(moveFundsToMsPDATx);
+
+        const lookupTable = await squads.createTransactionLookupTable(txState.publicKey);
+        // wait for the lookup table to be active
+        await setTimeout(1000);
+
+        // 30 CPIs and instruction PDA derivations need more than the default compute budget
+        const computeBudgetIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 });
+        await squads.executeTransactionV0(txState.publicKey, lookupTable, undefined, undefined, [computeBudgetIx]);
+
+        txState = await squads.getTransaction(txState.publicKey);
+        expect(txState.status).to.have.property("executed");
+        for (const payee of payees) {
+          const payeeAccount = await squads.connection.getAccountInfo(payee);
+          expect(payeeAccount.lamports).to.equal(1000000);
+        }
+      });
       
     });
 
+    describe("Closing transactions", function(){
+      let closeMsPDA;
+
+      this.beforeAll(async function(){
+        closeMsPDA = await createTestMultisig([testMember(anchor.web3.Keypair.generate().publicKey)]);
+        await fund(squads.getAuthorityPDA(closeMsPDA, 1));
+      });
+
+      it(`Close an executed transaction and its instructions`, async function(){
+        const authorityPDA = squads.getAuthorityPDA(closeMsPDA, 1);
+        const testIx = await createTestTransferTransaction(authorityPDA, anchor.web3.Keypair.generate().publicKey);
+        const txPDA = await createActiveTransaction(closeMsPDA, 1, (txBuilder) => txBuilder.withInstruction(testIx));
+        await squads.approveTransaction(txPDA);
+        await squads.executeTransaction(txPDA);
+        const [ixPDA] = getIxPDA(txPDA, new BN(1, 10), squads.multisigProgramId);
+
+        // the instructions must be closed first
+        await expectProgramError(
+          program.methods
+            .closeTransaction()
+            .accounts({ multisig: closeMsPDA, transaction: txPDA, rentCollector: creator.publicKey })
+            .rpc(),
+          "InstructionsNotClosed"
+        );
+
+        const balanceBefore = await squads.connection.getBalance(creator.publicKey, "confirmed");
+        await program.methods
+          .closeInstruction()
+          .accounts({ multisig: closeMsPDA, transaction: txPDA, instruction: ixPDA, rentCollector: creator.publicKey })
+          .rpc({ commitment: "confirmed" });
+        await program.methods
+          .closeTransaction()
+          .accounts({ multisig: closeMsPDA, transaction: txPDA, rentCollector: creator.publicKey })
+          .rpc({ commitment: "confirmed" });
+
+        expect(await squads.connection.getAccountInfo(ixPDA, "confirmed")).to.be.null;
+        expect(await squads.connection.getAccountInfo(txPDA, "confirmed")).to.be.null;
+        // the reclaimed rent covers the fees of the two closing transactions
+        const balanceAfter = await squads.connection.getBalance(creator.publicKey, "confirmed");
+        expect(balanceAfter).to.be.greaterThan(balanceBefore);
+      });
+
+      it(`Cannot close an active transaction`, async function(){
+        const txState = await squads.createTransaction(closeMsPDA, 1);
+        await squads.activateTransaction(txState.publicKey);
+
+        await expectProgramError(
+          program.methods
+            .closeTransaction()
+            .accounts({ multisig: closeMsPDA, transaction: txState.publicKey, rentCollector: creator.publicKey })
+            .rpc(),
+          "InvalidTransactionState"
+        );
+      });
+
+      it(`Send the rent to the rent collector`, async function(){
+        const rentCollector = anchor.web3.Keypair.generate().publicKey;
+        const setRentCollectorIx = await program.methods
+          .setRentCollector(rentCollector)
+          .accounts({ multisig: closeMsPDA })
+          .instruction();
+        const txPDA = await executeInternalTransaction(closeMsPDA, (txBuilder) => txBuilder.withInstruction(setRentCollectorIx));
+        const msState = await squads.getMultisig(closeMsPDA);
+        expect(msState.rentCollector.toBase58()).to.equal(rentCollector.toBase58());
+
+        // the rent no longer goes back to the creator of the transaction
+        const [ixPDA] = getIxPDA(txPDA, new BN(1, 10), squads.multisigProgramId);
+        await expectProgramError(
+          program.methods
+            .closeInstruction()
+            .accounts({ multisig: closeMsPDA, transaction: txPDA, instruction: ixPDA, rentCollector: creator.publicKey })
+            .rpc(),
+          "InvalidRentCollector"
+        );
+
+        await program.methods
+          .closeInstruction()
+          .accounts({ multisig: closeMsPDA, transaction: txPDA, instruction: ixPDA, rentCollector })
+          .rpc({ commitment: "confirmed" });
+        await program.methods
+          .closeTransaction()
+          .accounts({ multisig: closeMsPDA, transaction: txPDA, rentCollector })
+          .rpc({ commitment: "confirmed" });
+        const rentCollectorAccount = await squads.connection.getAccountInfo(rentCollector, "confirmed");
+        expect(rentCollectorAccount.lamports).to.be.greaterThan(0);
+      });
+    });
+
+
+    describe("Transaction expiry", function(){
+      let expiryMsPDA;
+
+      this.beforeAll(async function(){
+        expiryMsPDA = await createTestMultisig([testMember(anchor.web3.Keypair.generate().publicKey)]);
+        const setProposalLifetimeIx = await program.methods
+          .setProposalLifetime(5)
+          .accounts({ multisig: expiryMsPDA })
+          .instruction();
+        await executeInternalTransaction(expiryMsPDA, (txBuilder) => txBuilder.withInstruction(setProposalLifetimeIx));
+        const msState = await squads.getMultisig(expiryMsPDA);
+        expect(msState.proposalLifetime).to.equal(5);
+      });
+
+      it(`Cannot expire a transaction within its lifetime`, async function(){
+        const txState = await squads.createTransaction(expiryMsPDA, 1);
+        await squads.activateTransaction(txState.publicKey);
+
+        await expectProgramError(
+          program.methods
+            .expireTransaction()
+            .accounts({ multisig: expiryMsPDA, transaction: txState.publicKey })
+            .rpc(),
+          "TransactionNotExpired"
+        );
+      });
+
+      it(`Expire a transaction past its lifetime`, async function(){
+        let txState = await squads.createTransaction(expiryMsPDA, 1);
+        await squads.activateTransaction(txState.publicKey);
+        await setTimeout(8000);
+
+        // it can no longer be approved
+        await expectProgramError(squads.approveTransaction(txState.publicKey), "TransactionExpired");
+
+        // anyone can mark it as expired
+        await program.methods
+          .expireTransaction()
+          .accounts({ multisig: expiryMsPDA, transaction: txState.publicKey })
+          .rpc({ commitment: "confirmed" });
+        txState = await squads.getTransaction(txState.publicKey, "confirmed");
+        expect(txState.status).to.have.property("expired");
+
+        // and its rent reclaimed
+        await program.methods
+          .closeTransaction()
+          .accounts({ multisig: expiryMsPDA, transaction: txState.publicKey, rentCollector: creator.publicKey })
+          .rpc({ commitment: "confirmed" });
+        expect(await squads.connection.getAccountInfo(txState.publicKey, "confirmed")).to.be.null;
+      });
+
+      it(`An approved transaction expires a lifetime after its activation`, async function(){
+        const authorityPDA = squads.getAuthorityPDA(expiryMsPDA, 1);
+        const txPDA = await createActiveTransaction(expiryMsPDA, 1, async (txBuilder) =>
+          txBuilder.withInstruction(await createTestTransferTransaction(authorityPDA, creator.publicKey))
+        );
+        await setTimeout(3000);
+        let txState = await squads.approveTransaction(txPDA);
+        expect(txState.status).to.have.property("executeReady");
+        expect(txState.activatedAt.toNumber()).to.be.greaterThan(0);
+
+        // reaching the threshold doesn't extend the lifetime
+        await setTimeout(3500);
+        await expectProgramError(squads.executeTransaction(txPDA), "TransactionExpired");
+        await program.methods
+          .expireTransaction()
+          .accounts({ multisig: expiryMsPDA, transaction: txPDA })
+          .rpc({ commitment: "confirmed" });
+        txState = await squads.getTransaction(txPDA, "confirmed");
+        expect(txState.status).to.have.property("expired");
+      });
+
+      it(`Cannot set a proposal lifetime above the maximum`, async function(){
+        // a multisig without lifetime, the transaction must not expire before its execution
+        const multisigPDA = await createTestMultisig([testMember(anchor.web3.Keypair.generate().publicKey)]);
+        // 1 year and 1 second
+        const setProposalLifetimeIx = await program.methods
+          .setProposalLifetime(12 * 30 * 24 * 60 * 60 + 1)
+          .accounts({ multisig: multisigPDA })
+          .instruction();
+        const txPDA = await createActiveTransaction(multisigPDA, 0, (txBuilder) => txBuilder.withInstruction(setProposalLifetimeIx));
+        await squads.approveTransaction(txPDA);
+        await expectProgramError(squads.executeTransaction(txPDA), "InvalidProposalLifetime");
+      });
+    });
+
+
+    describe("Member permissions", function(){
+      let permissionsMsPDA;
+      const proposer = anchor.web3.Keypair.generate();
+      const voter = anchor.web3.Keypair.generate();
+
+      // creates a vault transaction on behalf of the given member
+      const createTransactionAs = async (member: anchor.web3.Keypair) => {
+        const msState = await squads.getMultisig(permissionsMsPDA, "confirmed");
+        const [txPDA] = getTxPDA(permissionsMsPDA, new BN(msState.transactionIndex + 1, 10), squads.multisigProgramId);
+        await program.methods
+          .createTransaction(1)
+          .accounts({ multisig: permissionsMsPDA, transaction: txPDA, creator: member.publicKey })
+          .signers([member])
+          .rpc({ commitment: "confirmed" });
+        return txPDA;
+      };
+
+      this.beforeAll(async function(){
+        permissionsMsPDA = await createTestMultisig([
+          testMember(proposer.publicKey, PERMISSION_INITIATE),
+          testMember(voter.publicKey, PERMISSION_VOTE),
+        ]);
+        await fund(proposer.publicKey);
+        await fund(voter.publicKey);
+      });
+
+      it(`Only members with the initiate permission can create transactions`, async function(){
+        await expectProgramError(createTransactionAs(voter), "UnauthorizedMember");
+
+        const txPDA = await createTransactionAs(proposer);
+        await program.methods
+          .activateTransaction()
+          .accounts({ multisig: permissionsMsPDA, transaction: txPDA, creator: proposer.publicKey })
+          .signers([proposer])
+          .rpc({ commitment: "confirmed" });
+        const txState = await squads.getTransaction(txPDA, "confirmed");
+        expect(txState.creator.toBase58()).to.equal(proposer.publicKey.toBase58());
+        expect(txState.status).to.have.property("active");
+      });
+
+      it(`Only members with the vote and execute permissions can approve and execute`, async function(){
+        const txPDA = await createTransactionAs(proposer);
+        await program.methods
+          .activateTransaction()
+          .accounts({ multisig: permissionsMsPDA, transaction: txPDA, creator: proposer.publicKey })
+          .signers([proposer])
+          .rpc({ commitment: "confirmed" });
+
+        await expectProgramError(approveAs(txPDA, proposer), "UnauthorizedMember");
+        await approveAs(txPDA, voter);
+        let txState = await squads.getTransaction(txPDA, "confirmed");
+        expect(txState.status).to.have.property("executeReady");
+
+        await expectProgramError(executeAs(txPDA, voter), "UnauthorizedExecutor");
+        txState = await squads.executeTransaction(txPDA);
+        expect(txState.status).to.have.property("executed");
+      });
+
+      it(`Cannot migrate a multisig with the current layout`, async function(){
+        await expectProgramError(squads.migrateMultisig(permissionsMsPDA), "AlreadyMigrated");
+      });
+    });
+
+
+    describe("Weighted voting", function(){
+      let weightsMsPDA;
+      const heavyMember = anchor.web3.Keypair.generate();
+
+      this.beforeAll(async function(){
+        // total voting weight of 4, the heavy member alone reaches the threshold
+        weightsMsPDA = await createTestMultisig([
+          testMember(creator.publicKey, PERMISSION_ALL, 1),
+          testMember(heavyMember.publicKey, PERMISSION_ALL, 3),
+        ], 3);
+        const msState = await squads.getMultisig(weightsMsPDA);
+        expect(msState.threshold).to.equal(3);
+      });
+
+      it(`Approvals count the weight of the members`, async function(){
+        const txPDA = await createActiveTransaction(weightsMsPDA, 1, (txBuilder) => txBuilder);
+        let txState = await squads.approveTransaction(txPDA);
+        expect(txState.status).to.have.property("active");
+
+        await approveAs(txPDA, heavyMember);
+        txState = await squads.getTransaction(txPDA, "confirmed");
+        expect(txState.status).to.have.property("executeReady");
+      });
+
+      it(`Rejections count the weight of the members`, async function(){
+        const txPDA = await createActiveTransaction(weightsMsPDA, 1, (txBuilder) => txBuilder);
+        const [authorityPolicyPDA] = getAuthorityPolicyPDA(weightsMsPDA, new BN(1, 10), squads.multisigProgramId);
+        // a weight of 3 rejecting leaves 1 which can't reach the threshold
+        await program.methods
+          .rejectTransaction()
+          .accounts({
+            multisig: weightsMsPDA,
+            transaction: txPDA,
+            member: heavyMember.publicKey,
+            authorityPolicy: authorityPolicyPDA,
+          })
+          .signers([heavyMember])
+          .rpc({ commitment: "confirmed" });
+        const txState = await squads.getTransaction(txPDA, "confirmed");
+        expect(txState.status).to.have.property("rejected");
+      });
+
+      it(`Cannot set a threshold above the total voting weight`, async function(){
+        const txPDA = await createActiveTransaction(weightsMsPDA, 0, (txBuilder) => txBuilder.withChangeThreshold(5));
+        await squads.approveTransaction(txPDA);
+        await approveAs(txPDA, heavyMember);
+        await expectProgramError(squads.executeTransaction(txPDA), "InvalidThreshold");
+      });
+    });
+
+
+    describe("Authority policies", function(){
+      let policyMsPDA;
+      let policyPDA;
+      const member2 = anchor.web3.Keypair.generate();
+      const member3 = anchor.web3.Keypair.generate();
+
+      this.beforeAll(async function(){
+        policyMsPDA = await createTestMultisig([testMember(member2.publicKey), testMember(member3.publicKey)]);
+        [policyPDA] = getAuthorityPolicyPDA(policyMsPDA, new BN(1, 10), squads.multisigProgramId);
+        // the vault needs every member while internal transactions keep the threshold of 1
+        const addAuthorityPolicyIx = await program.methods
+          .addAuthorityPolicy(1, 3, 0)
+          .accounts({
+            authorityPolicy: policyPDA,
+            multisig: policyMsPDA,
+            rentPayer: creator.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .instruction();
+        await executeInternalTransaction(policyMsPDA, (txBuilder) => txBuilder.withInstruction(addAuthorityPolicyIx));
+        const policy = await program.account.authorityPolicy.fetch(policyPDA);
+        expect(policy.threshold).to.equal(3);
+      });
+
+      it(`Vault transactions use the threshold of the policy`, async function(){
+        const txPDA = await createActiveTransaction(policyMsPDA, 1, (txBuilder) => txBuilder);
+        let txState = await squads.approveTransaction(txPDA);
+        expect(txState.status).to.have.property("active");
+
+        await approveAs(txPDA, member2);
+        txState = await squads.getTransaction(txPDA, "confirmed");
+        expect(txState.status).to.have.property("active");
+
+        await approveAs(txPDA, member3);
+        txState = await squads.getTransaction(txPDA, "confirmed");
+        expect(txState.status).to.have.property("executeReady");
+      });
+
+      it(`Cannot approve once the policy is no longer satisfiable`, async function(){
+        // the remaining voting weight of 2 can't reach the threshold of 3 of the policy
+        await executeInternalTransaction(policyMsPDA, (txBuilder) => txBuilder.withRemoveMember(member3.publicKey));
+
+        const txPDA = await createActiveTransaction(policyMsPDA, 1, (txBuilder) => txBuilder);
+        await expectProgramError(squads.approveTransaction(txPDA), "AuthorityPolicyUnsatisfiable");
+      });
+    });
+
+
+    describe("Guardian sets", function(){
+      let guardianSetMsPDA;
+      let guardianSetPDA;
+      const guardians = [...new Array(3)].map(() => anchor.web3.Keypair.generate());
+      const outsider = anchor.web3.Keypair.generate();
+
+      this.beforeAll(async function(){
+        guardianSetMsPDA = await createTestMultisig([testMember(anchor.web3.Keypair.generate().publicKey)]);
+        [guardianSetPDA] = getGuardianSetPDA(guardianSetMsPDA, squads.multisigProgramId);
+        // 2-of-3 guardian set, set as the guardian of the multisig
+        const createGuardianSetIx = await program.methods
+          .createGuardianSet(guardians.map((g) => g.publicKey), 2)
+          .accounts({
+            guardianSet: guardianSetPDA,
+            multisig: guardianSetMsPDA,
+            rentPayer: creator.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .instruction();
+        await executeInternalTransaction(guardianSetMsPDA, async (txBuilder) =>
+          (await txBuilder.withInstruction(createGuardianSetIx).withUpdateMultisigSettings(0, true, guardianSetPDA))
+        );
+        await fund(guardians[0].publicKey);
+        await fund(outsider.publicKey);
+
+        const msState = await squads.getMultisig(guardianSetMsPDA);
+        expect(msState.guardian.toBase58()).to.equal(guardianSetPDA.toBase58());
+        const guardianSet = await program.account.guardianSet.fetch(guardianSetPDA);
+        expect(guardianSet.guardians.length).to.equal(3);
+        expect(guardianSet.threshold).to.equal(2);
+      });
+
+      it(`Execute a guardian action once approved by the guardian threshold`, async function(){
+        const [actionPDA] = getGuardianActionPDA(guardianSetPDA, new BN(1, 10), squads.multisigProgramId);
+        const pauseSpendingLimitIx = await program.methods
+          .pauseSpendingLimit()
+          .accounts({ multisig: guardianSetMsPDA, disabler: guardianSetPDA })
+          .instruction();
+        // the instruction accounts, after the program, the guardian set signs through the program
+        const remainingAccounts = [
+          { pubkey: squads.multisigProgramId, isSigner: false, isWritable: false },
+          ...pauseSpendingLimitIx.keys.map((key) => ({ ...key, isSigner: false })),
+        ];
+
+        // only guardians can propose actions
+        await expectProgramError(
+          program.methods
+            .createGuardianAction(pauseSpendingLimitIx)
+            .accounts({ guardianSet: guardianSetPDA, action: actionPDA, creator: outsider.publicKey })
+            .signers([outsider])
+            .rpc(),
+          "UnauthorizedMember"
+        );
+
+        // the proposing guardian approves the action
+        await program.methods
+          .createGuardianAction(pauseSpendingLimitIx)
+          .accounts({ guardianSet: guardianSetPDA, action: actionPDA, creator: guardians[0].publicKey })
+          .signers([guardians[0]])
+          .rpc({ commitment: "confirmed" });
+        await expectProgramError(
+          program.methods
+            .executeGuardianAction()
+            .accounts({ guardianSet: guardianSetPDA, action: actionPDA, creator: guardians[0].publicKey, guardian: guardians[0].publicKey })
+            .remainingAccounts(remainingAccounts)
+            .signers([guardians[0]])
+            .rpc(),
+          "GuardianActionNotApproved"
+        );
+
+        await program.methods
+          .approveGuardianAction()
+          .accounts({ guardianSet: guardianSetPDA, action: actionPDA, guardian: guardians[1].publicKey })
+          .signers([guardians[1]])
+          .rpc({ commitment: "confirmed" });
+        await program.methods
+          .executeGuardianAction()
+          .accounts({ guardianSet: guardianSetPDA, action: actionPDA, creator: guardians[0].publicKey, guardian: guardians[1].publicKey })
+          .remainingAccounts(remainingAccounts)
+          .signers([guardians[1]])
+          .rpc({ commitment: "confirmed" });
+
+        const msState = await squads.getMultisig(guardianSetMsPDA, "confirmed");
+        expect(msState.spendingLimitEnabled).to.be.false;
+        // the action account is closed
+        expect(await squads.connection.getAccountInfo(actionPDA, "confirmed")).to.be.null;
+      });
+
+      it(`Cannot create a guardian set with a threshold above its guardians`, async function(){
+        const multisigPDA = await createTestMultisig([testMember(anchor.web3.Keypair.generate().publicKey)]);
+        const [invalidGuardianSetPDA] = getGuardianSetPDA(multisigPDA, squads.multisigProgramId);
+        const createGuardianSetIx = await program.methods
+          .createGuardianSet(guardians.map((g) => g.publicKey), 4)
+          .accounts({
+            guardianSet: invalidGuardianSetPDA,
+            multisig: multisigPDA,
+            rentPayer: creator.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .instruction();
+        const txPDA = await createActiveTransaction(multisigPDA, 0, (txBuilder) => txBuilder.withInstruction(createGuardianSetIx));
+        await squads.approveTransaction(txPDA);
+        await expectProgramError(squads.executeTransaction(txPDA), "InvalidThreshold");
+      });
+    });
+
+
+    describe("Guardian rotation", function(){
+      let rotationMsPDA;
+      const guardian = anchor.web3.Keypair.generate();
+      const newGuardian = anchor.web3.Keypair.generate().publicKey;
+
+      this.beforeAll(async function(){
+        rotationMsPDA = await createTestMultisig([testMember(anchor.web3.Keypair.generate().publicKey)]);
+        await executeInternalTransaction(rotationMsPDA, (txBuilder) => txBuilder.withUpdateMultisigSettings(0, true, guardian.publicKey));
+      });
+
+      it(`Cannot replace the guardian without a guardian change`, async function(){
+        const txPDA = await createActiveTransaction(rotationMsPDA, 0, (txBuilder) => txBuilder.withUpdateMultisigSettings(0, true, newGuardian));
+        await squads.approveTransaction(txPDA);
+        await expectProgramError(squads.executeTransaction(txPDA), "GuardianChangeRequiresDelay");
+      });
+
+      it(`Propose a guardian change and let the guardian veto it`, async function(){
+        await executeInternalTransaction(rotationMsPDA, (txBuilder) => txBuilder.withProposeGuardianChange(newGuardian));
+        let msState = await squads.getMultisig(rotationMsPDA, "confirmed");
+        expect(msState.pendingGuardianChange.guardian.toBase58()).to.equal(newGuardian.toBase58());
+        // the change waits for the 3 days delay
+        const now = Math.floor(Date.now() / 1000);
+        expect(msState.pendingGuardianChange.readyAt.toNumber()).to.be.greaterThan(now + 2 * 24 * 60 * 60);
+
+        // anyone can finalize it, once ready
+        await expectProgramError(
+          program.methods
+            .finalizeGuardianChange()
+            .accounts({ multisig: rotationMsPDA })
+            .rpc(),
+          "GuardianChangeNotReady"
+        );
+
+        await program.methods
+          .vetoGuardianChange()
+          .accounts({ multisig: rotationMsPDA, guardian: guardian.publicKey })
+          .signers([guardian])
+          .rpc({ commitment: "confirmed" });
+        msState = await squads.getMultisig(rotationMsPDA, "confirmed");
+        expect(msState.pendingGuardianChange).to.be.null;
+        expect(msState.guardian.toBase58()).to.equal(guardian.publicKey.toBase58());
+
+        await expectProgramError(
+          program.methods
+            .vetoGuardianChange()
+            .accounts({ multisig: rotationMsPDA, guardian: guardian.publicKey })
+            .signers([guardian])
+            .rpc(),
+          "NoPendingGuardianChange"
+        );
+      });
+
+      it(`The guardian cannot keep vetoing its removal`, async function(){
+        const vetoGuardianChange = () => program.methods
+          .vetoGuardianChange()
+          .accounts({ multisig: rotationMsPDA, guardian: guardian.publicKey })
+          .signers([guardian])
+          .rpc({ commitment: "confirmed" });
+
+        // the previous test used one of the 3 vetoes in a row
+        for (let i = 0; i < 2; i++) {
+          await executeInternalTransaction(rotationMsPDA, (txBuilder) => txBuilder.withProposeGuardianChange(null));
+          await vetoGuardianChange();
+        }
+        let msState = await squads.getMultisig(rotationMsPDA, "confirmed");
+        expect(msState.guardianVetoes).to.equal(3);
+
+        await executeInternalTransaction(rotationMsPDA, (txBuilder) => txBuilder.withProposeGuardianChange(null));
+        await expectProgramError(vetoGuardianChange(), "GuardianVetoLimitReached");
+        msState = await squads.getMultisig(rotationMsPDA, "confirmed");
+        expect(msState.pendingGuardianChange.guardian).to.be.null;
+      });
+    });
+
+
+    describe("Guardian veto", function(){
+      let vetoMsPDA;
+      const guardian = anchor.web3.Keypair.generate();
+
+      // vetoes a transaction on behalf of the given signer
+      const vetoAs = (transactionPDA: anchor.web3.PublicKey, signer: anchor.web3.Keypair) => {
+        return program.methods
+          .vetoTransaction()
+          .accounts({ multisig: vetoMsPDA, transaction: transactionPDA, guardian: signer.publicKey })
+          .signers([signer])
+          .rpc({ commitment: "confirmed" });
+      };
+
+      this.beforeAll(async function(){
+        vetoMsPDA = await createTestMultisig([testMember(anchor.web3.Keypair.generate().publicKey)]);
+        await executeInternalTransaction(vetoMsPDA, (txBuilder) => txBuilder.withUpdateMultisigSettings(0, true, guardian.publicKey));
+      });
+
+      it(`The guardian can veto an approved vault transaction`, async function(){
+        const txPDA = await createActiveTransaction(vetoMsPDA, 1, (txBuilder) => txBuilder);
+        await squads.approveTransaction(txPDA);
+
+        await expectProgramError(vetoAs(txPDA, anchor.web3.Keypair.generate()), "UnauthorizedMember");
+        await vetoAs(txPDA, guardian);
+        const txState = await squads.getTransaction(txPDA, "confirmed");
+        expect(txState.status).to.have.property("cancelled");
+      });
+
+      it(`The guardian cannot veto an internal transaction`, async function(){
+        const txPDA = await createActiveTransaction(vetoMsPDA, 0, (txBuilder) => txBuilder.withChangeThreshold(1));
+        await expectProgramError(vetoAs(txPDA, guardian), "InvalidAuthorityIndex");
+      });
+    });
+
+
+    describe("Guardian freeze", function(){
+      let freezeMsPDA;
+      const guardian = anchor.web3.Keypair.generate();
+
+      // freezes the multisig on behalf of the guardian
+      const freeze = (duration: number) => {
+        return program.methods
+          .freezeMultisig(duration)
+          .accounts({ multisig: freezeMsPDA, guardian: guardian.publicKey })
+          .signers([guardian])
+          .rpc({ commitment: "confirmed" });
+      };
+
+      this.beforeAll(async function(){
+        freezeMsPDA = await createTestMultisig([testMember(anchor.web3.Keypair.generate().publicKey)]);
+        await executeInternalTransaction(freezeMsPDA, (txBuilder) => txBuilder.withUpdateMultisigSettings(0, true, guardian.publicKey));
+        await fund(squads.getAuthorityPDA(freezeMsPDA, 1));
+      });
+
+      it(`Cannot freeze for longer than the maximum duration`, async function(){
+        await expectProgramError(freeze(0), "InvalidFreezeDuration");
+        // 1 week and 1 second
+        await expectProgramError(freeze(7 * 24 * 60 * 60 + 1), "InvalidFreezeDuration");
+      });
+
+      it(`Vault transactions can't be executed while frozen`, async function(){
+        const authorityPDA = squads.getAuthorityPDA(freezeMsPDA, 1);
+        const testPayee = anchor.web3.Keypair.generate().publicKey;
+        const testIx = await createTestTransferTransaction(authorityPDA, testPayee);
+        const txPDA = await createActiveTransaction(freezeMsPDA, 1, (txBuilder) => txBuilder.withInstruction(testIx));
+        await squads.approveTransaction(txPDA);
+
+        await freeze(ONE_MINUTE);
+        let msState = await squads.getMultisig(freezeMsPDA, "confirmed");
+        expect(msState.frozenUntil.toNumber()).to.be.greaterThan(0);
+        await expectProgramError(squads.executeTransaction(txPDA), "MultisigFrozen");
+
+        // the members can still unfreeze it with an internal transaction
+        const unfreezeIx = await program.methods
+          .unfreezeMultisig()
+          .accounts({ multisig: freezeMsPDA })
+          .instruction();
+        await executeInternalTransaction(freezeMsPDA, (txBuilder) => txBuilder.withInstruction(unfreezeIx));
+
+        const txState = await squads.executeTransaction(txPDA);
+        expect(txState.status).to.have.property("executed");
+        const testPayeeAccount = await squads.connection.getAccountInfo(testPayee);
+        expect(testPayeeAccount.lamports).to.equal(1000000);
+      });
+
+      it(`Cannot freeze again during the cooldown`, async function(){
+        await expectProgramError(freeze(ONE_MINUTE), "FreezeCooldown");
+      });
+    });
+
+
+    describe("Member recovery", function(){
+      let recoveryMsPDA;
+      let recoveryPDA;
+      const guardian = anchor.web3.Keypair.generate();
+      const lostMember = anchor.web3.Keypair.generate();
+
+      // proposes a member recovery on behalf of the guardian
+      const proposeRecovery = (replacements: { oldKey: anchor.web3.PublicKey, newKey: anchor.web3.PublicKey }[]) => {
+        return program.methods
+          .proposeRecovery(replacements)
+          .accounts({
+            multisig: recoveryMsPDA,
+            recovery: recoveryPDA,
+            guardian: guardian.publicKey,
+            rentPayer: creator.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([guardian])
+          .rpc({ commitment: "confirmed" });
+      };
+
+      this.beforeAll(async function(){
+        recoveryMsPDA = await createTestMultisig([testMember(lostMember.publicKey)]);
+        [recoveryPDA] = getRecoveryPDA(recoveryMsPDA, squads.multisigProgramId);
+        await executeInternalTransaction(recoveryMsPDA, (txBuilder) => txBuilder.withUpdateMultisigSettings(0, true, guardian.publicKey));
+      });
+
+      it(`Cannot replace every voting member`, async function(){
+        await expectProgramError(
+          proposeRecovery([
+            { oldKey: creator.publicKey, newKey: anchor.web3.Keypair.generate().publicKey },
+            { oldKey: lostMember.publicKey, newKey: anchor.web3.Keypair.generate().publicKey },
+          ]),
+          "InvalidRecovery"
+        );
+      });
+
+      it(`Propose a recovery and let a member veto it`, async function(){
+        const newKey = anchor.web3.Keypair.generate().publicKey;
+        await proposeRecovery([{ oldKey: lostMember.publicKey, newKey }]);
+        const recovery = await program.account.memberRecovery.fetch(recoveryPDA, "confirmed");
+        expect(recovery.replacements[0].newKey.toBase58()).to.equal(newKey.toBase58());
+
+        // the recovery waits for the 2 weeks delay
+        await expectProgramError(
+          program.methods
+            .finalizeRecovery()
+            .accounts({ multisig: recoveryMsPDA, recovery: recoveryPDA, rentPayer: creator.publicKey })
+            .rpc(),
+          "RecoveryNotReady"
+        );
+
+        // the replaced key can't veto it, as it may be the compromised one
+        await expectProgramError(
+          program.methods
+            .vetoRecovery()
+            .accounts({ multisig: recoveryMsPDA, recovery: recoveryPDA, rentPayer: creator.publicKey, member: lostMember.publicKey })
+            .signers([lostMember])
+            .rpc(),
+          "UnauthorizedMember"
+        );
+
+        // any other member can veto it
+        await program.methods
+          .vetoRecovery()
+          .accounts({ multisig: recoveryMsPDA, recovery: recoveryPDA, rentPayer: creator.publicKey, member: creator.publicKey })
+          .rpc({ commitment: "confirmed" });
+        expect(await squads.connection.getAccountInfo(recoveryPDA, "confirmed")).to.be.null;
+        const msState = await squads.getMultisig(recoveryMsPDA, "confirmed");
+        expect(msState.keys.some((member) => member.key.equals(lostMember.publicKey))).to.be.true;
+      });
+    });
+
+
+    describe("Spending limit destinations", function(){
+      let destinationsMsPDA;
+      const createKey = anchor.web3.Keypair.generate().publicKey;
+      const allowedDestination = anchor.web3.Keypair.generate().publicKey;
+      const amount = 0.05 * LAMPORTS_PER_SOL;
+
+      this.beforeAll(async function(){
+        destinationsMsPDA = await createTestMultisig([testMember(anchor.web3.Keypair.generate().publicKey)]);
+        await fund(squads.getAuthorityPDA(destinationsMsPDA, 1));
+        await executeInternalTransaction(destinationsMsPDA, (txBuilder) =>
+          txBuilder.withAddSpendingLimit(createKey, anchor.web3.PublicKey.default, 1, amount, [creator.publicKey], { daily: {} }, [allowedDestination])
+        );
+        const spendingLimit = await squads.getSpendingLimit(destinationsMsPDA, createKey);
+        expect(spendingLimit.destinations.map((d) => d.toBase58())).to.deep.equal([allowedDestination.toBase58()]);
+      });
+
+      it(`Only send to the allowed destinations`, async function(){
+        const transferAmount = 0.01 * LAMPORTS_PER_SOL;
+        await expectProgramError(
+          useSolSpendingLimit(destinationsMsPDA, createKey, transferAmount, anchor.web3.Keypair.generate().publicKey),
+          "DestinationNotAllowed"
+        );
+
+        await useSolSpendingLimit(destinationsMsPDA, createKey, transferAmount, allowedDestination);
+        const destinationAccount = await squads.connection.getAccountInfo(allowedDestination, "confirmed");
+        expect(destinationAccount.lamports).to.equal(transferAmount);
+      });
+
+      it(`Cannot allow more than 10 destinations`, async function(){
+        const destinations = [...new Array(11)].map(() => anchor.web3.Keypair.generate().publicKey);
+        const txPDA = await createActiveTransaction(destinationsMsPDA, 0, (txBuilder) =>
+          txBuilder.withAddSpendingLimit(anchor.web3.Keypair.generate().publicKey, anchor.web3.PublicKey.default, 1, amount, [creator.publicKey], { daily: {} }, destinations)
+        );
+        await squads.approveTransaction(txPDA);
+        await expectProgramError(squads.executeTransaction(txPDA), "InvalidDestinations");
+      });
+    });
+
+
+    describe("Shared spending limits", function(){
+      let sharedMsPDA;
+      const createKey = anchor.web3.Keypair.generate().publicKey;
+      const spendingLimitMember = anchor.web3.Keypair.generate();
+      const amount = 0.05 * LAMPORTS_PER_SOL;
+
+      this.beforeAll(async function(){
+        sharedMsPDA = await createTestMultisig([testMember(anchor.web3.Keypair.generate().publicKey)]);
+        await fund(squads.getAuthorityPDA(sharedMsPDA, 1));
+        // the spending limit members don't need to be members of the multisig
+        await executeInternalTransaction(sharedMsPDA, (txBuilder) =>
+          txBuilder.withAddSpendingLimit(createKey, anchor.web3.PublicKey.default, 1, amount, [creator.publicKey, spendingLimitMember.publicKey], { daily: {} })
+        );
+      });
+
+      it(`The members share the remaining amount`, async function(){
+        const destination = anchor.web3.Keypair.generate().publicKey;
+        await useSolSpendingLimit(sharedMsPDA, createKey, 0.03 * LAMPORTS_PER_SOL, destination, spendingLimitMember);
+        await useSolSpendingLimit(sharedMsPDA, createKey, 0.01 * LAMPORTS_PER_SOL, destination);
+
+        const spendingLimit = await squads.getSpendingLimit(sharedMsPDA, createKey, "confirmed");
+        expect(spendingLimit.remainingAmount.toNumber()).to.equal(0.01 * LAMPORTS_PER_SOL);
+        await expectProgramError(
+          useSolSpendingLimit(sharedMsPDA, createKey, 0.02 * LAMPORTS_PER_SOL, destination, spendingLimitMember),
+          "SpendingLimitExceeded"
+        );
+      });
+
+      it(`Only the members can use the spending limit`, async function(){
+        await expectProgramError(
+          useSolSpendingLimit(sharedMsPDA, createKey, 0.01 * LAMPORTS_PER_SOL, anchor.web3.Keypair.generate().publicKey, anchor.web3.Keypair.generate()),
+          "UnauthorizedMember"
+        );
+      });
+
+      it(`Cannot migrate a spending limit with the current layout`, async function(){
+        await expectProgramError(squads.migrateSpendingLimit(sharedMsPDA, createKey), "AlreadyMigrated");
+      });
+    });
+
+
+    describe("Spending limit periods", function(){
+      let periodsMsPDA;
+      const amount = 0.02 * LAMPORTS_PER_SOL;
+
+      this.beforeAll(async function(){
+        periodsMsPDA = await createTestMultisig([testMember(anchor.web3.Keypair.generate().publicKey)]);
+        await fund(squads.getAuthorityPDA(periodsMsPDA, 1));
+      });
+
+      it(`A custom period resets the remaining amount`, async function(){
+        const createKey = anchor.web3.Keypair.generate().publicKey;
+        const destination = anchor.web3.Keypair.generate().publicKey;
+        await executeInternalTransaction(periodsMsPDA, (txBuilder) =>
+          txBuilder.withAddSpendingLimit(createKey, anchor.web3.PublicKey.default, 1, amount, [creator.publicKey], { custom: { seconds: 3 } })
+        );
+
+        await useSolSpendingLimit(periodsMsPDA, createKey, amount, destination);
+        await expectProgramError(useSolSpendingLimit(periodsMsPDA, createKey, amount / 2, destination), "SpendingLimitExceeded");
+
+        await setTimeout(5000);
+        await useSolSpendingLimit(periodsMsPDA, createKey, amount, destination);
+        const destinationAccount = await squads.connection.getAccountInfo(destination, "confirmed");
+        expect(destinationAccount.lamports).to.equal(2 * amount);
+      });
+
+      it(`A one time spending limit never resets`, async function(){
+        const createKey = anchor.web3.Keypair.generate().publicKey;
+        const destination = anchor.web3.Keypair.generate().publicKey;
+        await executeInternalTransaction(periodsMsPDA, (txBuilder) =>
+          txBuilder.withAddSpendingLimit(createKey, anchor.web3.PublicKey.default, 1, amount, [creator.publicKey], { oneTime: {} })
+        );
+        const spendingLimit = await squads.getSpendingLimit(periodsMsPDA, createKey);
+        expect(spendingLimit.period).to.deep.equal({ oneTime: {} });
+
+        await useSolSpendingLimit(periodsMsPDA, createKey, amount, destination);
+        await setTimeout(2000);
+        await expectProgramError(useSolSpendingLimit(periodsMsPDA, createKey, amount / 2, destination), "SpendingLimitExceeded");
+      });
+
+      it(`Cannot create a custom period of 0 seconds`, async function(){
+        const txPDA = await createActiveTransaction(periodsMsPDA, 0, (txBuilder) =>
+          txBuilder.withAddSpendingLimit(anchor.web3.Keypair.generate().publicKey, anchor.web3.PublicKey.default, 1, amount, [creator.publicKey], { custom: { seconds: 0 } })
+        );
+        await squads.approveTransaction(txPDA);
+        await expectProgramError(squads.executeTransaction(txPDA), "InvalidPeriod");
+      });
+    });
+
+
+    describe("Rolling spending limits", function(){
+      let rollingMsPDA;
+      const amount = 0.02 * LAMPORTS_PER_SOL;
+
+      this.beforeAll(async function(){
+        rollingMsPDA = await createTestMultisig([testMember(anchor.web3.Keypair.generate().publicKey)]);
+        await fund(squads.getAuthorityPDA(rollingMsPDA, 1));
+      });
+
+      it(`The spends leave the window after the period`, async function(){
+        const createKey = anchor.web3.Keypair.generate().publicKey;
+        const destination = anchor.web3.Keypair.generate().publicKey;
+        await executeInternalTransaction(rollingMsPDA, (txBuilder) =>
+          txBuilder.withAddSpendingLimit(createKey, anchor.web3.PublicKey.default, 1, amount, [creator.publicKey], { custom: { seconds: 3 } }, [], { rolling: {} })
+        );
+
+        // distinct amounts, identical transactions would be rejected as already processed
+        await useSolSpendingLimit(rollingMsPDA, createKey, 0.012 * LAMPORTS_PER_SOL, destination);
+        await useSolSpendingLimit(rollingMsPDA, createKey, 0.008 * LAMPORTS_PER_SOL, destination);
+        let spendingLimit = await squads.getSpendingLimit(rollingMsPDA, createKey, "confirmed");
+        expect(spendingLimit.spends.length).to.equal(2);
+        await expectProgramError(useSolSpendingLimit(rollingMsPDA, createKey, amount / 2, destination), "SpendingLimitExceeded");
+
+        await setTimeout(5000);
+        await useSolSpendingLimit(rollingMsPDA, createKey, amount / 2, destination);
+        spendingLimit = await squads.getSpendingLimit(rollingMsPDA, createKey, "confirmed");
+        expect(spendingLimit.spends.length).to.equal(1);
+        expect(spendingLimit.remainingAmount.toNumber()).to.equal(amount / 2);
+      });
+
+      it(`Cannot create a one time rolling spending limit`, async function(){
+        const txPDA = await createActiveTransaction(rollingMsPDA, 0, (txBuilder) =>
+          txBuilder.withAddSpendingLimit(anchor.web3.Keypair.generate().publicKey, anchor.web3.PublicKey.default, 1, amount, [creator.publicKey], { oneTime: {} }, [], { rolling: {} })
+        );
+        await squads.approveTransaction(txPDA);
+        await expectProgramError(squads.executeTransaction(txPDA), "InvalidPeriod");
+      });
+    });
+
+
+    describe("Spending limit expiration and counters", function(){
+      let expirationMsPDA;
+      const amount = 0.05 * LAMPORTS_PER_SOL;
+
+      // the clock of the cluster, which may differ from the local clock
+      const clusterTime = async () => {
+        const slot = await squads.connection.getSlot("confirmed");
+        return await squads.connection.getBlockTime(slot);
+      };
+
+      this.beforeAll(async function(){
+        expirationMsPDA = await createTestMultisig([testMember(anchor.web3.Keypair.generate().publicKey)]);
+        await fund(squads.getAuthorityPDA(expirationMsPDA, 1));
+      });
+
+      it(`Cannot create a spending limit expiring in the past`, async function(){
+        const expiration = (await clusterTime()) - ONE_MINUTE;
+        const txPDA = await createActiveTransaction(expirationMsPDA, 0, (txBuilder) =>
+          txBuilder.withAddSpendingLimit(anchor.web3.Keypair.generate().publicKey, anchor.web3.PublicKey.default, 1, amount, [creator.publicKey], { daily: {} }, [], { fixed: {} }, expiration)
+        );
+        await squads.approveTransaction(txPDA);
+        await expectProgramError(squads.executeTransaction(txPDA), "InvalidExpiration");
+      });
+
+      it(`Count the uses until the expiration`, async function(){
+        const createKey = anchor.web3.Keypair.generate().publicKey;
+        const destination = anchor.web3.Keypair.generate().publicKey;
+        const expiration = (await clusterTime()) + 10;
+        await executeInternalTransaction(expirationMsPDA, (txBuilder) =>
+          txBuilder.withAddSpendingLimit(createKey, anchor.web3.PublicKey.default, 1, amount, [creator.publicKey], { daily: {} }, [], { fixed: {} }, expiration)
+        );
+
+        await useSolSpendingLimit(expirationMsPDA, createKey, 0.01 * LAMPORTS_PER_SOL, destination);
+        await useSolSpendingLimit(expirationMsPDA, createKey, 0.02 * LAMPORTS_PER_SOL, destination);
+        const spendingLimit = await squads.getSpendingLimit(expirationMsPDA, createKey, "confirmed");
+        expect(spendingLimit.expiration.toNumber()).to.equal(expiration);
+        expect(spendingLimit.useCount.toNumber()).to.equal(2);
+        expect(spendingLimit.totalSpent.toNumber()).to.equal(0.03 * LAMPORTS_PER_SOL);
+        expect(spendingLimit.lastUsed.toNumber()).to.be.greaterThan(0);
+
+        await setTimeout(12000);
+        await expectProgramError(
+          useSolSpendingLimit(expirationMsPDA, createKey, 0.01 * LAMPORTS_PER_SOL, destination),
+          "SpendingLimitExpired"
+        );
+      });
+    });
+
+
+    describe("Execution time lock", function(){
+      let executionTimeLockMsPDA;
+
+      this.beforeAll(async function(){
+        executionTimeLockMsPDA = await createTestMultisig([testMember(anchor.web3.Keypair.generate().publicKey)]);
+        await fund(squads.getAuthorityPDA(executionTimeLockMsPDA, 1));
+        const setTimeLockModeIx = await program.methods
+          .setTimeLockMode({ execution: {} })
+          .accounts({ multisig: executionTimeLockMsPDA })
+          .instruction();
+        await executeInternalTransaction(executionTimeLockMsPDA, async (txBuilder) =>
+          (await txBuilder.withUpdateMultisigSettings(3, true, null)).withInstruction(setTimeLockModeIx)
+        );
+        const msState = await squads.getMultisig(executionTimeLockMsPDA);
+        expect(msState.timeLock).to.equal(3);
+        expect(msState.timeLockMode).to.have.property("execution");
+      });
+
+      it(`Approve right away and wait the time lock before executing`, async function(){
+        const authorityPDA = squads.getAuthorityPDA(executionTimeLockMsPDA, 1);
+        const testPayee = anchor.web3.Keypair.generate().publicKey;
+        const testIx = await createTestTransferTransaction(authorityPDA, testPayee);
+        const txPDA = await createActiveTransaction(executionTimeLockMsPDA, 1, (txBuilder) => txBuilder.withInstruction(testIx));
+
+        let txState = await squads.approveTransaction(txPDA);
+        expect(txState.status).to.have.property("executeReady");
+        expect(txState.executionDelay).to.equal(3);
+        await expectProgramError(squads.executeTransaction(txPDA), "TimeLockNotSatisfied");
+
+        await setTimeout(5000);
+        txState = await squads.executeTransaction(txPDA);
+        expect(txState.status).to.have.property("executed");
+        const testPayeeAccount = await squads.connection.getAccountInfo(testPayee);
+        expect(testPayeeAccount.lamports).to.equal(1000000);
+      });
+
+      it(`Cancel an approved transaction during the time lock`, async function(){
+        const txPDA = await createActiveTransaction(executionTimeLockMsPDA, 1, (txBuilder) => txBuilder);
+        await squads.approveTransaction(txPDA);
+        const txState = await squads.cancelTransaction(txPDA);
+        expect(txState.status).to.have.property("cancelled");
+      });
+    });
+
+
+    describe("Execution policies", function(){
+      let executionPolicyMsPDA;
+      const executor = anchor.web3.Keypair.generate();
+      const outsider = anchor.web3.Keypair.generate();
+
+      // creates an approved vault transaction without instructions
+      const createApprovedTransaction = async () => {
+        const txPDA = await createActiveTransaction(executionPolicyMsPDA, 1, (txBuilder) => txBuilder);
+        await squads.approveTransaction(txPDA);
+        return txPDA;
+      };
+
+      // sets the execution policy through an internal transaction
+      const setExecutionPolicy = async (executionPolicy) => {
+        const setExecutionPolicyIx = await program.methods
+          .setExecutionPolicy(executionPolicy)
+          .accounts({ multisig: executionPolicyMsPDA })
+          .instruction();
+        await executeInternalTransaction(executionPolicyMsPDA, (txBuilder) => txBuilder.withInstruction(setExecutionPolicyIx));
+      };
+
+      this.beforeAll(async function(){
+        executionPolicyMsPDA = await createTestMultisig([testMember(anchor.web3.Keypair.generate().publicKey)]);
+        await fund(executor.publicKey);
+        await fund(outsider.publicKey);
+      });
+
+      it(`Designated executors can execute approved transactions`, async function(){
+        // approved before the policy change, which is checked at execution and doesn't deprecate it
+        const pendingTxPDA = await createApprovedTransaction();
+        await setExecutionPolicy({ executors: { keys: [executor.publicKey] } });
+        const msState = await squads.getMultisig(executionPolicyMsPDA);
+        expect(msState.executionPolicy.executors.keys[0].toBase58()).to.equal(executor.publicKey.toBase58());
+        let txState = await executeAs(pendingTxPDA, executor);
+        expect(txState.status).to.have.property("executed");
+
+        const txPDA = await createApprovedTransaction();
+        await expectProgramError(executeAs(txPDA, outsider), "UnauthorizedExecutor");
+        txState = await executeAs(txPDA, executor);
+        expect(txState.status).to.have.property("executed");
+      });
+
+      it(`Anyone can execute approved transactions when permissionless`, async function(){
+        await setExecutionPolicy({ permissionless: {} });
+
+        const txPDA = await createApprovedTransaction();
+        const txState = await executeAs(txPDA, outsider);
+        expect(txState.status).to.have.property("executed");
+      });
+    });
+
+
+    describe("Instruction buffers", function(){
+      let bufferMsPDA;
+
+      // the serialized instruction written to the buffers, and its hash
+      const serializeInstruction = (instruction: anchor.web3.TransactionInstruction): [Buffer, number[]] => {
+        const serialized = program.coder.types.encode("IncomingInstruction", {
+          programId: instruction.programId,
+          keys: instruction.keys,
+          data: instruction.data,
+        });
+        return [serialized, [...createHash("sha256").update(serialized).digest()]];
+      };
+
+      this.beforeAll(async function(){
+        bufferMsPDA = await createTestMultisig([testMember(anchor.web3.Keypair.generate().publicKey)]);
+        await fund(squads.getAuthorityPDA(bufferMsPDA, 1));
+      });
+
+      it(`Attach an instruction written in chunks`, async function(){
+        const authorityPDA = squads.getAuthorityPDA(bufferMsPDA, 1);
+        const testPayee = anchor.web3.Keypair.generate().publicKey;
+        const testIx = await createTestTransferTransaction(authorityPDA, testPayee);
+        const [serialized, finalHash] = serializeInstruction(testIx);
+        const half = Math.floor(serialized.length / 2);
+
+        let txState = await squads.createTransaction(bufferMsPDA, 1);
+        const [bufferPDA] = getInstructionBufferPDA(txState.publicKey, squads.multisigProgramId);
+        const [ixPDA] = getIxPDA(txState.publicKey, new BN(1, 10), squads.multisigProgramId);
+        const bufferAccounts = { multisig: bufferMsPDA, transaction: txState.publicKey, instructionBuffer: bufferPDA, creator: creator.publicKey };
+        await program.methods
+          .createInstructionBuffer(finalHash, serialized.length, serialized.subarray(0, half))
+          .accounts({ ...bufferAccounts, systemProgram: SystemProgram.programId })
+          .rpc({ commitment: "confirmed" });
+
+        // the buffer must be complete
+        await expectProgramError(
+          program.methods
+            .addInstructionFromBuffer()
+            .accounts({ ...bufferAccounts, instruction: ixPDA, systemProgram: SystemProgram.programId })
+            .rpc(),
+          "InstructionBufferIncomplete"
+        );
+
+        await program.methods
+          .writeInstructionBuffer(serialized.subarray(half))
+          .accounts(bufferAccounts)
+          .rpc({ commitment: "confirmed" });
+        await program.methods
+          .addInstructionFromBuffer()
+          .accounts({ ...bufferAccounts, instruction: ixPDA, systemProgram: SystemProgram.programId })
+          .rpc({ commitment: "confirmed" });
+        expect(await squads.connection.getAccountInfo(bufferPDA, "confirmed")).to.be.null;
+        const ixState = await squads.getInstruction(ixPDA);
+        expect(ixState.programId.toBase58()).to.equal(SystemProgram.programId.toBase58());
+
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
+        txState = await squads.executeTransaction(txState.publicKey);
+        expect(txState.status).to.have.property("executed");
+        const testPayeeAccount = await squads.connection.getAccountInfo(testPayee);
+        expect(testPayeeAccount.lamports).to.equal(1000000);
+      });
+
+      it(`Cannot attach an instruction not matching the hash of the buffer`, async function(){
+        const testIx = await createTestTransferTransaction(squads.getAuthorityPDA(bufferMsPDA, 1), anchor.web3.Keypair.generate().publicKey);
+        const [serialized] = serializeInstruction(testIx);
+
+        const txState = await squads.createTransaction(bufferMsPDA, 1);
+        const [bufferPDA] = getInstructionBufferPDA(txState.publicKey, squads.multisigProgramId);
+        const [ixPDA] = getIxPDA(txState.publicKey, new BN(1, 10), squads.multisigProgramId);
+        const bufferAccounts = { multisig: bufferMsPDA, transaction: txState.publicKey, instructionBuffer: bufferPDA, creator: creator.publicKey };
+        await program.methods
+          .createInstructionBuffer(new Array(32).fill(0), serialized.length, serialized)
+          .accounts({ ...bufferAccounts, systemProgram: SystemProgram.programId })
+          .rpc({ commitment: "confirmed" });
+        await expectProgramError(
+          program.methods
+            .addInstructionFromBuffer()
+            .accounts({ ...bufferAccounts, instruction: ixPDA, systemProgram: SystemProgram.programId })
+            .rpc(),
+          "InvalidBufferHash"
+        );
+
+        // the buffer can be closed to reclaim its rent
+        await program.methods
+          .closeInstructionBuffer()
+          .accounts({ transaction: txState.publicKey, instructionBuffer: bufferPDA, creator: creator.publicKey })
+          .rpc({ commitment: "confirmed" });
+        expect(await squads.connection.getAccountInfo(bufferPDA, "confirmed")).to.be.null;
+      });
+
+      it(`Close a buffer once its transaction is closed`, async function(){
+        // the wallet alone rejects the transactions of this multisig
+        const rejectMsPDA = await createTestMultisig([]);
+        const testIx = await createTestTransferTransaction(squads.getAuthorityPDA(rejectMsPDA, 1), anchor.web3.Keypair.generate().publicKey);
+        const [serialized, finalHash] = serializeInstruction(testIx);
+
+        const txState = await squads.createTransaction(rejectMsPDA, 1);
+        const [bufferPDA] = getInstructionBufferPDA(txState.publicKey, squads.multisigProgramId);
+        await program.methods
+          .createInstructionBuffer(finalHash, serialized.length, serialized)
+          .accounts({ multisig: rejectMsPDA, transaction: txState.publicKey, instructionBuffer: bufferPDA, creator: creator.publicKey, systemProgram: SystemProgram.programId })
+          .rpc({ commitment: "confirmed" });
+
+        // reject and close the transaction without attaching the buffer
+        await squads.activateTransaction(txState.publicKey);
+        await squads.rejectTransaction(txState.publicKey);
+        await program.methods
+          .closeTransaction()
+          .accounts({ multisig: rejectMsPDA, transaction: txState.publicKey, rentCollector: creator.publicKey })
+          .rpc({ commitment: "confirmed" });
+        expect(await squads.connection.getAccountInfo(txState.publicKey, "confirmed")).to.be.null;
+
+        await program.methods
+          .closeInstructionBuffer()
+          .accounts({ transaction: txState.publicKey, instructionBuffer: bufferPDA, creator: creator.publicKey })
+          .rpc({ commitment: "confirmed" });
+        expect(await squads.connection.getAccountInfo(bufferPDA, "confirmed")).to.be.null;
+      });
+    });
+
+
+    describe("Editing draft transactions", function(){
+      let editMsPDA;
+
+      this.beforeAll(async function(){
+        editMsPDA = await createTestMultisig([testMember(anchor.web3.Keypair.generate().publicKey)]);
+        await fund(squads.getAuthorityPDA(editMsPDA, 1));
+      });
+
+      it(`Replace and remove the instructions of a draft`, async function(){
+        const authorityPDA = squads.getAuthorityPDA(editMsPDA, 1);
+        const [payee, removedPayee, lastPayee, newPayee] = [...new Array(4)].map(() => anchor.web3.Keypair.generate().publicKey);
+
+        let txState = await squads.createTransaction(editMsPDA, 1);
+        const ix1State = await squads.addInstruction(txState.publicKey, await createTestTransferTransaction(authorityPDA, payee));
+        const ix2State = await squads.addInstruction(txState.publicKey, await createTestTransferTransaction(authorityPDA, removedPayee));
+        const ix3State = await squads.addInstruction(txState.publicKey, await createTestTransferTransaction(authorityPDA, lastPayee));
+
+        await program.methods
+          .updateInstruction(await createTestTransferTransaction(authorityPDA, newPayee))
+          .accounts({ multisig: editMsPDA, transaction: txState.publicKey, instruction: ix1State.publicKey, creator: creator.publicKey })
+          .rpc({ commitment: "confirmed" });
+
+        // the last instruction account is always closed
+        await expectProgramError(
+          program.methods
+            .removeInstruction(2)
+            .accounts({ multisig: editMsPDA, transaction: txState.publicKey, instruction: ix2State.publicKey, creator: creator.publicKey })
+            .remainingAccounts([{ pubkey: ix2State.publicKey, isSigner: false, isWritable: true }])
+            .rpc(),
+          "InvalidInstructionAccount"
+        );
+        // the following instructions must all be passed
+        await expectProgramError(
+          program.methods
+            .removeInstruction(2)
+            .accounts({ multisig: editMsPDA, transaction: txState.publicKey, instruction: ix3State.publicKey, creator: creator.publicKey })
+            .rpc(),
+          "InvalidNumberOfAccounts"
+        );
+
+        // the third instruction moves to the second index in the same instruction
+        txState = await squads.removeInstruction(txState.publicKey, 2);
+        expect(txState.instructionIndex).to.equal(2);
+        expect(await squads.connection.getAccountInfo(ix3State.publicKey)).to.be.null;
+        const movedState = await squads.getInstruction(ix2State.publicKey);
+        expect(movedState.instructionIndex).to.equal(2);
+        expect(movedState.keys[1].pubkey.toBase58()).to.equal(lastPayee.toBase58());
+
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
+        txState = await squads.executeTransaction(txState.publicKey);
+        expect(txState.status).to.have.property("executed");
+        expect(await squads.connection.getAccountInfo(payee)).to.be.null;
+        expect(await squads.connection.getAccountInfo(removedPayee)).to.be.null;
+        for (const executedPayee of [newPayee, lastPayee]) {
+          const payeeAccount = await squads.connection.getAccountInfo(executedPayee);
+          expect(payeeAccount.lamports).to.equal(1000000);
+        }
+      });
+
+      it(`Cannot edit the instructions of an active transaction`, async function(){
+        const authorityPDA = squads.getAuthorityPDA(editMsPDA, 1);
+        const txState = await squads.createTransaction(editMsPDA, 1);
+        const ixState = await squads.addInstruction(txState.publicKey, await createTestTransferTransaction(authorityPDA, creator.publicKey));
+        await squads.activateTransaction(txState.publicKey);
+
+        await expectProgramError(
+          program.methods
+            .updateInstruction(await createTestTransferTransaction(authorityPDA, anchor.web3.Keypair.generate().publicKey))
+            .accounts({ multisig: editMsPDA, transaction: txState.publicKey, instruction: ixState.publicKey, creator: creator.publicKey })
+            .rpc(),
+          "InvalidTransactionState"
+        );
+      });
+    });
+
   });
 
 });